@@ -260,6 +260,7 @@ export enum BuiltinPluginName {
   APIPlugin = 'APIPlugin',
   RuntimeChunkPlugin = 'RuntimeChunkPlugin',
   SizeLimitsPlugin = 'SizeLimitsPlugin',
+  SubresourceIntegrityPlugin = 'SubresourceIntegrityPlugin',
   HttpExternalsRspackPlugin = 'HttpExternalsRspackPlugin',
   CopyRspackPlugin = 'CopyRspackPlugin',
   HtmlRspackPlugin = 'HtmlRspackPlugin',
@@ -538,6 +539,21 @@ export interface JsPathData {
   chunk?: JsChunkPathData
 }
 
+export interface JsProgressEvent {
+  percentage: number
+  phase: string
+  message: string
+  activeModule?: string
+  modulesDone: number
+  modulesTotal: number
+  phaseDurations?: Array<JsProgressPhaseDuration>
+}
+
+export interface JsProgressPhaseDuration {
+  phase: string
+  duration: number
+}
+
 export interface JsResolveArgs {
   request: string
   context: string
@@ -817,10 +833,14 @@ export interface RawCacheGroupOptions {
   automaticNameDelimiter?: string
   minChunks?: number
   minSize?: number | RawSplitChunkSizes
+  minSizeReduction?: number | RawSplitChunkSizes
+  enforceSizeThreshold?: number | RawSplitChunkSizes
+  minRemainingSize?: number | RawSplitChunkSizes
   maxSize?: number | RawSplitChunkSizes
   maxAsyncSize?: number | RawSplitChunkSizes
   maxInitialSize?: number | RawSplitChunkSizes
   name?: string | false | Function
+  usedExports?: boolean
   reuseExistingChunk?: boolean
   enforce?: boolean
 }
@@ -1060,13 +1080,18 @@ export interface RawGeneratorOptions {
   cssModule?: RawCssModuleGeneratorOptions
 }
 
+export interface RawHtmlResourceHints {
+  initial?: boolean
+  asyncChunks?: boolean
+}
+
 export interface RawHtmlRspackPluginOptions {
   /** emitted file name in output path */
   filename?: string
   /** template html file */
   template?: string
   templateContent?: string
-  templateParameters?: Record<string, string>
+  templateParameters?: Record<string, any>
   /** "head", "body" or "false" */
   inject: "head" | "body" | "false"
   /** path or `auto` */
@@ -1081,6 +1106,7 @@ export interface RawHtmlRspackPluginOptions {
   title?: string
   favicon?: string
   meta?: Record<string, Record<string, string>>
+  resourceHints?: RawHtmlResourceHints
 }
 
 export interface RawHttpExternalsRspackPluginOptions {
@@ -1094,6 +1120,16 @@ export interface RawIgnorePluginOptions {
   checkResource?: (resource: string, context: string) => boolean
 }
 
+/**
+ * The `{ code, module, message }` rules of `ignoreWarnings`, RegExps and functions are applied
+ * by the `IgnoreWarningsPlugin` in JavaScript.
+ */
+export interface RawIgnoreWarning {
+  code?: string
+  module?: RegExp
+  message?: RegExp
+}
+
 export interface RawInfo {
   immutable?: boolean
   minimized?: boolean
@@ -1284,6 +1320,7 @@ export interface RawOptions {
   node?: RawNodeOption
   profile: boolean
   bail: boolean
+  ignoreWarnings: Array<RawIgnoreWarning>
   __references: Record<string, any>
 }
 
@@ -1352,6 +1389,7 @@ export interface RawProgressPluginOptions {
   template: string
   tick?: string | Array<string>
   progressChars: string
+  handler?: "json" | ((event: JsProgressEvent) => void)
 }
 
 export interface RawProvideOptions {
@@ -1476,7 +1514,14 @@ export interface RawSizeLimitsPluginOptions {
 }
 
 export interface RawSnapshotOptions {
+  managedPaths?: (string | RegExp)[]
+  immutablePaths?: (string | RegExp)[]
+  module?: RawSnapshotStrategy
+}
 
+export interface RawSnapshotStrategy {
+  hash?: boolean
+  timestamp?: boolean
 }
 
 export interface RawSourceMapDevToolPluginOptions {
@@ -1511,17 +1556,23 @@ export interface RawSplitChunksOptions {
   minChunks?: number
   hidePathInfo?: boolean
   minSize?: number | RawSplitChunkSizes
-  enforceSizeThreshold?: number
+  minSizeReduction?: number | RawSplitChunkSizes
+  enforceSizeThreshold?: number | RawSplitChunkSizes
   minRemainingSize?: number | RawSplitChunkSizes
   maxSize?: number | RawSplitChunkSizes
   maxAsyncSize?: number | RawSplitChunkSizes
   maxInitialSize?: number | RawSplitChunkSizes
+  usedExports?: boolean
 }
 
 export interface RawStatsOptions {
   colors: boolean
 }
 
+export interface RawSubresourceIntegrityPluginOptions {
+  hashFuncNames: ("sha256" | "sha384" | "sha512")[]
+}
+
 export interface RawSwcCssMinimizerRspackPluginOptions {
   test?: string | RegExp | (string | RegExp)[]
   include?: string | RegExp | (string | RegExp)[]
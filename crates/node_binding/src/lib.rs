@@ -6,13 +6,14 @@ extern crate napi_derive;
 extern crate rspack_allocator;
 
 use std::pin::Pin;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
 use compiler::{Compiler, CompilerState, CompilerStateGuard};
 use napi::bindgen_prelude::*;
 use rspack_binding_options::BuiltinPlugin;
 use rspack_core::PluginExt;
 use rspack_error::Diagnostic;
+use rspack_fs::AsyncNativeFileSystem;
 use rspack_fs_node::{AsyncNodeWritableFileSystem, ThreadsafeNodeFS};
 
 mod compiler;
@@ -69,8 +70,9 @@ impl Rspack {
       plugins,
       AsyncNodeWritableFileSystem::new(output_filesystem)
         .map_err(|e| Error::from_reason(format!("Failed to create writable filesystem: {e}",)))?,
-      resolver_factory,
-      loader_resolver_factory,
+      Arc::new(AsyncNativeFileSystem),
+      Some(resolver_factory),
+      Some(loader_resolver_factory),
     );
 
     Ok(Self {
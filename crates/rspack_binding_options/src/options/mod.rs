@@ -11,6 +11,7 @@ mod raw_dynamic_entry;
 mod raw_entry;
 mod raw_experiments;
 mod raw_external;
+mod raw_ignore_warnings;
 mod raw_mode;
 mod raw_module;
 mod raw_node;
@@ -27,6 +28,7 @@ pub use raw_dynamic_entry::*;
 pub use raw_entry::*;
 pub use raw_experiments::*;
 pub use raw_external::*;
+pub use raw_ignore_warnings::*;
 pub use raw_mode::*;
 pub use raw_module::*;
 pub use raw_node::*;
@@ -57,6 +59,7 @@ pub struct RawOptions {
   pub node: Option<RawNodeOption>,
   pub profile: bool,
   pub bail: bool,
+  pub ignore_warnings: Vec<RawIgnoreWarning>,
   #[napi(js_name = "__references", ts_type = "Record<string, any>")]
   pub __references: References,
 }
@@ -107,6 +110,7 @@ impl TryFrom<RawOptions> for CompilerOptions {
       dev_server: Default::default(),
       profile: value.profile,
       bail: value.bail,
+      ignore_warnings: value.ignore_warnings.into_iter().map(Into::into).collect(),
       __references: value.__references,
     })
   }
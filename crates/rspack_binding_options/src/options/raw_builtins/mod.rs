@@ -11,6 +11,7 @@ mod raw_mf;
 mod raw_progress;
 mod raw_runtime_chunk;
 mod raw_size_limits;
+mod raw_sri;
 mod raw_swc_css_minimizer;
 mod raw_swc_js_minimizer;
 
@@ -67,6 +68,7 @@ use rspack_plugin_runtime::{
 use rspack_plugin_runtime_chunk::RuntimeChunkPlugin;
 use rspack_plugin_schemes::{DataUriPlugin, FileUriPlugin};
 use rspack_plugin_size_limits::SizeLimitsPlugin;
+use rspack_plugin_sri::SubresourceIntegrityPlugin;
 use rspack_plugin_swc_css_minimizer::SwcCssMinimizerRspackPlugin;
 use rspack_plugin_swc_js_minimizer::SwcJsMinimizerRspackPlugin;
 use rspack_plugin_warn_sensitive_module::WarnCaseSensitiveModulesPlugin;
@@ -90,6 +92,7 @@ use self::{
   raw_mf::{RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions, RawProvideOptions},
   raw_runtime_chunk::RawRuntimeChunkOptions,
   raw_size_limits::RawSizeLimitsPluginOptions,
+  raw_sri::RawSubresourceIntegrityPluginOptions,
 };
 use crate::{
   plugins::{CssExtractRspackAdditionalDataPlugin, JsLoaderRspackPlugin},
@@ -162,6 +165,7 @@ pub enum BuiltinPluginName {
   APIPlugin,
   RuntimeChunkPlugin,
   SizeLimitsPlugin,
+  SubresourceIntegrityPlugin,
 
   // rspack specific plugins
   // naming format follow XxxRspackPlugin
@@ -212,7 +216,7 @@ impl BuiltinPlugin {
       }
       BuiltinPluginName::ProgressPlugin => {
         let plugin =
-          ProgressPlugin::new(downcast_into::<RawProgressPluginOptions>(self.options)?.into())
+          ProgressPlugin::new(downcast_into::<RawProgressPluginOptions>(self.options)?.try_into()?)
             .boxed();
         plugins.push(plugin);
       }
@@ -434,6 +438,13 @@ impl BuiltinPlugin {
             .boxed();
         plugins.push(plugin)
       }
+      BuiltinPluginName::SubresourceIntegrityPlugin => {
+        let plugin = SubresourceIntegrityPlugin::new(
+          downcast_into::<RawSubresourceIntegrityPluginOptions>(self.options)?.try_into()?,
+        )
+        .boxed();
+        plugins.push(plugin)
+      }
 
       // rspack specific plugins
       BuiltinPluginName::HttpExternalsRspackPlugin => {
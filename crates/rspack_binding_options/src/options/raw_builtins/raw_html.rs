@@ -3,6 +3,7 @@ use std::str::FromStr;
 
 use napi_derive::napi;
 use rspack_plugin_html::config::HtmlInject;
+use rspack_plugin_html::config::HtmlResourceHints;
 use rspack_plugin_html::config::HtmlRspackPluginOptions;
 use rspack_plugin_html::config::HtmlScriptLoading;
 use rspack_plugin_html::sri::HtmlSriHashFunction;
@@ -12,6 +13,13 @@ pub type RawHtmlInject = String;
 pub type RawHtmlSriHashFunction = String;
 pub type RawHtmlFilename = String;
 
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawHtmlResourceHints {
+  pub initial: Option<bool>,
+  pub async_chunks: Option<bool>,
+}
+
 #[derive(Debug)]
 #[napi(object)]
 pub struct RawHtmlRspackPluginOptions {
@@ -21,7 +29,8 @@ pub struct RawHtmlRspackPluginOptions {
   /// template html file
   pub template: Option<String>,
   pub template_content: Option<String>,
-  pub template_parameters: Option<HashMap<String, String>>,
+  #[napi(ts_type = "Record<string, any>")]
+  pub template_parameters: Option<HashMap<String, serde_json::Value>>,
   /// "head", "body" or "false"
   #[napi(ts_type = "\"head\" | \"body\" | \"false\"")]
   pub inject: RawHtmlInject,
@@ -40,6 +49,16 @@ pub struct RawHtmlRspackPluginOptions {
   pub title: Option<String>,
   pub favicon: Option<String>,
   pub meta: Option<HashMap<String, HashMap<String, String>>>,
+  pub resource_hints: Option<RawHtmlResourceHints>,
+}
+
+impl From<RawHtmlResourceHints> for HtmlResourceHints {
+  fn from(value: RawHtmlResourceHints) -> Self {
+    HtmlResourceHints {
+      initial: value.initial.unwrap_or_default(),
+      async_chunks: value.async_chunks.unwrap_or_default(),
+    }
+  }
 }
 
 impl From<RawHtmlRspackPluginOptions> for HtmlRspackPluginOptions {
@@ -68,6 +87,7 @@ impl From<RawHtmlRspackPluginOptions> for HtmlRspackPluginOptions {
       title: value.title,
       favicon: value.favicon,
       meta: value.meta,
+      resource_hints: value.resource_hints.map(Into::into).unwrap_or_default(),
     }
   }
 }
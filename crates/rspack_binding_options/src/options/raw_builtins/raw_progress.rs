@@ -1,9 +1,65 @@
 use napi::Either;
 use napi_derive::napi;
-use rspack_plugin_progress::ProgressPluginOptions;
+use rspack_error::{error, Result};
+use rspack_napi::threadsafe_function::ThreadsafeFunction;
+use rspack_plugin_progress::{
+  ProgressEvent, ProgressPhase, ProgressPluginHandler, ProgressPluginOptions,
+};
 
-#[derive(Debug, Clone)]
 #[napi(object)]
+pub struct JsProgressPhaseDuration {
+  pub phase: String,
+  pub duration: u32,
+}
+
+#[napi(object)]
+pub struct JsProgressEvent {
+  pub percentage: f64,
+  pub phase: String,
+  pub message: String,
+  pub active_module: Option<String>,
+  pub modules_done: u32,
+  pub modules_total: u32,
+  pub phase_durations: Option<Vec<JsProgressPhaseDuration>>,
+}
+
+fn phase_to_string(phase: ProgressPhase) -> String {
+  match phase {
+    ProgressPhase::Setup => "setup",
+    ProgressPhase::Building => "building",
+    ProgressPhase::Sealing => "sealing",
+    ProgressPhase::Optimizing => "optimizing",
+    ProgressPhase::Emitting => "emitting",
+  }
+  .to_string()
+}
+
+impl From<&ProgressEvent> for JsProgressEvent {
+  fn from(value: &ProgressEvent) -> Self {
+    Self {
+      percentage: value.percentage as f64,
+      phase: phase_to_string(value.phase),
+      message: value.message.clone(),
+      active_module: value.active_module.clone(),
+      modules_done: value.modules_done,
+      modules_total: value.modules_total,
+      phase_durations: value.phase_durations.as_ref().map(|durations| {
+        durations
+          .iter()
+          .map(|duration| JsProgressPhaseDuration {
+            phase: phase_to_string(duration.phase),
+            duration: duration.duration as u32,
+          })
+          .collect()
+      }),
+    }
+  }
+}
+
+type RawProgressHandler = Either<String, ThreadsafeFunction<JsProgressEvent, ()>>;
+
+#[derive(Debug, Clone)]
+#[napi(object, object_to_js = false)]
 pub struct RawProgressPluginOptions {
   // the prefix name of progress bar
   pub prefix: String,
@@ -15,11 +71,29 @@ pub struct RawProgressPluginOptions {
   pub tick: Option<Either<String, Vec<String>>>,
   // the progress characters
   pub progress_chars: String,
+  // receives structured progress events instead of drawing the progress bar, "json" writes them
+  // to stderr as JSON lines
+  #[napi(ts_type = "\"json\" | ((event: JsProgressEvent) => void)")]
+  pub handler: Option<RawProgressHandler>,
 }
 
-impl From<RawProgressPluginOptions> for ProgressPluginOptions {
-  fn from(value: RawProgressPluginOptions) -> Self {
-    Self {
+impl TryFrom<RawProgressPluginOptions> for ProgressPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawProgressPluginOptions) -> Result<Self> {
+    let handler = match value.handler {
+      Some(Either::A(handler)) if handler == "json" => Some(ProgressPluginHandler::JsonLines),
+      Some(Either::A(handler)) => {
+        return Err(error!(
+          "Unknown progress handler '{handler}', expected 'json' or a function"
+        ))
+      }
+      Some(Either::B(f)) => Some(ProgressPluginHandler::Fn(std::sync::Arc::new(
+        move |event: &ProgressEvent| f.call_with_fatal(event.into()),
+      ))),
+      None => None,
+    };
+    Ok(Self {
       prefix: value.prefix,
       profile: value.profile,
       template: value.template,
@@ -28,6 +102,7 @@ impl From<RawProgressPluginOptions> for ProgressPluginOptions {
         Either::A(str) => str.chars().map(|c| c.to_string()).collect(),
         Either::B(vec) => vec,
       }),
-    }
+      handler,
+    })
   }
 }
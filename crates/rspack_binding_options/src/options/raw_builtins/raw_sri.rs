@@ -0,0 +1,24 @@
+use napi_derive::napi;
+use rspack_error::Result;
+use rspack_plugin_sri::SubresourceIntegrityPluginOptions;
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawSubresourceIntegrityPluginOptions {
+  #[napi(ts_type = "(\"sha256\" | \"sha384\" | \"sha512\")[]")]
+  pub hash_func_names: Vec<String>,
+}
+
+impl TryFrom<RawSubresourceIntegrityPluginOptions> for SubresourceIntegrityPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawSubresourceIntegrityPluginOptions) -> Result<Self> {
+    Ok(SubresourceIntegrityPluginOptions {
+      hash_func_names: value
+        .hash_func_names
+        .iter()
+        .map(|name| name.parse())
+        .collect::<Result<_>>()?,
+    })
+  }
+}
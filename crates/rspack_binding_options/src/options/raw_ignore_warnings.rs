@@ -0,0 +1,29 @@
+use derivative::Derivative;
+use napi_derive::napi;
+use rspack_core::IgnoreWarning;
+use rspack_napi::regexp::{JsRegExp, JsRegExpExt};
+
+/// The `{ code, module, message }` rules of `ignoreWarnings`, RegExps and functions are applied
+/// by the `IgnoreWarningsPlugin` in JavaScript.
+#[derive(Derivative, Default)]
+#[derivative(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawIgnoreWarning {
+  pub code: Option<String>,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "RegExp")]
+  pub module: Option<JsRegExp>,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "RegExp")]
+  pub message: Option<JsRegExp>,
+}
+
+impl From<RawIgnoreWarning> for IgnoreWarning {
+  fn from(value: RawIgnoreWarning) -> Self {
+    Self {
+      code: value.code,
+      module: value.module.map(|r| r.to_rspack_regex()),
+      message: value.message.map(|r| r.to_rspack_regex()),
+    }
+  }
+}
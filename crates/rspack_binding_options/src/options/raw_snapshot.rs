@@ -1,12 +1,57 @@
+use derivative::Derivative;
+use napi::Either;
 use napi_derive::napi;
-use rspack_core::SnapshotOptions;
+use rspack_core::{SnapshotOptions, SnapshotPath, SnapshotStrategy};
+use rspack_napi::regexp::{JsRegExp, JsRegExpExt};
+
+type RawSnapshotPath = Either<String, JsRegExp>;
 
 #[derive(Debug, Default)]
 #[napi(object)]
-pub struct RawSnapshotOptions;
+pub struct RawSnapshotStrategy {
+  pub hash: Option<bool>,
+  pub timestamp: Option<bool>,
+}
+
+#[derive(Derivative, Default)]
+#[derivative(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawSnapshotOptions {
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(string | RegExp)[]")]
+  pub managed_paths: Option<Vec<RawSnapshotPath>>,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(string | RegExp)[]")]
+  pub immutable_paths: Option<Vec<RawSnapshotPath>>,
+  pub module: Option<RawSnapshotStrategy>,
+}
+
+fn to_snapshot_paths(paths: Option<Vec<RawSnapshotPath>>) -> Vec<SnapshotPath> {
+  paths
+    .unwrap_or_default()
+    .into_iter()
+    .map(|path| match path {
+      Either::A(s) => SnapshotPath::String(s),
+      Either::B(r) => SnapshotPath::Regexp(r.to_rspack_regex()),
+    })
+    .collect()
+}
+
+impl From<RawSnapshotStrategy> for SnapshotStrategy {
+  fn from(value: RawSnapshotStrategy) -> Self {
+    Self {
+      hash: value.hash.unwrap_or_default(),
+      timestamp: value.timestamp.unwrap_or_default(),
+    }
+  }
+}
 
 impl From<RawSnapshotOptions> for SnapshotOptions {
-  fn from(_value: RawSnapshotOptions) -> Self {
-    SnapshotOptions
+  fn from(value: RawSnapshotOptions) -> Self {
+    SnapshotOptions {
+      managed_paths: to_snapshot_paths(value.managed_paths),
+      immutable_paths: to_snapshot_paths(value.immutable_paths),
+      module: value.module.map(Into::into).unwrap_or_default(),
+    }
   }
 }
@@ -44,13 +44,14 @@ pub struct RawSplitChunksOptions {
   pub min_chunks: Option<u32>,
   pub hide_path_info: Option<bool>,
   pub min_size: Option<Either<f64, RawSplitChunkSizes>>,
-  //   pub min_size_reduction: usize,
-  pub enforce_size_threshold: Option<f64>,
+  pub min_size_reduction: Option<Either<f64, RawSplitChunkSizes>>,
+  pub enforce_size_threshold: Option<Either<f64, RawSplitChunkSizes>>,
   pub min_remaining_size: Option<Either<f64, RawSplitChunkSizes>>,
   // layer: String,
   pub max_size: Option<Either<f64, RawSplitChunkSizes>>,
   pub max_async_size: Option<Either<f64, RawSplitChunkSizes>>,
   pub max_initial_size: Option<Either<f64, RawSplitChunkSizes>>,
+  pub used_exports: Option<bool>,
 }
 
 #[derive(Derivative)]
@@ -79,9 +80,9 @@ pub struct RawCacheGroupOptions {
   //   pub max_initial_requests: usize,
   pub min_chunks: Option<u32>,
   pub min_size: Option<Either<f64, RawSplitChunkSizes>>,
-  //   pub min_size_reduction: usize,
-  //   pub enforce_size_threshold: usize,
-  //   pub min_remaining_size: usize,
+  pub min_size_reduction: Option<Either<f64, RawSplitChunkSizes>>,
+  pub enforce_size_threshold: Option<Either<f64, RawSplitChunkSizes>>,
+  pub min_remaining_size: Option<Either<f64, RawSplitChunkSizes>>,
   // layer: String,
   pub max_size: Option<Either<f64, RawSplitChunkSizes>>,
   pub max_async_size: Option<Either<f64, RawSplitChunkSizes>>,
@@ -89,7 +90,7 @@ pub struct RawCacheGroupOptions {
   #[napi(ts_type = "string | false | Function")]
   #[derivative(Debug = "ignore")]
   pub name: Option<RawChunkOptionName>,
-  // used_exports: bool,
+  pub used_exports: Option<bool>,
   pub reuse_existing_chunk: Option<bool>,
   pub enforce: Option<bool>,
 }
@@ -124,8 +125,16 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks::PluginOptions {
 
     let overall_min_size = create_sizes(raw_opts.min_size);
 
+    let overall_min_size_reduction = create_sizes(raw_opts.min_size_reduction);
+
+    let overall_enforce_size_threshold = create_sizes(raw_opts.enforce_size_threshold);
+
+    let overall_min_remaining_size = create_sizes(raw_opts.min_remaining_size);
+
     let overall_max_size = create_sizes(raw_opts.max_size);
 
+    let overall_used_exports = raw_opts.used_exports.unwrap_or_default();
+
     let overall_max_async_size = create_sizes(raw_opts.max_async_size).merge(&overall_max_size);
 
     let overall_max_initial_size = create_sizes(raw_opts.max_initial_size).merge(&overall_max_size);
@@ -148,6 +157,27 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks::PluginOptions {
             &overall_min_size
           });
 
+          let min_size_reduction = create_sizes(v.min_size_reduction).merge(if enforce {
+            &empty_sizes
+          } else {
+            &overall_min_size_reduction
+          });
+
+          let enforce_size_threshold = create_sizes(v.enforce_size_threshold).merge(if enforce {
+            &empty_sizes
+          } else {
+            &overall_enforce_size_threshold
+          });
+
+          // defaults to `min_size` for cache groups which are not enforced, like webpack
+          let min_remaining_size = if enforce {
+            create_sizes(v.min_remaining_size)
+          } else {
+            create_sizes(v.min_remaining_size)
+              .merge(&overall_min_remaining_size)
+              .merge(&min_size)
+          };
+
           let max_size = create_sizes(v.max_size);
 
           let max_async_size = create_sizes(v.max_async_size)
@@ -199,11 +229,15 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks::PluginOptions {
             }),
             min_chunks,
             min_size,
+            min_size_reduction,
+            min_remaining_size,
+            enforce_size_threshold,
             automatic_name_delimiter: v
               .automatic_name_delimiter
               .unwrap_or(overall_automatic_name_delimiter.clone()),
             filename: v.filename.map(Filename::from),
             reuse_existing_chunk: v.reuse_existing_chunk.unwrap_or(true),
+            used_exports: v.used_exports.unwrap_or(overall_used_exports),
             // TODO(hyf0): the non-enforced default value should be 30
             // I would set align default value with Webpack when the options is exposed to users
             max_async_requests: u32::MAX,
@@ -243,6 +277,7 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks::PluginOptions {
             .unwrap_or_else(rspack_plugin_split_chunks::create_all_chunk_filter)
         }),
         min_size: fallback_min_size,
+        min_remaining_size: overall_min_remaining_size,
         max_async_size: fallback_max_async_size,
         max_initial_size: fallback_max_initial_size,
         automatic_name_delimiter: raw_fallback_cache_group
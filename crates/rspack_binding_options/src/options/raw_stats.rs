@@ -11,6 +11,7 @@ impl From<RawStatsOptions> for StatsOptions {
   fn from(value: RawStatsOptions) -> Self {
     Self {
       colors: value.colors,
+      ..Default::default()
     }
   }
 }
@@ -11,6 +11,7 @@ use indexmap::{IndexMap, IndexSet};
 use itertools::Itertools;
 use rayon::prelude::*;
 use rspack_error::{error, Diagnostic, Result, Severity};
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_futures::FuturesResults;
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_hook::define_hook;
@@ -154,6 +155,7 @@ pub struct Compilation {
   pub plugin_driver: SharedPluginDriver,
   pub resolver_factory: Arc<ResolverFactory>,
   pub loader_resolver_factory: Arc<ResolverFactory>,
+  pub input_filesystem: Arc<dyn AsyncReadableFileSystem + Send + Sync>,
   pub named_chunks: HashMap<String, ChunkUkey>,
   pub named_chunk_groups: HashMap<String, ChunkGroupUkey>,
 
@@ -209,6 +211,7 @@ impl Compilation {
     plugin_driver: SharedPluginDriver,
     resolver_factory: Arc<ResolverFactory>,
     loader_resolver_factory: Arc<ResolverFactory>,
+    input_filesystem: Arc<dyn AsyncReadableFileSystem + Send + Sync>,
     records: Option<CompilationRecords>,
     old_cache: Arc<OldCache>,
     module_executor: Option<ModuleExecutor>,
@@ -239,6 +242,7 @@ impl Compilation {
       plugin_driver,
       resolver_factory,
       loader_resolver_factory,
+      input_filesystem,
       named_chunks: Default::default(),
       named_chunk_groups: Default::default(),
 
@@ -622,7 +626,24 @@ impl Compilation {
     self
       .diagnostics
       .iter()
-      .filter(|d| matches!(d.severity(), Severity::Warn))
+      .filter(|d| matches!(d.severity(), Severity::Warn) && !self.is_warning_ignored(d))
+  }
+
+  fn is_warning_ignored(&self, diagnostic: &Diagnostic) -> bool {
+    let ignore_warnings = &self.options.ignore_warnings;
+    if ignore_warnings.is_empty() {
+      return false;
+    }
+    let module = diagnostic
+      .module_identifier()
+      .and_then(|identifier| self.module_by_identifier(&identifier));
+    let module_resource = module.map(|module| match module.as_normal_module() {
+      Some(normal_module) => normal_module.resource_resolved_data().resource.as_str(),
+      None => module.identifier().as_str(),
+    });
+    ignore_warnings
+      .iter()
+      .any(|ignore| ignore.matches(diagnostic, module_resource))
   }
 
   /// Get sorted warnings based on the factors as follows in order:
@@ -852,6 +873,37 @@ impl Compilation {
   #[instrument(name = "compilation::create_module_assets", skip_all)]
   async fn create_module_assets(&mut self, _plugin_driver: SharedPluginDriver) {
     let mut temp = vec![];
+    let mut emitted_assets: Vec<(String, CompilationAsset)> = vec![];
+    let mut emitted_sources: HashSet<(String, Option<*const ()>)> = HashSet::default();
+    for module in self.get_module_graph().modules().values() {
+      let Some(build_info) = module.build_info() else {
+        continue;
+      };
+      for (name, asset) in &build_info.assets {
+        if self
+          .chunk_graph
+          .chunk_graph_module_by_module_identifier
+          .contains_key(&module.identifier())
+        {
+          for chunk in self.chunk_graph.get_module_chunks(module.identifier()) {
+            temp.push((*chunk, name.clone()));
+          }
+        }
+        // a concatenated module carries the assets of its inner modules, which are still in the
+        // module graph, the same source is only emitted once, different sources of the same
+        // filename are reported as a conflict by `emit_asset`
+        let source = asset
+          .get_source()
+          .map(|source| Arc::as_ptr(source) as *const ());
+        if emitted_sources.insert((name.clone(), source)) {
+          emitted_assets.push((name.clone(), asset.clone()));
+        }
+      }
+    }
+    for (name, asset) in emitted_assets {
+      self.emit_asset(name, asset);
+    }
+
     for (module_identifier, assets) in self.module_assets.iter() {
       // assets of executed modules are not in this compilation
       if self
@@ -879,27 +931,47 @@ impl Compilation {
 
   #[instrument(skip_all)]
   async fn create_chunk_assets(&mut self, plugin_driver: SharedPluginDriver) -> Result<()> {
+    let logger = self.get_logger("rspack.Compilation");
+    let mut chunk_assets_cache_counter = match self.options.cache {
+      CacheOptions::Disabled => None,
+      _ => Some(logger.cache("chunk assets cache")),
+    };
+
     let results = self
       .chunk_by_ukey
       .values()
       .map(|chunk| async {
-        let mut manifest = Vec::new();
-        let mut diagnostics = Vec::new();
-        plugin_driver
-          .compilation_hooks
-          .render_manifest
-          .call(self, &chunk.ukey, &mut manifest, &mut diagnostics)
+        let (manifest, diagnostics, from_cache) = self
+          .old_cache
+          .create_chunk_assets_occasion
+          .use_cache(self, chunk, || async {
+            let mut manifest = Vec::new();
+            let mut diagnostics = Vec::new();
+            plugin_driver
+              .compilation_hooks
+              .render_manifest
+              .call(self, &chunk.ukey, &mut manifest, &mut diagnostics)
+              .await?;
+            Ok((manifest, diagnostics))
+          })
           .await?;
 
-        Ok((chunk.ukey, manifest, diagnostics))
+        Ok((chunk.ukey, manifest, diagnostics, from_cache))
       })
       .collect::<FuturesResults<Result<_>>>();
 
     let chunk_ukey_and_manifest = results.into_inner();
 
     for result in chunk_ukey_and_manifest.into_iter() {
-      let (chunk_ukey, manifest, diagnostics) = result?;
+      let (chunk_ukey, manifest, diagnostics, from_cache) = result?;
       self.extend_diagnostics(diagnostics);
+      if let Some(counter) = &mut chunk_assets_cache_counter {
+        if from_cache {
+          counter.hit();
+        } else {
+          counter.miss();
+        }
+      }
 
       for file_manifest in manifest {
         let filename = file_manifest.filename().to_string();
@@ -926,6 +998,10 @@ impl Compilation {
       }
     }
 
+    if let Some(counter) = chunk_assets_cache_counter {
+      logger.cache_end(counter);
+    }
+
     // TODO: add code_generated_modules in render_runtime_modules
     for (identifier, _) in self.runtime_modules.iter() {
       self.code_generated_modules.insert(*identifier);
@@ -9,7 +9,8 @@ use rspack_sources::Source;
 use rustc_hash::FxHashSet as HashSet;
 
 use crate::{
-  fast_set, get_chunk_from_ukey, ChunkKind, Compilation, Compiler, ModuleExecutor, RuntimeSpec,
+  fast_set, get_chunk_from_ukey, retain_changed_files, ChunkKind, Compilation, Compiler,
+  ModuleExecutor, RuntimeSpec,
 };
 
 impl<T> Compiler<T>
@@ -55,6 +56,7 @@ where
     {
       let mut modified_files = HashSet::default();
       modified_files.extend(changed_files.iter().map(PathBuf::from));
+      retain_changed_files(&self.compilation, &mut modified_files).await;
       let mut removed_files = HashSet::default();
       removed_files.extend(deleted_files.iter().map(PathBuf::from));
 
@@ -72,6 +74,7 @@ where
         self.plugin_driver.clone(),
         self.resolver_factory.clone(),
         self.loader_resolver_factory.clone(),
+        self.input_filesystem.clone(),
         Some(records),
         self.old_cache.clone(),
         Some(ModuleExecutor::default()),
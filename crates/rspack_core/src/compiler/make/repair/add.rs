@@ -77,6 +77,9 @@ impl Task<MakeTaskContext> for AddTask {
       resolver_factory: context.resolver_factory.clone(),
       compiler_options: context.compiler_options.clone(),
       plugin_driver: context.plugin_driver.clone(),
+      fs: context.fs.clone(),
+      module_importer: context.module_importer.clone(),
+      old_cache: context.old_cache.clone(),
     })])
   }
 }
@@ -1,13 +1,15 @@
 use std::{collections::VecDeque, sync::Arc};
 
 use rspack_error::{Diagnostic, IntoTWithDiagnosticArray};
+use rspack_fs::AsyncReadableFileSystem;
 
 use super::{process_dependencies::ProcessDependenciesTask, MakeTaskContext};
 use crate::{
+  old_cache::Cache as OldCache,
   utils::task_loop::{Task, TaskResult, TaskType},
   AsyncDependenciesBlock, BoxDependency, BuildContext, BuildResult, CompilerModuleContext,
-  CompilerOptions, DependencyParents, Module, ModuleProfile, ResolverFactory, RunnerContext,
-  SharedPluginDriver,
+  CompilerOptions, DependencyParents, Module, ModuleImporter, ModuleProfile, ResolverFactory,
+  RunnerContext, SharedPluginDriver,
 };
 
 #[derive(Debug)]
@@ -17,6 +19,9 @@ pub struct BuildTask {
   pub resolver_factory: Arc<ResolverFactory>,
   pub compiler_options: Arc<CompilerOptions>,
   pub plugin_driver: SharedPluginDriver,
+  pub fs: Arc<dyn AsyncReadableFileSystem + Send + Sync>,
+  pub module_importer: Option<ModuleImporter>,
+  pub old_cache: Arc<OldCache>,
 }
 
 #[async_trait::async_trait]
@@ -30,6 +35,9 @@ impl Task<MakeTaskContext> for BuildTask {
       resolver_factory,
       plugin_driver,
       current_profile,
+      fs,
+      module_importer,
+      old_cache,
       mut module,
     } = *self;
     if let Some(current_profile) = &current_profile {
@@ -50,9 +58,13 @@ impl Task<MakeTaskContext> for BuildTask {
             resolver_factory: resolver_factory.clone(),
             module: CompilerModuleContext::from_module(module.as_ref()),
             module_source_map_kind: *module.get_source_map_kind(),
+            module_importer,
+            assets: Default::default(),
           },
           plugin_driver: plugin_driver.clone(),
           compiler_options: &compiler_options,
+          fs,
+          old_cache,
         },
         None,
       )
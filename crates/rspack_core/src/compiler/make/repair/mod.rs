@@ -6,6 +6,7 @@ pub mod process_dependencies;
 use std::sync::Arc;
 
 use rspack_error::Result;
+use rspack_fs::AsyncReadableFileSystem;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use super::MakeArtifact;
@@ -14,7 +15,7 @@ use crate::{
   old_cache::Cache as OldCache,
   utils::task_loop::{run_task_loop, Task},
   BuildDependency, Compilation, CompilerOptions, DependencyType, Module, ModuleFactory,
-  ModuleProfile, NormalModuleSource, ResolverFactory, SharedPluginDriver,
+  ModuleImporter, ModuleProfile, NormalModuleSource, ResolverFactory, SharedPluginDriver,
 };
 
 pub struct MakeTaskContext {
@@ -23,8 +24,10 @@ pub struct MakeTaskContext {
   pub compiler_options: Arc<CompilerOptions>,
   pub resolver_factory: Arc<ResolverFactory>,
   pub loader_resolver_factory: Arc<ResolverFactory>,
+  pub fs: Arc<dyn AsyncReadableFileSystem + Send + Sync>,
   pub old_cache: Arc<OldCache>,
   pub dependency_factories: HashMap<DependencyType, Arc<dyn ModuleFactory>>,
+  pub module_importer: Option<ModuleImporter>,
 
   pub artifact: MakeArtifact,
 }
@@ -36,8 +39,13 @@ impl MakeTaskContext {
       compiler_options: compilation.options.clone(),
       resolver_factory: compilation.resolver_factory.clone(),
       loader_resolver_factory: compilation.loader_resolver_factory.clone(),
+      fs: compilation.input_filesystem.clone(),
       old_cache: compilation.old_cache.clone(),
       dependency_factories: compilation.dependency_factories.clone(),
+      module_importer: compilation
+        .module_executor
+        .as_ref()
+        .and_then(|module_executor| module_executor.importer()),
       artifact,
     }
   }
@@ -59,6 +67,7 @@ impl MakeTaskContext {
       self.plugin_driver.clone(),
       self.resolver_factory.clone(),
       self.loader_resolver_factory.clone(),
+      self.fs.clone(),
       None,
       self.old_cache.clone(),
       None,
@@ -2,12 +2,13 @@ mod compilation;
 mod hmr;
 mod make;
 mod module_executor;
+mod watching;
 
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use rspack_error::Result;
-use rspack_fs::AsyncWritableFileSystem;
+use rspack_fs::{AsyncReadableFileSystem, AsyncWritableFileSystem};
 use rspack_futures::FuturesResults;
 use rspack_hook::define_hook;
 use rspack_sources::BoxSource;
@@ -16,7 +17,10 @@ use tracing::instrument;
 
 pub use self::compilation::*;
 pub use self::hmr::{collect_changed_modules, CompilationRecords};
-pub use self::module_executor::{ExecuteModuleId, ExecutedRuntimeModule, ModuleExecutor};
+pub use self::module_executor::{
+  ExecuteModuleId, ExecuteModuleResult, ExecutedRuntimeModule, ModuleExecutor, ModuleImporter,
+};
+pub use self::watching::{WatchOptions, Watching, WatchingHandle};
 use crate::old_cache::Cache as OldCache;
 use crate::{
   fast_set, BoxPlugin, CompilerOptions, Logger, PluginDriver, ResolverFactory, SharedPluginDriver,
@@ -55,6 +59,7 @@ where
 {
   pub options: Arc<CompilerOptions>,
   pub output_filesystem: T,
+  pub input_filesystem: Arc<dyn AsyncReadableFileSystem + Send + Sync>,
   pub compilation: Compilation,
   pub plugin_driver: SharedPluginDriver,
   pub resolver_factory: Arc<ResolverFactory>,
@@ -74,8 +79,9 @@ where
     options: CompilerOptions,
     plugins: Vec<BoxPlugin>,
     output_filesystem: T,
-    resolver_factory: Arc<ResolverFactory>,
-    loader_resolver_factory: Arc<ResolverFactory>,
+    input_filesystem: Arc<dyn AsyncReadableFileSystem + Send + Sync>,
+    resolver_factory: Option<Arc<ResolverFactory>>,
+    loader_resolver_factory: Option<Arc<ResolverFactory>>,
   ) -> Self {
     #[cfg(debug_assertions)]
     {
@@ -83,6 +89,19 @@ where
         debug_info.with_context(options.context.to_string());
       }
     }
+    // resolve through the input file system unless the resolver factories are shared with others
+    let resolver_factory = resolver_factory.unwrap_or_else(|| {
+      Arc::new(ResolverFactory::new_with_input_filesystem(
+        options.resolve.clone(),
+        input_filesystem.clone(),
+      ))
+    });
+    let loader_resolver_factory = loader_resolver_factory.unwrap_or_else(|| {
+      Arc::new(ResolverFactory::new_with_input_filesystem(
+        options.resolve_loader.clone(),
+        input_filesystem.clone(),
+      ))
+    });
     let (plugin_driver, options) = PluginDriver::new(options, plugins, resolver_factory.clone());
     let old_cache = Arc::new(OldCache::new(options.clone()));
     let module_executor = ModuleExecutor::default();
@@ -93,6 +112,7 @@ where
         plugin_driver.clone(),
         resolver_factory.clone(),
         loader_resolver_factory.clone(),
+        input_filesystem.clone(),
         None,
         old_cache.clone(),
         Some(module_executor),
@@ -100,6 +120,7 @@ where
         Default::default(),
       ),
       output_filesystem,
+      input_filesystem,
       plugin_driver,
       resolver_factory,
       loader_resolver_factory,
@@ -128,6 +149,7 @@ where
         self.plugin_driver.clone(),
         self.resolver_factory.clone(),
         self.loader_resolver_factory.clone(),
+        self.input_filesystem.clone(),
         None,
         self.old_cache.clone(),
         Some(module_executor),
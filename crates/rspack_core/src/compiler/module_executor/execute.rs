@@ -2,7 +2,7 @@ use std::{iter::once, sync::atomic::AtomicU32};
 
 use itertools::Itertools;
 use rayon::prelude::*;
-use rspack_error::Result;
+use rspack_error::{error, Result};
 use rspack_identifier::{Identifier, IdentifierSet};
 use rustc_hash::FxHashMap as HashMap;
 use rustc_hash::FxHashSet as HashSet;
@@ -73,10 +73,28 @@ impl Task<MakeTaskContext> for ExecuteTask {
     let id = EXECUTE_MODULE_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
 
     let mg = compilation.get_module_graph_mut();
-    let entry_module_identifier = mg
+    // the module is missing when the request failed to resolve, the error is reported as a
+    // diagnostic of the factorization
+    let Some(entry_module_identifier) = mg
       .get_module_by_dependency_id(&entry_dep_id)
-      .expect("should have module")
-      .identifier();
+      .map(|module| module.identifier())
+    else {
+      let request = mg
+        .dependency_by_id(&entry_dep_id)
+        .and_then(|dep| dep.as_module_dependency())
+        .map(|dep| dep.request().to_string())
+        .unwrap_or_default();
+      context.recovery_from_temp_compilation(compilation);
+      result_sender
+        .send((
+          Err(error!("Failed to import module '{request}'")),
+          Default::default(),
+          Default::default(),
+          vec![],
+        ))
+        .expect("should send result success");
+      return Ok(vec![]);
+    };
     let mut queue = vec![entry_module_identifier];
     let mut modules = HashSet::default();
 
@@ -3,9 +3,12 @@ mod entry;
 mod execute;
 mod overwrite;
 
+use std::sync::Arc;
+
 use dashmap::DashMap;
 use dashmap::{mapref::entry::Entry, DashSet};
 pub use execute::ExecuteModuleId;
+pub use execute::ExecuteModuleResult;
 pub use execute::ExecutedRuntimeModule;
 use rspack_error::Result;
 use rspack_identifier::Identifier;
@@ -17,7 +20,7 @@ use tokio::sync::{
 use self::{
   ctrl::{CtrlTask, Event},
   entry::EntryParam,
-  execute::{ExecuteModuleResult, ExecuteTask},
+  execute::ExecuteTask,
   overwrite::OverwriteTask,
 };
 use super::make::{repair::MakeTaskContext, update_module_graph, MakeArtifact, MakeParam};
@@ -26,18 +29,46 @@ use crate::{
   DependencyId, LoaderImportDependency, PublicPath,
 };
 
+/// Moves the results collected by the executor tasks out of `shared`. They are only cloned
+/// when a [ModuleImporter] still holds on to them, the shared collection is cleared in place
+/// then, so the importer doesn't keep stale results.
+fn take_shared<T: Clone + Default>(shared: &mut Arc<T>, clear: impl FnOnce(&T)) -> T {
+  match Arc::try_unwrap(std::mem::take(shared)) {
+    Ok(value) => value,
+    Err(value) => {
+      let taken = T::clone(&value);
+      clear(&value);
+      *shared = value;
+      taken
+    }
+  }
+}
+
 #[derive(Debug, Default)]
 pub struct ModuleExecutor {
-  request_dep_map: DashMap<String, DependencyId>,
+  request_dep_map: Arc<DashMap<(Context, String), DependencyId>>,
   pub make_artifact: MakeArtifact,
 
   event_sender: Option<UnboundedSender<Event>>,
   stop_receiver: Option<oneshot::Receiver<MakeArtifact>>,
-  assets: DashMap<String, CompilationAsset>,
-  module_assets: DashMap<Identifier, DashSet<String>>,
-  code_generated_modules: DashSet<Identifier>,
-  module_code_generated_modules: DashMap<Identifier, DashSet<Identifier>>,
-  pub executed_runtime_modules: DashMap<Identifier, ExecutedRuntimeModule>,
+  assets: Arc<DashMap<String, CompilationAsset>>,
+  module_assets: Arc<DashMap<Identifier, DashSet<String>>>,
+  code_generated_modules: Arc<DashSet<Identifier>>,
+  module_code_generated_modules: Arc<DashMap<Identifier, DashSet<Identifier>>>,
+  pub executed_runtime_modules: Arc<DashMap<Identifier, ExecutedRuntimeModule>>,
+}
+
+/// A handle to import modules with the [ModuleExecutor] of a compilation, it's available
+/// to the loaders of the modules built while the executor is running.
+#[derive(Debug, Clone)]
+pub struct ModuleImporter {
+  event_sender: UnboundedSender<Event>,
+  request_dep_map: Arc<DashMap<(Context, String), DependencyId>>,
+  assets: Arc<DashMap<String, CompilationAsset>>,
+  module_assets: Arc<DashMap<Identifier, DashSet<String>>>,
+  code_generated_modules: Arc<DashSet<Identifier>>,
+  module_code_generated_modules: Arc<DashMap<Identifier, DashSet<Identifier>>>,
+  executed_runtime_modules: Arc<DashMap<Identifier, ExecutedRuntimeModule>>,
 }
 
 impl ModuleExecutor {
@@ -73,6 +104,8 @@ impl ModuleExecutor {
     let (stop_sender, stop_receiver) = oneshot::channel();
     self.event_sender = Some(event_sender.clone());
     self.stop_receiver = Some(stop_receiver);
+    // modules built by the executor can import other modules as well
+    ctx.module_importer = self.importer();
 
     tokio::spawn(async move {
       let _ = run_task_loop_with_event(
@@ -106,7 +139,7 @@ impl ModuleExecutor {
       panic!("receive make artifact failed");
     }
 
-    let module_assets = std::mem::take(&mut self.module_assets);
+    let module_assets = take_shared(&mut self.module_assets, DashMap::clear);
     for (original_module_identifier, files) in module_assets {
       let assets = compilation
         .module_assets
@@ -117,7 +150,8 @@ impl ModuleExecutor {
       }
     }
 
-    let module_code_generation_modules = std::mem::take(&mut self.module_code_generated_modules);
+    let module_code_generation_modules =
+      take_shared(&mut self.module_code_generated_modules, DashMap::clear);
     for (original_module_identifier, code_generation_modules) in module_code_generation_modules {
       for module_identifier in code_generation_modules {
         if let Some(module_assets) = compilation.module_assets.remove(&module_identifier) {
@@ -130,7 +164,7 @@ impl ModuleExecutor {
       }
     }
 
-    let assets = std::mem::take(&mut self.assets);
+    let assets = take_shared(&mut self.assets, DashMap::clear);
     for (filename, asset) in assets {
       compilation.emit_asset(filename, asset);
     }
@@ -143,13 +177,26 @@ impl ModuleExecutor {
       compilation.built_modules.insert(id);
     }
 
-    let code_generated_modules = std::mem::take(&mut self.code_generated_modules);
+    let code_generated_modules = take_shared(&mut self.code_generated_modules, DashSet::clear);
     for id in code_generated_modules {
       compilation.code_generated_modules.insert(id);
     }
   }
 
-  #[allow(clippy::too_many_arguments)]
+  /// The importer of the running executor, it's only available between
+  /// [ModuleExecutor::hook_before_make] and [ModuleExecutor::hook_after_finish_modules].
+  pub fn importer(&self) -> Option<ModuleImporter> {
+    Some(ModuleImporter {
+      event_sender: self.event_sender.clone()?,
+      request_dep_map: self.request_dep_map.clone(),
+      assets: self.assets.clone(),
+      module_assets: self.module_assets.clone(),
+      code_generated_modules: self.code_generated_modules.clone(),
+      module_code_generated_modules: self.module_code_generated_modules.clone(),
+      executed_runtime_modules: self.executed_runtime_modules.clone(),
+    })
+  }
+
   pub async fn import_module(
     &self,
     request: String,
@@ -158,16 +205,37 @@ impl ModuleExecutor {
     original_module_context: Option<Context>,
     original_module_identifier: Option<Identifier>,
   ) -> Result<ExecuteModuleResult> {
-    let sender = self
-      .event_sender
-      .as_ref()
-      .expect("should have event sender");
-    let (param, dep_id) = match self.request_dep_map.entry(request.clone()) {
+    self
+      .importer()
+      .expect("should have event sender")
+      .import_module(
+        request,
+        public_path,
+        base_uri,
+        original_module_context,
+        original_module_identifier,
+      )
+      .await
+  }
+}
+
+impl ModuleImporter {
+  pub async fn import_module(
+    &self,
+    request: String,
+    public_path: Option<PublicPath>,
+    base_uri: Option<String>,
+    original_module_context: Option<Context>,
+    original_module_identifier: Option<Identifier>,
+  ) -> Result<ExecuteModuleResult> {
+    let sender = &self.event_sender;
+    let context = original_module_context.unwrap_or(Context::from(""));
+    let (param, dep_id) = match self
+      .request_dep_map
+      .entry((context.clone(), request.clone()))
+    {
       Entry::Vacant(v) => {
-        let dep = LoaderImportDependency::new(
-          request.clone(),
-          original_module_context.unwrap_or(Context::from("")),
-        );
+        let dep = LoaderImportDependency::new(request.clone(), context);
         let dep_id = *dep.id();
         v.insert(dep_id);
         (EntryParam::Entry(Box::new(dep)), dep_id)
@@ -0,0 +1,339 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use glob::Pattern;
+use notify::{Config, Event, EventKind, PollWatcher, RecommendedWatcher, RecursiveMode, Watcher};
+use rspack_error::{error, Result};
+use rspack_fs::AsyncWritableFileSystem;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+use crate::{Compilation, Compiler};
+
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+  /// Delay the rebuild after the first change, in milliseconds. Changes made during this period
+  /// are aggregated into one rebuild.
+  pub aggregate_timeout: u64,
+  /// Check for changes every `poll` milliseconds instead of using native file system events.
+  pub poll: Option<u64>,
+  /// Glob patterns of paths that should not be watched.
+  pub ignored: Vec<String>,
+}
+
+impl Default for WatchOptions {
+  fn default() -> Self {
+    Self {
+      aggregate_timeout: 20,
+      poll: None,
+      ignored: Vec::new(),
+    }
+  }
+}
+
+#[derive(Debug)]
+enum WatchingMessage {
+  Change(Vec<PathBuf>),
+  Invalidate,
+  Close,
+}
+
+/// A cloneable handle to control a running [Watching] from other tasks or threads.
+#[derive(Debug, Clone)]
+pub struct WatchingHandle {
+  sender: UnboundedSender<WatchingMessage>,
+}
+
+impl WatchingHandle {
+  /// Trigger a rebuild without waiting for a file change.
+  pub fn invalidate(&self) {
+    let _ = self.sender.send(WatchingMessage::Invalidate);
+  }
+
+  /// Stop watching after the current build finishes.
+  pub fn close(&self) {
+    let _ = self.sender.send(WatchingMessage::Close);
+  }
+}
+
+/// Watches the file, context and missing dependencies of the last compilation and calls
+/// [Compiler::rebuild] whenever some of them change.
+pub struct Watching<T>
+where
+  T: AsyncWritableFileSystem + Send + Sync,
+{
+  compiler: Compiler<T>,
+  options: WatchOptions,
+  ignored: Vec<Pattern>,
+  sender: UnboundedSender<WatchingMessage>,
+  receiver: UnboundedReceiver<WatchingMessage>,
+  watcher: Option<Box<dyn Watcher + Send>>,
+  /// Watched directories, the value is whether the directory is watched recursively.
+  watched: HashMap<PathBuf, bool>,
+  files: HashSet<PathBuf>,
+  contexts: HashSet<PathBuf>,
+  missing: HashSet<PathBuf>,
+}
+
+impl<T> std::fmt::Debug for Watching<T>
+where
+  T: AsyncWritableFileSystem + Send + Sync,
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Watching")
+      .field("options", &self.options)
+      .field("watched", &self.watched)
+      .finish()
+  }
+}
+
+impl<T> Watching<T>
+where
+  T: AsyncWritableFileSystem + Send + Sync,
+{
+  pub fn new(compiler: Compiler<T>, options: WatchOptions) -> Result<Self> {
+    let ignored = options
+      .ignored
+      .iter()
+      .map(|pattern| {
+        Pattern::new(pattern).map_err(|e| error!("Invalid watch ignore pattern `{pattern}`: {e}"))
+      })
+      .collect::<Result<Vec<_>>>()?;
+    let (sender, receiver) = unbounded_channel();
+    Ok(Self {
+      compiler,
+      options,
+      ignored,
+      sender,
+      receiver,
+      watcher: None,
+      watched: Default::default(),
+      files: Default::default(),
+      contexts: Default::default(),
+      missing: Default::default(),
+    })
+  }
+
+  pub fn handle(&self) -> WatchingHandle {
+    WatchingHandle {
+      sender: self.sender.clone(),
+    }
+  }
+
+  pub fn compiler(&self) -> &Compiler<T> {
+    &self.compiler
+  }
+
+  pub fn into_compiler(self) -> Compiler<T> {
+    self.compiler
+  }
+
+  /// Build once and rebuild on every aggregated change until [WatchingHandle::close] is called.
+  ///
+  /// `handler` is called after each build with its result and the resulting compilation.
+  pub async fn watch<F>(&mut self, mut handler: F) -> Result<()>
+  where
+    F: FnMut(Result<()>, &Compilation),
+  {
+    let result = self.compiler.build().await;
+    handler(result, &self.compiler.compilation);
+
+    loop {
+      self.watch_dependencies()?;
+      let Some((changed_files, deleted_files)) = self.wait_for_changes().await else {
+        break;
+      };
+      let result = self.compiler.rebuild(changed_files, deleted_files).await;
+      handler(result, &self.compiler.compilation);
+    }
+
+    self.watcher = None;
+    self.watched.clear();
+    Ok(())
+  }
+
+  fn create_watcher(&self) -> Result<Box<dyn Watcher + Send>> {
+    let sender = self.sender.clone();
+    let event_handler = move |event: notify::Result<Event>| {
+      let Ok(event) = event else {
+        return;
+      };
+      if matches!(event.kind, EventKind::Access(_)) {
+        return;
+      }
+      let _ = sender.send(WatchingMessage::Change(event.paths));
+    };
+    let watcher: Box<dyn Watcher + Send> = match self.options.poll {
+      Some(interval) => Box::new(
+        PollWatcher::new(
+          event_handler,
+          Config::default().with_poll_interval(Duration::from_millis(interval)),
+        )
+        .map_err(|e| error!("Failed to create the file watcher: {e}"))?,
+      ),
+      None => Box::new(
+        RecommendedWatcher::new(event_handler, Config::default())
+          .map_err(|e| error!("Failed to create the file watcher: {e}"))?,
+      ),
+    };
+    Ok(watcher)
+  }
+
+  fn watch_dependencies(&mut self) -> Result<()> {
+    let compilation = &self.compiler.compilation;
+    let collect = |paths: &mut dyn Iterator<Item = &PathBuf>| {
+      paths
+        .filter(|path| !is_ignored(path, &self.ignored))
+        .cloned()
+        .collect::<HashSet<_>>()
+    };
+    // the dependencies of modules are kept by the make artifacts rather than the compilation
+    self.files = collect(&mut compilation.file_dependencies());
+    self.contexts = collect(&mut compilation.context_dependencies());
+    self.missing = collect(&mut compilation.missing_dependencies());
+    let targets = watch_targets(self.files.iter().chain(&self.missing), &self.contexts);
+
+    if self.watcher.is_none() {
+      self.watcher = Some(self.create_watcher()?);
+    }
+    let Some(watcher) = self.watcher.as_mut() else {
+      return Ok(());
+    };
+
+    for (path, recursive) in &self.watched {
+      if targets.get(path) != Some(recursive) {
+        let _ = watcher.unwatch(path);
+      }
+    }
+    for (path, recursive) in &targets {
+      if self.watched.get(path) == Some(recursive) {
+        continue;
+      }
+      let mode = if *recursive {
+        RecursiveMode::Recursive
+      } else {
+        RecursiveMode::NonRecursive
+      };
+      // the directory may be removed between collecting the targets and watching it,
+      // which will be picked up by the watcher of its parent directory
+      let _ = watcher.watch(path, mode);
+    }
+    self.watched = targets;
+    Ok(())
+  }
+
+  fn is_relevant(&self, path: &Path) -> bool {
+    if is_ignored(path, &self.ignored) {
+      return false;
+    }
+    // creating any ancestor of a missing dependency may be the first step of creating it
+    self.files.contains(path)
+      || self.missing.iter().any(|missing| missing.starts_with(path))
+      || self
+        .contexts
+        .iter()
+        .any(|context| path.starts_with(context))
+  }
+
+  /// Wait for the first relevant change and aggregate the following ones until no change
+  /// happens for `aggregate_timeout`. Returns `None` when watching is closed.
+  async fn wait_for_changes(
+    &mut self,
+  ) -> Option<(
+    std::collections::HashSet<String>,
+    std::collections::HashSet<String>,
+  )> {
+    let mut paths = HashSet::default();
+    let mut invalidated = false;
+    let aggregate_timeout = Duration::from_millis(self.options.aggregate_timeout);
+
+    while paths.is_empty() && !invalidated {
+      match self.receiver.recv().await? {
+        WatchingMessage::Change(changes) => {
+          paths.extend(changes.into_iter().filter(|path| self.is_relevant(path)))
+        }
+        WatchingMessage::Invalidate => invalidated = true,
+        WatchingMessage::Close => return None,
+      }
+    }
+
+    loop {
+      match tokio::time::timeout(aggregate_timeout, self.receiver.recv()).await {
+        Err(_) => break,
+        Ok(None | Some(WatchingMessage::Close)) => return None,
+        Ok(Some(WatchingMessage::Change(changes))) => {
+          paths.extend(changes.into_iter().filter(|path| self.is_relevant(path)))
+        }
+        Ok(Some(WatchingMessage::Invalidate)) => {}
+      }
+    }
+
+    // ask the input file system rather than the disk, it is what the next build reads from
+    let mut changed_files = std::collections::HashSet::new();
+    let mut deleted_files = std::collections::HashSet::new();
+    for path in paths {
+      let exists = self.compiler.input_filesystem.metadata(&path).await.is_ok();
+      let path = path.to_string_lossy().to_string();
+      if exists {
+        changed_files.insert(path);
+      } else {
+        deleted_files.insert(path);
+      }
+    }
+    Some((changed_files, deleted_files))
+  }
+}
+
+fn is_ignored(path: &Path, ignored: &[Pattern]) -> bool {
+  !ignored.is_empty()
+    && path
+      .ancestors()
+      .any(|path| ignored.iter().any(|pattern| pattern.matches_path(path)))
+}
+
+/// Files are watched through their parent directory so that files which are created, removed
+/// or replaced by editors are noticed as well. Missing files are watched through the nearest
+/// existing ancestor.
+fn watch_targets<'a>(
+  files: impl Iterator<Item = &'a PathBuf>,
+  contexts: &HashSet<PathBuf>,
+) -> HashMap<PathBuf, bool> {
+  let mut targets = HashMap::default();
+  for file in files {
+    if let Some(directory) = file.ancestors().skip(1).find(|path| path.is_dir()) {
+      targets.entry(directory.to_path_buf()).or_insert(false);
+    }
+  }
+  for context in contexts {
+    if let Some(directory) = context.ancestors().find(|path| path.is_dir()) {
+      // a directory stays recursive when it is also the nearest ancestor of another target
+      *targets.entry(directory.to_path_buf()).or_insert(false) |= directory == context;
+    }
+  }
+  targets
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn watch_targets_use_existing_directories() {
+    let root = std::env::temp_dir().join(format!("rspack-watching-{}", std::process::id()));
+    let src = root.join("src");
+    std::fs::create_dir_all(&src).unwrap();
+
+    let files = [src.join("index.js"), root.join("missing/a/b.js")];
+    let contexts = [src.clone(), src.join("missing")].into_iter().collect();
+    let targets = watch_targets(files.iter(), &contexts);
+    assert_eq!(targets.len(), 2);
+    assert_eq!(targets.get(&src), Some(&true));
+    assert_eq!(targets.get(&root), Some(&false));
+
+    let ignored = vec![Pattern::new("**/missing").unwrap()];
+    assert!(is_ignored(&root.join("missing/a/b.js"), &ignored));
+    assert!(!is_ignored(&src.join("index.js"), &ignored));
+
+    let _ = std::fs::remove_dir_all(root);
+  }
+}
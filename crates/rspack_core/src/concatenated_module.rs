@@ -562,6 +562,8 @@ impl Module for ConcatenatedModule {
       json_data: Default::default(),
       top_level_declarations: Some(Default::default()),
       module_concatenation_bailout: Default::default(),
+      snapshot: None,
+      assets: Default::default(),
     };
     self.clear_diagnostics();
 
@@ -583,6 +585,14 @@ impl Module for ConcatenatedModule {
         build_info.cacheable = false;
       }
 
+      // populate assets
+      build_info.assets.extend(
+        cur_build_info
+          .assets
+          .iter()
+          .map(|(name, asset)| (name.clone(), asset.clone())),
+      );
+
       // populate dependencies
       for dep_id in module.get_dependencies() {
         let dep = module_graph
@@ -53,6 +53,8 @@ pub enum DependencyType {
   CssCompose,
   // css :export
   CssExport,
+  // css modules :import() and @value from
+  CssIcssImport,
   // css modules local ident
   CssLocalIdent,
   // context element
@@ -126,6 +128,7 @@ impl DependencyType {
       DependencyType::CssImport => "css import",
       DependencyType::CssCompose => "css compose",
       DependencyType::CssExport => "css export",
+      DependencyType::CssIcssImport => "css icss import",
       DependencyType::CssLocalIdent => "css local ident",
       DependencyType::ContextElement(type_prefix) => match type_prefix {
         ContextTypePrefix::Import => "import() context element",
@@ -35,6 +35,10 @@ impl Dependency for LoaderImportDependency {
   fn dependency_type(&self) -> &DependencyType {
     &DependencyType::LoaderImport
   }
+
+  fn get_context(&self) -> Option<&Context> {
+    Some(&self.context)
+  }
 }
 
 impl ModuleDependency for LoaderImportDependency {
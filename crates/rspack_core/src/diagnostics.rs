@@ -7,6 +7,7 @@ use rspack_error::{
   thiserror::{self, Error},
   DiagnosticExt, Error, TraceableError,
 };
+use rspack_sources::{Mapping, SourceMap};
 use rspack_util::ext::AsAny;
 
 use crate::{BoxLoader, ErrorSpan};
@@ -165,3 +166,171 @@ pub fn map_box_diagnostics_to_module_parse_diagnostics(
     })
     .collect()
 }
+
+/// Converts an offset of `src` to a 1-based line and a 0-based column.
+fn offset_to_line_column(src: &str, offset: usize) -> Option<(u32, u32)> {
+  let before = src.get(..offset)?;
+  let line = before.matches('\n').count() + 1;
+  let column = before
+    .rfind('\n')
+    .map_or(before, |i| &before[i + 1..])
+    .chars()
+    .count();
+  Some((line as u32, column as u32))
+}
+
+/// Converts a 1-based line and a 0-based column of `src` to an offset.
+fn line_column_to_offset(src: &str, line: u32, column: u32) -> Option<usize> {
+  let line_start = if line <= 1 {
+    0
+  } else {
+    src
+      .match_indices('\n')
+      .nth(line as usize - 2)
+      .map(|(i, _)| i + 1)?
+  };
+  let line_end = src[line_start..]
+    .find('\n')
+    .map_or(src.len(), |i| line_start + i);
+  Some(
+    src[line_start..line_end]
+      .char_indices()
+      .nth(column as usize)
+      .map_or(line_end, |(i, _)| line_start + i),
+  )
+}
+
+/// Finds the original location of `offset` of the generated source, as the source index and the
+/// offset in the content of that source.
+fn original_offset(
+  generated: &str,
+  offset: usize,
+  mappings: &[Mapping],
+  source_map: &SourceMap,
+) -> Option<(u32, usize)> {
+  let (line, column) = offset_to_line_column(generated, offset)?;
+  let mapping = mappings
+    .iter()
+    .filter(|m| m.generated_line == line && m.generated_column <= column)
+    .max_by_key(|m| m.generated_column)?;
+  let original = mapping.original?;
+  let content = source_map.get_source_content(original.source_index as usize)?;
+  let offset = line_column_to_offset(
+    content,
+    original.original_line,
+    original.original_column + (column - mapping.generated_column),
+  )?;
+  Some((original.source_index, offset))
+}
+
+fn map_diagnostic_to_original_source(
+  diagnostic: &rspack_error::Diagnostic,
+  mappings: &[Mapping],
+  source_map: &SourceMap,
+) -> Option<rspack_error::Diagnostic> {
+  let traceable_error = diagnostic.traceable_error()?;
+  let (start, end) = traceable_error.span();
+  let generated = traceable_error.src();
+  let (source_index, original_start) = original_offset(generated, start, mappings, source_map)?;
+  let content = source_map.get_source_content(source_index as usize)?;
+  let original_end = original_offset(generated, end, mappings, source_map)
+    .filter(|(index, end)| *index == source_index && *end >= original_start)
+    .map_or_else(
+      || {
+        let line_end = content[original_start..]
+          .find('\n')
+          .map_or(content.len(), |i| original_start + i);
+        (original_start + end - start).min(line_end)
+      },
+      |(_, end)| end,
+    );
+  let source: Box<dyn Diagnostic + Send + Sync> = Box::new(traceable_error.clone().with_source(
+    content.to_string(),
+    original_start,
+    original_end,
+  ));
+  let error = if let Some(parse_error) = diagnostic.downcast_ref::<ModuleParseError>() {
+    Error::new(ModuleParseError {
+      message: parse_error.message.clone(),
+      title: parse_error.title,
+      help: parse_error.help.clone(),
+      source,
+    })
+  } else if diagnostic.downcast_ref::<TraceableError>().is_some()
+    || diagnostic
+      .downcast_ref::<Box<dyn Diagnostic + Send + Sync>>()
+      .is_some()
+  {
+    Error::new_boxed(source)
+  } else {
+    // the error is wrapped by an error which can't be rebuilt
+    return None;
+  };
+  let mut mapped = rspack_error::Diagnostic::from(error)
+    .with_module_identifier(diagnostic.module_identifier())
+    .with_file(diagnostic.file().map(ToOwned::to_owned))
+    .with_hide_stack(diagnostic.hide_stack())
+    .with_chunk(diagnostic.chunk())
+    .with_stack(diagnostic.stack());
+  if let Some(code) = diagnostic.diagnostic_code() {
+    mapped = mapped.with_diagnostic_code(code);
+  }
+  Some(mapped)
+}
+
+/// Maps the spans of diagnostics, which point into the source produced by loaders, back to the
+/// original source of the resource with the source map produced by loaders, so the code frame
+/// shows the code the user actually wrote.
+pub fn map_diagnostics_to_original_source(
+  diagnostics: Vec<rspack_error::Diagnostic>,
+  source_map: &SourceMap,
+) -> Vec<rspack_error::Diagnostic> {
+  let mappings = source_map
+    .decoded_mappings()
+    .into_iter()
+    .filter(|mapping| mapping.original.is_some())
+    .collect::<Vec<_>>();
+  diagnostics
+    .into_iter()
+    .map(|diagnostic| {
+      map_diagnostic_to_original_source(&diagnostic, &mappings, source_map).unwrap_or(diagnostic)
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn map_parse_error_to_original_source() {
+    let original = "const a: number = 1;\nfoo(;\n";
+    let generated = "var a = 1;\nfoo(;\n";
+    let source_map = SourceMap::from_json(
+      &serde_json::json!({
+        "version": 3,
+        "sources": ["index.ts"],
+        "sourcesContent": [original],
+        "names": [],
+        "mappings": "AAAA;AACA",
+      })
+      .to_string(),
+    )
+    .expect("should be a valid source map");
+    let error = TraceableError::from_file(
+      generated.to_string(),
+      15,
+      16,
+      "JavaScript parsing error".to_string(),
+      "Expression expected".to_string(),
+    );
+    let diagnostics = map_box_diagnostics_to_module_parse_diagnostics(vec![error.boxed()], &[]);
+
+    let mapped = map_diagnostics_to_original_source(diagnostics, &source_map);
+    let mapped = mapped[0]
+      .traceable_error()
+      .expect("should have a traceable error");
+    assert_eq!(mapped.src(), original);
+    assert_eq!(mapped.span(), (25, 26));
+  }
+}
@@ -727,6 +727,33 @@ impl ExportsInfo {
     true
   }
 
+  /// Port of `getUsageKey` of Webpack's ExportsInfo, the module is used equally in the runtimes
+  /// having the same key.
+  pub fn get_usage_key(&self, runtime: Option<&RuntimeSpec>, mg: &ModuleGraph) -> Vec<UsageState> {
+    let mut key = vec![];
+    self.collect_usage_key(runtime, mg, &mut key);
+    key
+  }
+
+  fn collect_usage_key(
+    &self,
+    runtime: Option<&RuntimeSpec>,
+    mg: &ModuleGraph,
+    key: &mut Vec<UsageState>,
+  ) {
+    if let Some(redirect_to) = self.redirect_to {
+      redirect_to
+        .get_exports_info(mg)
+        .collect_usage_key(runtime, mg, key);
+    } else {
+      key.push(self.other_exports_info.get_used(mg, runtime));
+    }
+    key.push(self._side_effects_only_info.get_used(mg, runtime));
+    for export_info in self.get_ordered_exports() {
+      key.push(export_info.get_used(mg, runtime));
+    }
+  }
+
   pub fn get_used(
     &self,
     name: UsedName,
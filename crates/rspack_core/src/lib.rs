@@ -74,6 +74,8 @@ pub use chunk_graph::*;
 mod build_chunk_graph;
 mod stats;
 pub use stats::*;
+mod snapshot;
+pub use snapshot::*;
 mod runtime;
 mod runtime_module;
 pub use runtime::*;
@@ -1,11 +1,16 @@
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
+use rspack_error::{error, Result};
 use rspack_loader_runner::ResourceData;
 pub use rspack_loader_runner::{run_loaders, Content, Loader, LoaderContext};
+use rspack_sources::BoxSource;
 use rspack_util::source_map::SourceMapKind;
 
 use crate::{
-  CompilerOptions, Context, FactoryMeta, Module, ModuleIdentifier, ModuleType, ResolverFactory,
+  AssetInfo, CompilationAsset, CompilationAssets, CompilerOptions, Context, DependencyCategory,
+  ExecuteModuleResult, FactoryMeta, Module, ModuleIdentifier, ModuleImporter, ModuleType,
+  PublicPath, Resolve, ResolveInnerError, ResolveOptionsWithDependencyType, ResolveResult,
+  ResolverFactory,
 };
 
 #[derive(Debug, Clone)]
@@ -19,6 +24,8 @@ pub struct CompilerModuleContext {
   pub user_request: Option<String>,
   pub raw_request: Option<String>,
   pub factory_meta: Option<FactoryMeta>,
+  pub resolve_options: Option<Box<Resolve>>,
+  pub dependency_category: DependencyCategory,
 }
 
 impl CompilerModuleContext {
@@ -37,6 +44,10 @@ impl CompilerModuleContext {
       factory_meta: normal_module
         .and_then(|normal_module| normal_module.factory_meta())
         .map(|factory_meta| factory_meta.to_owned()),
+      resolve_options: module.get_resolve_options(),
+      dependency_category: normal_module
+        .map(|normal_module| *normal_module.dependency_category())
+        .unwrap_or_default(),
     }
   }
 }
@@ -47,6 +58,101 @@ pub struct RunnerContext {
   pub resolver_factory: Arc<ResolverFactory>,
   pub module: CompilerModuleContext,
   pub module_source_map_kind: SourceMapKind,
+  /// Available while the module executor of the compilation is running.
+  pub module_importer: Option<ModuleImporter>,
+  /// Files emitted by the loaders, see [LoaderContextExt::emit_file].
+  pub assets: CompilationAssets,
 }
 
 pub type BoxLoader = Arc<dyn Loader<RunnerContext>>;
+
+/// Compilation APIs for loaders written in Rust.
+#[async_trait::async_trait]
+pub trait LoaderContextExt {
+  /// Emit a file to the output, it's kept with the module and emitted as long as the module is
+  /// in the compilation.
+  fn emit_file(&mut self, name: String, source: BoxSource, asset_info: Option<AssetInfo>);
+
+  /// Resolve `request` in `context` with the resolve options and the dependency category of the
+  /// module, the files involved are added as dependencies of the module.
+  async fn resolve(&mut self, context: &Path, request: &str) -> Result<ResolveResult>;
+
+  /// Build `request` and execute it with the module executor of the compilation, the
+  /// dependencies of the executed modules are added as dependencies of the module.
+  async fn import_module(
+    &mut self,
+    request: String,
+    public_path: Option<PublicPath>,
+    base_uri: Option<String>,
+  ) -> Result<ExecuteModuleResult>;
+}
+
+#[async_trait::async_trait]
+impl LoaderContextExt for LoaderContext<RunnerContext> {
+  fn emit_file(&mut self, name: String, source: BoxSource, asset_info: Option<AssetInfo>) {
+    self.context.assets.insert(
+      name,
+      CompilationAsset::new(Some(source), asset_info.unwrap_or_default()),
+    );
+  }
+
+  async fn resolve(&mut self, context: &Path, request: &str) -> Result<ResolveResult> {
+    let resolver = self
+      .context
+      .resolver_factory
+      .get(ResolveOptionsWithDependencyType {
+        resolve_options: self.context.module.resolve_options.clone(),
+        resolve_to_context: false,
+        dependency_category: self.context.module.dependency_category,
+      });
+    let mut resolve_context = Default::default();
+    let result = resolver.resolve_with_context(context, request, &mut resolve_context);
+    self
+      .file_dependencies
+      .extend(resolve_context.file_dependencies);
+    self
+      .missing_dependencies
+      .extend(resolve_context.missing_dependencies);
+    result.map_err(|ResolveInnerError::RspackResolver(e)| {
+      error!("Can't resolve '{request}' in '{}': {e}", context.display())
+    })
+  }
+
+  async fn import_module(
+    &mut self,
+    request: String,
+    public_path: Option<PublicPath>,
+    base_uri: Option<String>,
+  ) -> Result<ExecuteModuleResult> {
+    let Some(module_importer) = &self.context.module_importer else {
+      return Err(error!(
+        "importModule is not available when the module is built outside of the make stage"
+      ));
+    };
+    let result = module_importer
+      .import_module(
+        request,
+        public_path,
+        base_uri,
+        self.context.module.context.as_deref().cloned(),
+        Some(self.context.module.module_identifier),
+      )
+      .await?;
+    if !result.cacheable {
+      self.cacheable = false;
+    }
+    self
+      .file_dependencies
+      .extend(result.file_dependencies.iter().cloned());
+    self
+      .context_dependencies
+      .extend(result.context_dependencies.iter().cloned());
+    self
+      .missing_dependencies
+      .extend(result.missing_dependencies.iter().cloned());
+    self
+      .build_dependencies
+      .extend(result.build_dependencies.iter().cloned());
+    Ok(result)
+  }
+}
@@ -1,11 +1,13 @@
 use std::fmt::Display;
 use std::hash::Hash;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::{any::Any, borrow::Cow, fmt::Debug};
 
 use async_trait::async_trait;
 use json::JsonValue;
 use rspack_error::{Diagnosable, Diagnostic, Result};
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_identifier::{Identifiable, Identifier};
 use rspack_sources::Source;
@@ -16,16 +18,19 @@ use rustc_hash::FxHashSet as HashSet;
 
 use crate::concatenated_module::ConcatenatedModule;
 use crate::{
-  AsyncDependenciesBlock, BoxDependency, ChunkGraph, ChunkUkey, CodeGenerationResult, Compilation,
-  CompilerOptions, ConcatenationScope, ConnectionState, Context, ContextModule, DependenciesBlock,
-  DependencyId, DependencyTemplate, ExportInfoProvided, ExternalModule, ImmutableModuleGraph,
-  ModuleDependency, ModuleGraph, ModuleGraphAccessor, ModuleType, MutableModuleGraph, NormalModule,
-  RawModule, Resolve, RunnerContext, RuntimeSpec, SelfModule, SharedPluginDriver, SourceType,
+  old_cache::Cache as OldCache, AsyncDependenciesBlock, BoxDependency, ChunkGraph, ChunkUkey,
+  CodeGenerationResult, Compilation, CompilationAssets, CompilerOptions, ConcatenationScope,
+  ConnectionState, Context, ContextModule, DependenciesBlock, DependencyId, DependencyTemplate,
+  ExportInfoProvided, ExternalModule, ImmutableModuleGraph, ModuleDependency, ModuleGraph,
+  ModuleGraphAccessor, ModuleType, MutableModuleGraph, NormalModule, RawModule, Resolve,
+  RunnerContext, RuntimeSpec, SelfModule, SharedPluginDriver, Snapshot, SourceType,
 };
 pub struct BuildContext<'a> {
   pub runner_context: RunnerContext,
   pub plugin_driver: SharedPluginDriver,
   pub compiler_options: &'a CompilerOptions,
+  pub fs: Arc<dyn AsyncReadableFileSystem + Send + Sync>,
+  pub old_cache: Arc<OldCache>,
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -51,6 +56,10 @@ pub struct BuildInfo {
   pub json_data: Option<JsonValue>,
   pub top_level_declarations: Option<HashSet<Atom>>,
   pub module_concatenation_bailout: Option<String>,
+  /// State of `file_dependencies` when the module was built, see [Snapshot].
+  pub snapshot: Option<Snapshot>,
+  /// Files emitted by the loaders of the module.
+  pub assets: CompilationAssets,
 }
 
 impl Default for BuildInfo {
@@ -69,6 +78,8 @@ impl Default for BuildInfo {
       json_data: None,
       top_level_declarations: None,
       module_concatenation_bailout: None,
+      snapshot: None,
+      assets: Default::default(),
     }
   }
 }
@@ -11,7 +11,10 @@ use std::{
 use bitflags::bitflags;
 use dashmap::DashMap;
 use derivative::Derivative;
-use rspack_error::{error, Diagnosable, Diagnostic, DiagnosticExt, NodeError, Result, Severity};
+use rspack_error::{
+  error, Diagnosable, Diagnostic, DiagnosticExt, IntoTWithDiagnosticArray, NodeError, Result,
+  Severity,
+};
 use rspack_hash::RspackHash;
 use rspack_hook::define_hook;
 use rspack_identifier::Identifiable;
@@ -27,14 +30,15 @@ use rustc_hash::FxHasher;
 use serde_json::json;
 
 use crate::{
-  add_connection_states, contextify, diagnostics::ModuleBuildError, get_context,
-  impl_module_meta_info, AsyncDependenciesBlockIdentifier, BoxLoader, BoxModule, BuildContext,
-  BuildInfo, BuildMeta, BuildResult, ChunkGraph, CodeGenerationResult, Compilation,
-  ConcatenationScope, ConnectionState, Context, DependenciesBlock, DependencyId,
-  DependencyTemplate, FactoryMeta, GenerateContext, GeneratorOptions, LibIdentOptions, Module,
-  ModuleDependency, ModuleGraph, ModuleIdentifier, ModuleType, ParseContext, ParseResult,
-  ParserAndGenerator, ParserOptions, Resolve, RspackLoaderRunnerPlugin, RunnerContext,
-  RuntimeGlobals, RuntimeSpec, SourceType,
+  add_connection_states, contextify,
+  diagnostics::{map_diagnostics_to_original_source, ModuleBuildError},
+  get_context, impl_module_meta_info, AsyncDependenciesBlockIdentifier, BoxLoader, BoxModule,
+  BuildContext, BuildInfo, BuildMeta, BuildResult, ChunkGraph, CodeGenerationResult, Compilation,
+  ConcatenationScope, ConnectionState, Context, DependenciesBlock, DependencyCategory,
+  DependencyId, DependencyTemplate, FactoryMeta, GenerateContext, GeneratorOptions,
+  LibIdentOptions, Module, ModuleDependency, ModuleGraph, ModuleIdentifier, ModuleType,
+  ParseContext, ParseResult, ParserAndGenerator, ParserOptions, Resolve, RspackLoaderRunnerPlugin,
+  RunnerContext, RuntimeGlobals, RuntimeSpec, Snapshot, SourceType,
 };
 
 bitflags! {
@@ -130,6 +134,8 @@ pub struct NormalModule {
 
   /// Resolve options derived from [Rule.resolve]
   resolve_options: Option<Box<Resolve>>,
+  /// Category of the dependency this module is created from
+  dependency_category: DependencyCategory,
   /// Parser options derived from [Rule.parser]
   parser_options: Option<ParserOptions>,
   /// Generator options derived from [Rule.generator]
@@ -193,6 +199,7 @@ impl NormalModule {
     match_resource: Option<ResourceData>,
     resource_data: Arc<ResourceData>,
     resolve_options: Option<Box<Resolve>>,
+    dependency_category: DependencyCategory,
     loaders: Vec<BoxLoader>,
   ) -> Self {
     let module_type = module_type.into();
@@ -212,6 +219,7 @@ impl NormalModule {
       match_resource,
       resource_data,
       resolve_options,
+      dependency_category,
       loaders,
       original_source: None,
       source: NormalModuleSource::Unbuild,
@@ -255,6 +263,10 @@ impl NormalModule {
     &self.raw_request
   }
 
+  pub fn dependency_category(&self) -> &DependencyCategory {
+    &self.dependency_category
+  }
+
   pub fn source(&self) -> &NormalModuleSource {
     &self.source
   }
@@ -392,14 +404,30 @@ impl Module for NormalModule {
 
     let additional_data = AdditionalData::default();
 
-    let loader_result = run_loaders(
-      self.loaders.clone(),
-      self.resource_data.clone(),
-      Some(plugin.clone()),
-      build_context.runner_context,
-      additional_data,
-    )
-    .await;
+    let cached_loader_result = build_context
+      .old_cache
+      .build_module_occasion
+      .get(&self.identifier(), &*build_context.fs)
+      .await;
+    let from_cache = cached_loader_result.is_some();
+    let loader_result = match cached_loader_result {
+      Some(cached) => Ok(
+        cached
+          .into_loader_result(build_context.runner_context)
+          .with_empty_diagnostic(),
+      ),
+      None => {
+        run_loaders(
+          self.loaders.clone(),
+          self.resource_data.clone(),
+          Some(plugin.clone()),
+          build_context.runner_context,
+          build_context.fs.clone(),
+          additional_data,
+        )
+        .await
+      }
+    };
     let (mut loader_result, ds) = match loader_result {
       Ok(r) => r.split_into_parts(),
       Err(r) => {
@@ -425,12 +453,41 @@ impl Module for NormalModule {
         });
       }
     };
+    let resource_content = self
+      .resource_data
+      .resource_path
+      .as_deref()
+      .zip(loader_result.resource_content.as_ref())
+      .map(|(path, content)| (path, content.as_bytes()));
+    build_info.snapshot = Snapshot::create(
+      &*build_context.fs,
+      &build_context.compiler_options.snapshot,
+      loader_result.file_dependencies.iter(),
+      resource_content.as_slice(),
+    )
+    .await;
+    if !from_cache {
+      build_context.old_cache.build_module_occasion.set(
+        self.identifier(),
+        &loader_result,
+        &ds,
+        build_info.snapshot.as_ref(),
+      );
+    }
+
     build_context
       .plugin_driver
       .normal_module_hooks
       .additional_data
       .call(&mut loader_result.additional_data)
       .await?;
+    // spans of diagnostics emitted by the loaders and of parse errors point into the output of
+    // the loaders, which is mapped back with the source map of the loaders
+    let loader_source_map = loader_result.source_map.clone();
+    let ds = match &loader_source_map {
+      Some(source_map) if !ds.is_empty() => map_diagnostics_to_original_source(ds, source_map),
+      _ => ds,
+    };
     self.add_diagnostics(ds);
 
     let content = if self.module_type().is_binary() {
@@ -457,6 +514,7 @@ impl Module for NormalModule {
       build_info.context_dependencies = loader_result.context_dependencies;
       build_info.missing_dependencies = loader_result.missing_dependencies;
       build_info.build_dependencies = loader_result.build_dependencies;
+      build_info.assets = loader_result.context.assets;
 
       return Ok(BuildResult {
         build_info,
@@ -472,6 +530,7 @@ impl Module for NormalModule {
     build_info.context_dependencies = loader_result.context_dependencies;
     build_info.missing_dependencies = loader_result.missing_dependencies;
     build_info.build_dependencies = loader_result.build_dependencies;
+    build_info.assets = loader_result.context.assets;
 
     let (
       ParseResult {
@@ -502,6 +561,10 @@ impl Module for NormalModule {
       })?
       .split_into_parts();
     if !diagnostics.is_empty() {
+      let diagnostics = match &loader_source_map {
+        Some(source_map) => map_diagnostics_to_original_source(diagnostics, source_map),
+        None => diagnostics,
+      };
       self.add_diagnostics(diagnostics);
       build_meta = self.last_successful_build_meta.clone();
     } else {
@@ -585,6 +585,7 @@ impl NormalModuleFactory {
         match_resource_data,
         Arc::new(create_data.resource_resolve_data.clone()),
         resolved_resolve_options,
+        dependency_category,
         loaders,
       )
       .boxed()
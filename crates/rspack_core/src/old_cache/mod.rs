@@ -6,18 +6,19 @@ use std::{
   },
 };
 
-use crate::CompilerOptions;
+use crate::{CacheOptions, CompilerOptions};
 
 mod local;
 mod occasion;
 mod storage;
 pub use local::*;
-use occasion::{CodeGenerateOccasion, CreateChunkAssetsOccasion};
+use occasion::{BuildModuleOccasion, CodeGenerateOccasion, CreateChunkAssetsOccasion};
 use storage::new_storage;
 
 #[derive(Debug)]
 pub struct Cache {
   is_idle: AtomicBool,
+  pub build_module_occasion: BuildModuleOccasion,
   pub code_generate_occasion: CodeGenerateOccasion,
   pub create_chunk_assets_occasion: CreateChunkAssetsOccasion,
 }
@@ -26,22 +27,45 @@ impl Cache {
   pub fn new(options: Arc<CompilerOptions>) -> Self {
     Self {
       is_idle: true.into(),
-      code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options.cache)),
-      create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(&options.cache)),
+      // unchanged modules are not rebuilt while the compiler is alive, loader results are only
+      // worth keeping for the next compiler
+      build_module_occasion: BuildModuleOccasion::new(match &options.cache {
+        CacheOptions::FileSystem(_) => new_storage(&options.cache, "build-module"),
+        _ => None,
+      }),
+      code_generate_occasion: CodeGenerateOccasion::new(new_storage(
+        &options.cache,
+        "code-generate",
+      )),
+      create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(
+        &options.cache,
+        "create-chunk-assets",
+      )),
     }
   }
 
-  pub fn set_modified_files(&self, _modified_files: Vec<PathBuf>) {
-    // TODO
+  pub fn set_modified_files(&self, modified_files: Vec<PathBuf>) {
+    self
+      .code_generate_occasion
+      .evict_modified_files(&modified_files);
+    self
+      .create_chunk_assets_occasion
+      .evict_modified_files(&modified_files);
   }
 
   pub fn begin_idle(&self) {
-    if self.is_idle.load(Ordering::Relaxed) {
-      // TODO clean cache
+    if !self.is_idle.swap(true, Ordering::Relaxed) {
+      self.build_module_occasion.begin_idle();
+      self.code_generate_occasion.begin_idle();
+      self.create_chunk_assets_occasion.begin_idle();
     }
   }
 
   pub fn end_idle(&self) {
-    self.is_idle.store(false, Ordering::Relaxed);
+    if self.is_idle.swap(false, Ordering::Relaxed) {
+      self.build_module_occasion.end_idle();
+      self.code_generate_occasion.end_idle();
+      self.create_chunk_assets_occasion.end_idle();
+    }
   }
 }
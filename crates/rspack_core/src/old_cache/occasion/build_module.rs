@@ -0,0 +1,175 @@
+use std::path::PathBuf;
+
+use rspack_base64::base64;
+use rspack_error::Diagnostic;
+use rspack_fs::AsyncReadableFileSystem;
+use rspack_identifier::Identifier;
+use rspack_loader_runner::{Content, LoaderResult};
+use rspack_sources::SourceMap;
+use rustc_hash::FxHashSet as HashSet;
+use serde::{Deserialize, Serialize};
+
+use crate::old_cache::storage::{self, Persistable};
+use crate::{RunnerContext, Snapshot};
+
+type Storage = dyn storage::Storage<CachedLoaderResult>;
+
+/// The result of the loaders of a module, it is restored instead of running the loaders again
+/// as long as the file dependencies of the module are unchanged according to its snapshot.
+#[derive(Debug, Clone)]
+pub struct CachedLoaderResult {
+  content: Content,
+  source_map: Option<SourceMap>,
+  file_dependencies: HashSet<PathBuf>,
+  build_dependencies: HashSet<PathBuf>,
+  snapshot: Snapshot,
+}
+
+impl CachedLoaderResult {
+  pub fn into_loader_result(self, context: RunnerContext) -> LoaderResult<RunnerContext> {
+    LoaderResult {
+      cacheable: true,
+      file_dependencies: self.file_dependencies,
+      context_dependencies: Default::default(),
+      missing_dependencies: Default::default(),
+      build_dependencies: self.build_dependencies,
+      content: self.content,
+      resource_content: None,
+      source_map: self.source_map,
+      additional_data: Default::default(),
+      context,
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum PersistedContent {
+  String { content: String },
+  Buffer { base64: String },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedLoaderResult {
+  content: PersistedContent,
+  source_map: Option<String>,
+  file_dependencies: Vec<PathBuf>,
+  build_dependencies: Vec<PathBuf>,
+  snapshot: Snapshot,
+}
+
+impl Persistable for CachedLoaderResult {
+  fn to_bytes(&self) -> Option<Vec<u8>> {
+    let content = match &self.content {
+      Content::String(content) => PersistedContent::String {
+        content: content.clone(),
+      },
+      Content::Buffer(buffer) => PersistedContent::Buffer {
+        base64: base64::encode_to_string(buffer),
+      },
+    };
+    let source_map = match &self.source_map {
+      Some(source_map) => Some(source_map.clone().to_json().ok()?),
+      None => None,
+    };
+    serde_json::to_vec(&PersistedLoaderResult {
+      content,
+      source_map,
+      file_dependencies: self.file_dependencies.iter().cloned().collect(),
+      build_dependencies: self.build_dependencies.iter().cloned().collect(),
+      snapshot: self.snapshot.clone(),
+    })
+    .ok()
+  }
+
+  fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    let persisted: PersistedLoaderResult = serde_json::from_slice(bytes).ok()?;
+    let content = match persisted.content {
+      PersistedContent::String { content } => Content::String(content),
+      PersistedContent::Buffer { base64 } => Content::Buffer(base64::decode_to_vec(base64).ok()?),
+    };
+    let source_map = match persisted.source_map {
+      Some(source_map) => Some(SourceMap::from_json(&source_map).ok()?),
+      None => None,
+    };
+    Some(Self {
+      content,
+      source_map,
+      file_dependencies: persisted.file_dependencies.into_iter().collect(),
+      build_dependencies: persisted.build_dependencies.into_iter().collect(),
+      snapshot: persisted.snapshot,
+    })
+  }
+}
+
+#[derive(Debug)]
+pub struct BuildModuleOccasion {
+  storage: Option<Box<Storage>>,
+}
+
+impl BuildModuleOccasion {
+  pub fn new(storage: Option<Box<Storage>>) -> Self {
+    Self { storage }
+  }
+
+  pub fn begin_idle(&self) {
+    if let Some(storage) = &self.storage {
+      storage.begin_idle();
+    }
+  }
+
+  pub fn end_idle(&self) {
+    if let Some(storage) = &self.storage {
+      storage.end_idle();
+    }
+  }
+
+  /// The cached loader result of `module`, `None` if any of its file dependencies changed.
+  pub async fn get(
+    &self,
+    module: &Identifier,
+    fs: &(dyn AsyncReadableFileSystem + Send + Sync),
+  ) -> Option<CachedLoaderResult> {
+    let cached = self.storage.as_ref()?.get(module)?;
+    for file in &cached.file_dependencies {
+      if !cached.snapshot.is_unchanged(fs, file).await {
+        return None;
+      }
+    }
+    Some(cached)
+  }
+
+  /// Cache the loader result of `module`. Results depending on anything the snapshot can not
+  /// validate, or with side effects like emitted files and diagnostics, are not cached.
+  pub fn set(
+    &self,
+    module: Identifier,
+    loader_result: &LoaderResult<RunnerContext>,
+    diagnostics: &[Diagnostic],
+    snapshot: Option<&Snapshot>,
+  ) {
+    let (Some(storage), Some(snapshot)) = (&self.storage, snapshot) else {
+      return;
+    };
+    if !loader_result.cacheable
+      || !loader_result.context_dependencies.is_empty()
+      || !loader_result.missing_dependencies.is_empty()
+      || !loader_result.additional_data.is_empty()
+      || !loader_result.context.assets.is_empty()
+      || !diagnostics.is_empty()
+    {
+      storage.remove(&module);
+      return;
+    }
+    storage.set(
+      module,
+      CachedLoaderResult {
+        content: loader_result.content.clone(),
+        source_map: loader_result.source_map.clone(),
+        file_dependencies: loader_result.file_dependencies.clone(),
+        build_dependencies: loader_result.build_dependencies.clone(),
+        snapshot: snapshot.clone(),
+      },
+    );
+  }
+}
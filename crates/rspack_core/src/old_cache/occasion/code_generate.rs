@@ -1,19 +1,113 @@
+use std::path::PathBuf;
+
 use rspack_error::Result;
+use rspack_hash::RspackHashDigest;
 use rspack_identifier::Identifier;
+use serde::{Deserialize, Serialize};
 
-use crate::{get_runtime_key, RuntimeSpec, RuntimeSpecSet};
+use super::file_index::FileIndex;
+use crate::old_cache::storage::{Persistable, PersistedSource};
+use crate::{get_runtime_key, RuntimeGlobals, RuntimeSpec, RuntimeSpecSet, SourceType};
 use crate::{old_cache::storage, BoxModule, CodeGenerationResult, Compilation, NormalModuleSource};
 
 type Storage = dyn storage::Storage<Vec<(CodeGenerationResult, RuntimeSpec)>>;
 
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedCodeGenerationResult {
+  sources: Vec<(String, PersistedSource)>,
+  runtime_requirements: u128,
+  hash: Option<String>,
+  runtime: Vec<String>,
+}
+
+impl Persistable for Vec<(CodeGenerationResult, RuntimeSpec)> {
+  fn to_bytes(&self) -> Option<Vec<u8>> {
+    let results = self
+      .iter()
+      .map(|(result, runtime)| {
+        // code generation data and init fragments are type erased, they can not be restored
+        if !result.data.is_empty()
+          || !result.chunk_init_fragments.is_empty()
+          || result.concatenation_scope.is_some()
+        {
+          return None;
+        }
+        Some(PersistedCodeGenerationResult {
+          sources: result
+            .inner
+            .iter()
+            .map(|(source_type, source)| {
+              Some((
+                source_type.to_string(),
+                PersistedSource::from_source(source)?,
+              ))
+            })
+            .collect::<Option<Vec<_>>>()?,
+          runtime_requirements: result.runtime_requirements.bits(),
+          hash: result.hash.as_ref().map(|hash| hash.encoded().to_string()),
+          runtime: runtime.iter().map(|r| r.to_string()).collect(),
+        })
+      })
+      .collect::<Option<Vec<_>>>()?;
+    serde_json::to_vec(&results).ok()
+  }
+
+  fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    let results: Vec<PersistedCodeGenerationResult> = serde_json::from_slice(bytes).ok()?;
+    results
+      .into_iter()
+      .map(|persisted| {
+        let mut result = CodeGenerationResult::default();
+        for (source_type, source) in persisted.sources {
+          let source_type = match source_type.as_str() {
+            "runtime" => SourceType::Runtime,
+            source_type => SourceType::from(source_type),
+          };
+          result.inner.insert(source_type, source.into_source("")?);
+        }
+        result.runtime_requirements = RuntimeGlobals::from_bits(persisted.runtime_requirements)?;
+        result.hash = persisted.hash.as_deref().map(RspackHashDigest::from);
+        let runtime = persisted.runtime.into_iter().map(Into::into).collect();
+        Some((result, runtime))
+      })
+      .collect()
+  }
+}
+
 #[derive(Debug)]
 pub struct CodeGenerateOccasion {
   storage: Option<Box<Storage>>,
+  file_index: FileIndex,
 }
 
 impl CodeGenerateOccasion {
   pub fn new(storage: Option<Box<Storage>>) -> Self {
-    Self { storage }
+    Self {
+      storage,
+      file_index: Default::default(),
+    }
+  }
+
+  /// Evict the entries generated from any of the modified files.
+  pub fn evict_modified_files(&self, modified_files: &[PathBuf]) {
+    if let Some(storage) = &self.storage {
+      for id in self.file_index.take_affected(modified_files) {
+        storage.remove(&id);
+      }
+    }
+  }
+
+  pub fn begin_idle(&self) {
+    if let Some(storage) = &self.storage {
+      let dropped = storage.begin_idle();
+      self.file_index.remove_entries(&dropped);
+    }
+  }
+
+  pub fn end_idle(&self) {
+    if let Some(storage) = &self.storage {
+      storage.end_idle();
+    }
   }
 
   pub fn use_cache<'a, G>(
@@ -48,6 +142,7 @@ impl CodeGenerateOccasion {
         id.push_str(&get_runtime_key(runtime.clone()));
       }
       let id = Identifier::from(id);
+      self.file_index.add_module(id, module.as_ref());
 
       // currently no need to separate module hash by runtime
       if let Some(data) = storage.get(&id) {
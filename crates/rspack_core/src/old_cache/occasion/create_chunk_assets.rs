@@ -1,63 +1,222 @@
+use std::path::PathBuf;
+
 use futures::Future;
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result};
 use rspack_identifier::Identifier;
+use rustc_hash::FxHashSet as HashSet;
+use serde::{Deserialize, Serialize};
 
-use crate::{old_cache::storage, Chunk, Compilation, NormalModuleSource, RenderManifestEntry};
+use super::file_index::FileIndex;
+use crate::old_cache::storage::{Persistable, PersistedSource};
+use crate::{
+  old_cache::storage, AssetInfo, AssetInfoRelated, Chunk, Compilation, RenderManifestEntry,
+};
 
 type Storage = dyn storage::Storage<Vec<RenderManifestEntry>>;
 
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedAssetInfo {
+  immutable: bool,
+  minimized: bool,
+  full_hash: HashSet<String>,
+  chunk_hash: HashSet<String>,
+  content_hash: HashSet<String>,
+  source_filename: Option<String>,
+  development: bool,
+  hot_module_replacement: bool,
+  javascript_module: Option<bool>,
+  related_source_map: Option<String>,
+  version: String,
+  css_unused_idents: Option<HashSet<String>>,
+  extras: serde_json::Map<String, serde_json::Value>,
+}
+
+impl From<&AssetInfo> for PersistedAssetInfo {
+  fn from(info: &AssetInfo) -> Self {
+    Self {
+      immutable: info.immutable,
+      minimized: info.minimized,
+      full_hash: info.full_hash.clone(),
+      chunk_hash: info.chunk_hash.clone(),
+      content_hash: info.content_hash.clone(),
+      source_filename: info.source_filename.clone(),
+      development: info.development,
+      hot_module_replacement: info.hot_module_replacement,
+      javascript_module: info.javascript_module,
+      related_source_map: info.related.source_map.clone(),
+      version: info.version.clone(),
+      css_unused_idents: info.css_unused_idents.clone(),
+      extras: info.extras.clone(),
+    }
+  }
+}
+
+impl From<PersistedAssetInfo> for AssetInfo {
+  fn from(info: PersistedAssetInfo) -> Self {
+    Self {
+      immutable: info.immutable,
+      minimized: info.minimized,
+      full_hash: info.full_hash,
+      chunk_hash: info.chunk_hash,
+      content_hash: info.content_hash,
+      source_filename: info.source_filename,
+      development: info.development,
+      hot_module_replacement: info.hot_module_replacement,
+      javascript_module: info.javascript_module,
+      related: AssetInfoRelated {
+        source_map: info.related_source_map,
+      },
+      version: info.version,
+      css_unused_idents: info.css_unused_idents,
+      extras: info.extras,
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedRenderManifestEntry {
+  source: PersistedSource,
+  filename: String,
+  info: PersistedAssetInfo,
+  auxiliary: bool,
+  has_filename: bool,
+}
+
+impl Persistable for Vec<RenderManifestEntry> {
+  fn to_bytes(&self) -> Option<Vec<u8>> {
+    let entries = self
+      .iter()
+      .map(|entry| {
+        Some(PersistedRenderManifestEntry {
+          source: PersistedSource::from_source(entry.source())?,
+          filename: entry.filename().to_string(),
+          info: (&entry.info).into(),
+          auxiliary: entry.auxiliary,
+          has_filename: entry.has_filename(),
+        })
+      })
+      .collect::<Option<Vec<_>>>()?;
+    serde_json::to_vec(&entries).ok()
+  }
+
+  fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    let entries: Vec<PersistedRenderManifestEntry> = serde_json::from_slice(bytes).ok()?;
+    entries
+      .into_iter()
+      .map(|entry| {
+        Some(RenderManifestEntry::new(
+          entry.source.into_source(&entry.filename)?,
+          entry.filename,
+          entry.info.into(),
+          entry.auxiliary,
+          entry.has_filename,
+        ))
+      })
+      .collect()
+  }
+}
+
 #[derive(Debug)]
 pub struct CreateChunkAssetsOccasion {
   storage: Option<Box<Storage>>,
+  file_index: FileIndex,
 }
 
 impl CreateChunkAssetsOccasion {
   pub fn new(storage: Option<Box<Storage>>) -> Self {
-    Self { storage }
+    Self {
+      storage,
+      file_index: Default::default(),
+    }
+  }
+
+  /// Evict the assets of chunks containing modules generated from any of the modified files.
+  pub fn evict_modified_files(&self, modified_files: &[PathBuf]) {
+    if let Some(storage) = &self.storage {
+      for id in self.file_index.take_affected(modified_files) {
+        storage.remove(&id);
+      }
+    }
   }
 
-  pub async fn use_cache<'a, G, F>(
+  pub fn begin_idle(&self) {
+    if let Some(storage) = &self.storage {
+      let dropped = storage.begin_idle();
+      self.file_index.remove_entries(&dropped);
+    }
+  }
+
+  pub fn end_idle(&self) {
+    if let Some(storage) = &self.storage {
+      storage.end_idle();
+    }
+  }
+
+  /// Render the manifest of `chunk` or restore it from the cache. Entries are keyed by the hashes
+  /// of the chunk, so they stay valid as long as the content of the chunk is unchanged.
+  pub async fn use_cache<G, F>(
     &self,
     compilation: &Compilation,
     chunk: &Chunk,
     generator: G,
-  ) -> Result<Vec<RenderManifestEntry>>
+  ) -> Result<(Vec<RenderManifestEntry>, Vec<Diagnostic>, bool)>
   where
-    G: Fn() -> F,
-    F: Future<Output = Result<Vec<RenderManifestEntry>>>,
+    G: FnOnce() -> F,
+    F: Future<Output = Result<(Vec<RenderManifestEntry>, Vec<Diagnostic>)>>,
   {
-    let storage = match &self.storage {
-      Some(s) => s,
+    let (Some(storage), Some(chunk_hash)) = (&self.storage, &chunk.hash) else {
       // no cache return directly
-      None => return generator().await,
+      let (manifest, diagnostics) = generator().await?;
+      return Ok((manifest, diagnostics, false));
     };
 
-    let chunk_id = Identifier::from(chunk.expect_id());
-    let modules = &compilation
-      .chunk_graph
-      .get_chunk_graph_chunk(&chunk.ukey)
-      .modules;
-    let is_cache_valid = modules.iter().all(|module_id| {
-      matches!(
-        compilation
-          .get_module_graph()
-          .module_by_identifier(module_id)
-          .and_then(|m| m.as_normal_module())
-          .map(|m| matches!(m.source(), NormalModuleSource::Unbuild)),
-        Some(true)
-      )
-    });
-
-    if is_cache_valid {
-      // read
-      if let Some(data) = storage.get(&chunk_id) {
-        return Ok(data);
-      }
+    let mut content_hashes = chunk
+      .content_hash
+      .iter()
+      .map(|(source_type, hash)| format!("{source_type}:{}", hash.encoded()))
+      .collect::<Vec<_>>();
+    content_hashes.sort_unstable();
+    let id = Identifier::from(format!(
+      "{}|{}|{}",
+      chunk.expect_id(),
+      chunk_hash.encoded(),
+      content_hashes.join("|")
+    ));
+
+    if let Some(data) = storage.get(&id)
+      && uses_current_full_hash(&data, compilation)
+    {
+      return Ok((data, vec![], true));
     }
     // run generator and save to cache
-    let data = generator().await?;
-    // TODO sometime may not run save
-    storage.set(chunk_id, data.clone());
-    Ok(data)
+    let (data, diagnostics) = generator().await?;
+    // diagnostics are not restored, only clean results are cached
+    if diagnostics.is_empty() {
+      let module_graph = compilation.get_module_graph();
+      for module in compilation
+        .chunk_graph
+        .get_chunk_graph_chunk(&chunk.ukey)
+        .modules
+        .iter()
+        .filter_map(|module_id| module_graph.module_by_identifier(module_id))
+      {
+        self.file_index.add_module(id, module.as_ref());
+      }
+      storage.set(id, data.clone());
+    }
+    Ok((data, diagnostics, false))
   }
 }
+
+/// Whether the full hash rendered into the assets, e.g. `[fullhash]` in filenames, is the hash
+/// of `compilation`. It is not covered by the hashes of chunks that do not depend on it.
+fn uses_current_full_hash(manifest: &[RenderManifestEntry], compilation: &Compilation) -> bool {
+  let full_hash = compilation.hash.as_ref().map(|hash| hash.encoded());
+  manifest.iter().all(|entry| {
+    entry
+      .info
+      .full_hash
+      .iter()
+      .all(|hash| full_hash.is_some_and(|full_hash| full_hash.starts_with(hash.as_str())))
+  })
+}
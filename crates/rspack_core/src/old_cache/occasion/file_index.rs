@@ -0,0 +1,79 @@
+use std::path::{Path, PathBuf};
+
+use dashmap::DashMap;
+use rspack_identifier::{Identifier, IdentifierSet};
+
+use crate::Module;
+
+/// Records the files each cache entry was generated from, so that entries can be evicted
+/// as soon as one of those files is modified.
+#[derive(Debug, Default)]
+pub struct FileIndex {
+  inner: DashMap<PathBuf, IdentifierSet>,
+}
+
+impl FileIndex {
+  pub fn add_module(&self, id: Identifier, module: &dyn Module) {
+    if let Some(normal_module) = module.as_normal_module() {
+      if let Some(resource_path) = &normal_module.resource_resolved_data().resource_path {
+        self.add(id, resource_path);
+      }
+    }
+    if let Some(build_info) = module.build_info() {
+      for file in &build_info.file_dependencies {
+        self.add(id, file);
+      }
+    }
+  }
+
+  fn add(&self, id: Identifier, file: &Path) {
+    if let Some(mut ids) = self.inner.get_mut(file) {
+      ids.insert(id);
+      return;
+    }
+    self.inner.entry(file.to_path_buf()).or_default().insert(id);
+  }
+
+  /// Remove the given files from the index and return the cache entries affected by them.
+  pub fn take_affected(&self, files: &[PathBuf]) -> IdentifierSet {
+    files
+      .iter()
+      .filter_map(|file| self.inner.remove(file))
+      .flat_map(|(_, ids)| ids)
+      .collect()
+  }
+
+  /// Remove the cache entries dropped by the storage, files not affecting any entry are removed.
+  pub fn remove_entries(&self, ids: &[Identifier]) {
+    if ids.is_empty() {
+      return;
+    }
+    let ids = ids.iter().copied().collect::<IdentifierSet>();
+    self.inner.retain(|_, affected| {
+      affected.retain(|id| !ids.contains(id));
+      !affected.is_empty()
+    });
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn file_index_removes_dropped_entries() {
+    let index = FileIndex::default();
+    let dropped = Identifier::from("dropped");
+    let kept = Identifier::from("kept");
+    index.add(dropped, Path::new("/a.js"));
+    index.add(dropped, Path::new("/b.js"));
+    index.add(kept, Path::new("/b.js"));
+
+    index.remove_entries(&[dropped]);
+    assert!(!index.inner.contains_key(Path::new("/a.js")));
+    assert_eq!(
+      index.take_affected(&[PathBuf::from("/a.js"), PathBuf::from("/b.js")]),
+      IdentifierSet::from_iter([kept])
+    );
+  }
+}
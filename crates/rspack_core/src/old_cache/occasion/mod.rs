@@ -1,4 +1,7 @@
+mod build_module;
+pub use build_module::*;
 mod code_generate;
+mod file_index;
 pub use code_generate::*;
 mod create_chunk_assets;
 pub use create_chunk_assets::*;
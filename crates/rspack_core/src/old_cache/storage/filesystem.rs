@@ -0,0 +1,235 @@
+use std::{
+  fs,
+  hash::{BuildHasherDefault, Hash, Hasher},
+  path::{Path, PathBuf},
+  time::{Duration, SystemTime},
+};
+
+use dashmap::DashSet;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_identifier::{Identifier, IdentifierHasher};
+
+use super::{MemoryStorage, Persistable, Storage};
+use crate::FileSystemCacheOptions;
+
+const FINGERPRINT_FILE: &str = "FINGERPRINT";
+
+/// A storage that keeps entries in memory during the compilation and writes them into
+/// `cache_location` when the compiler becomes idle.
+///
+/// Entries are loaded lazily on the first lookup. The whole storage is invalidated when
+/// the `version` or the content of `build_dependencies` changes, and entries which have not
+/// been accessed for `max_age` milliseconds are removed on startup.
+#[derive(Debug)]
+pub struct FileSystemStorage<Item> {
+  memory: MemoryStorage<Item>,
+  dirty: DashSet<Identifier, BuildHasherDefault<IdentifierHasher>>,
+  directory: PathBuf,
+}
+
+impl<Item> FileSystemStorage<Item>
+where
+  Item: Persistable,
+{
+  pub fn new(options: &FileSystemCacheOptions, name: &str) -> Self {
+    let directory = cache_location(options).join(name);
+    let storage = Self {
+      memory: MemoryStorage::new(),
+      dirty: Default::default(),
+      directory,
+    };
+    storage.validate(&fingerprint(options));
+    if options.max_age > 0 {
+      storage.evict_expired(Duration::from_millis(options.max_age as u64));
+    }
+    storage
+  }
+
+  fn validate(&self, fingerprint: &str) {
+    let fingerprint_path = self.directory.join(FINGERPRINT_FILE);
+    let is_valid = fs::read_to_string(&fingerprint_path)
+      .map(|persisted| persisted == fingerprint)
+      .unwrap_or(false);
+    if is_valid {
+      return;
+    }
+    if self.directory.exists() {
+      let _ = fs::remove_dir_all(&self.directory);
+    }
+    if fs::create_dir_all(&self.directory).is_ok() {
+      let _ = fs::write(fingerprint_path, fingerprint);
+    }
+  }
+
+  fn evict_expired(&self, max_age: Duration) {
+    let Ok(entries) = fs::read_dir(&self.directory) else {
+      return;
+    };
+    let now = SystemTime::now();
+    for entry in entries.flatten() {
+      if entry.file_name() == FINGERPRINT_FILE {
+        continue;
+      }
+      let expired = entry
+        .metadata()
+        .and_then(|metadata| metadata.modified())
+        .map(|modified| {
+          now
+            .duration_since(modified)
+            .map(|age| age > max_age)
+            .unwrap_or(false)
+        })
+        .unwrap_or(true);
+      if expired {
+        let _ = fs::remove_file(entry.path());
+      }
+    }
+  }
+
+  fn entry_path(&self, id: &Identifier) -> PathBuf {
+    let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+    hasher.write(id.as_bytes());
+    self
+      .directory
+      .join(hasher.digest(&HashDigest::Hex).encoded())
+  }
+
+  fn read(&self, id: &Identifier) -> Option<Item> {
+    let path = self.entry_path(id);
+    let content = fs::read(&path).ok()?;
+    // the identifier is stored in the first line to guard against hash collisions
+    let separator = content.iter().position(|byte| *byte == b'\n')?;
+    if &content[..separator] != id.as_bytes() {
+      return None;
+    }
+    let item = Item::from_bytes(&content[separator + 1..])?;
+    // refresh the modified time so that frequently used entries outlive `max_age`
+    if let Ok(file) = fs::File::options().write(true).open(&path) {
+      let _ = file.set_modified(SystemTime::now());
+    }
+    Some(item)
+  }
+
+  fn write(&self, id: &Identifier, item: &Item) {
+    let Some(bytes) = item.to_bytes() else {
+      return;
+    };
+    let mut content = Vec::with_capacity(id.len() + 1 + bytes.len());
+    content.extend_from_slice(id.as_bytes());
+    content.push(b'\n');
+    content.extend(bytes);
+
+    let path = self.entry_path(id);
+    let temp_path = path.with_extension("tmp");
+    if fs::write(&temp_path, content).is_ok() {
+      let _ = fs::rename(temp_path, path);
+    }
+  }
+}
+
+impl<Item> Storage<Item> for FileSystemStorage<Item>
+where
+  Item: Persistable + Clone + std::fmt::Debug + Send + Sync,
+{
+  fn get(&self, id: &Identifier) -> Option<Item> {
+    if let Some(item) = self.memory.get(id) {
+      return Some(item);
+    }
+    let item = self.read(id)?;
+    self.memory.set(*id, item.clone());
+    Some(item)
+  }
+
+  fn set(&self, id: Identifier, data: Item) {
+    self.dirty.insert(id);
+    self.memory.set(id, data);
+  }
+
+  fn remove(&self, id: &Identifier) {
+    self.dirty.remove(id);
+    self.memory.remove(id);
+    let _ = fs::remove_file(self.entry_path(id));
+  }
+
+  fn begin_idle(&self) -> Vec<Identifier> {
+    if self.dirty.is_empty() {
+      return vec![];
+    }
+    if fs::create_dir_all(&self.directory).is_err() {
+      return vec![];
+    }
+    let dirty = self.dirty.iter().map(|id| *id).collect::<Vec<_>>();
+    for id in dirty {
+      self.dirty.remove(&id);
+      if let Some(item) = self.memory.get(&id) {
+        self.write(&id, &item);
+      }
+    }
+    vec![]
+  }
+}
+
+fn cache_location(options: &FileSystemCacheOptions) -> PathBuf {
+  if !options.cache_location.is_empty() {
+    return PathBuf::from(&options.cache_location);
+  }
+  Path::new(&options.cache_directory).join(&options.name)
+}
+
+/// Hash of `version` and the content of every build dependency. Build dependencies
+/// which can not be read as a file contribute their path only.
+fn fingerprint(options: &FileSystemCacheOptions) -> String {
+  let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+  options.version.hash(&mut hasher);
+  for dependency in &options.build_dependencies {
+    dependency.hash(&mut hasher);
+    if let Ok(content) = fs::read(dependency) {
+      hasher.write(&content);
+    }
+  }
+  hasher.digest(&HashDigest::Hex).encoded().to_string()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[derive(Debug, Clone, PartialEq)]
+  struct Item(String);
+
+  impl Persistable for Item {
+    fn to_bytes(&self) -> Option<Vec<u8>> {
+      Some(self.0.as_bytes().to_vec())
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+      String::from_utf8(bytes.to_vec()).ok().map(Item)
+    }
+  }
+
+  fn options(cache_location: &Path, version: &str) -> FileSystemCacheOptions {
+    FileSystemCacheOptions {
+      cache_location: cache_location.to_string_lossy().to_string(),
+      version: version.to_string(),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn filesystem_storage_persists_and_invalidates() {
+    let location = std::env::temp_dir().join(format!("rspack-fs-cache-{}", std::process::id()));
+    let id = Identifier::from("module-a");
+
+    let storage = FileSystemStorage::<Item>::new(&options(&location, "1"), "test");
+    storage.set(id, Item("content".into()));
+    storage.begin_idle();
+
+    let storage = FileSystemStorage::<Item>::new(&options(&location, "1"), "test");
+    assert_eq!(storage.get(&id), Some(Item("content".into())));
+
+    let storage = FileSystemStorage::<Item>::new(&options(&location, "2"), "test");
+    assert_eq!(storage.get(&id), None);
+
+    let _ = fs::remove_dir_all(location);
+  }
+}
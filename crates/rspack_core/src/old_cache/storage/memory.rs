@@ -1,21 +1,55 @@
-use std::hash::BuildHasherDefault;
+use std::{
+  hash::BuildHasherDefault,
+  sync::atomic::{AtomicU32, Ordering},
+};
 
 use dashmap::DashMap;
 use rspack_identifier::{Identifier, IdentifierHasher};
 
 use super::Storage;
 
+/// An in-memory storage.
+///
+/// Every entry records the generation in which it was last used, and the generation is
+/// increased whenever the compiler becomes idle. When `max_generations` is set, entries which
+/// have not been used for more than `max_generations` compilations are dropped.
 #[derive(Debug)]
 pub struct MemoryStorage<Item> {
-  data: DashMap<Identifier, Item, BuildHasherDefault<IdentifierHasher>>,
+  data: DashMap<Identifier, (Item, u32), BuildHasherDefault<IdentifierHasher>>,
+  generation: AtomicU32,
+  max_generations: Option<u32>,
 }
 
 impl<Item> MemoryStorage<Item> {
   pub fn new() -> Self {
     Self {
       data: DashMap::default(),
+      generation: AtomicU32::new(0),
+      max_generations: None,
     }
   }
+
+  pub fn with_max_generations(max_generations: u32) -> Self {
+    Self {
+      max_generations: Some(max_generations),
+      ..Self::new()
+    }
+  }
+
+  fn collect_garbage(&self) -> Vec<Identifier> {
+    let generation = self.generation.fetch_add(1, Ordering::Relaxed) + 1;
+    let mut dropped = vec![];
+    if let Some(max_generations) = self.max_generations {
+      self.data.retain(|id, (_, used)| {
+        let keep = generation - *used <= max_generations;
+        if !keep {
+          dropped.push(*id);
+        }
+        keep
+      });
+    }
+    dropped
+  }
 }
 
 impl<Item> Storage<Item> for MemoryStorage<Item>
@@ -23,12 +57,39 @@ where
   Item: Clone + std::fmt::Debug + Send + Sync,
 {
   fn get(&self, id: &Identifier) -> Option<Item> {
-    self.data.get(id).map(|item| item.clone())
+    let mut entry = self.data.get_mut(id)?;
+    entry.1 = self.generation.load(Ordering::Relaxed);
+    Some(entry.0.clone())
   }
   fn set(&self, id: Identifier, data: Item) {
-    self.data.insert(id, data);
+    self
+      .data
+      .insert(id, (data, self.generation.load(Ordering::Relaxed)));
   }
   fn remove(&self, id: &Identifier) {
     self.data.remove(id);
   }
+  fn begin_idle(&self) -> Vec<Identifier> {
+    self.collect_garbage()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn memory_storage_drops_unused_entries() {
+    let storage = MemoryStorage::with_max_generations(1);
+    let used = Identifier::from("used");
+    let unused = Identifier::from("unused");
+    storage.set(used, 1);
+    storage.set(unused, 2);
+    assert!(storage.begin_idle().is_empty());
+
+    assert_eq!(storage.get(&used), Some(1));
+    assert_eq!(storage.begin_idle(), vec![unused]);
+    assert_eq!(storage.get(&used), Some(1));
+    assert_eq!(storage.get(&unused), None);
+  }
 }
@@ -4,24 +4,37 @@ use rspack_identifier::Identifier;
 
 use crate::CacheOptions;
 
+mod filesystem;
 mod memory;
+mod persist;
+use filesystem::FileSystemStorage;
 use memory::MemoryStorage;
+pub use persist::Persistable;
+pub(crate) use persist::PersistedSource;
 
 pub trait Storage<Item>: Debug + Send + Sync {
   fn get(&self, id: &Identifier) -> Option<Item>;
   fn set(&self, id: Identifier, data: Item);
   fn remove(&self, id: &Identifier);
-  // fn begin_idle(&self);
-  // fn end_idle(&self);
+  /// Returns the entries dropped by the garbage collection running while becoming idle.
+  fn begin_idle(&self) -> Vec<Identifier> {
+    vec![]
+  }
+  fn end_idle(&self) {}
   // fn clear(&self);
 }
 
-pub fn new_storage<Item>(options: &CacheOptions) -> Option<Box<dyn Storage<Item>>>
+pub fn new_storage<Item>(options: &CacheOptions, name: &str) -> Option<Box<dyn Storage<Item>>>
 where
-  Item: Debug + Clone + Send + Sync + 'static,
+  Item: Debug + Clone + Send + Sync + Persistable + 'static,
 {
   match options {
     CacheOptions::Disabled => None,
-    _ => Some(Box::new(MemoryStorage::new())),
+    CacheOptions::Memory(options) => Some(Box::new(if options.max_generations > 0 {
+      MemoryStorage::with_max_generations(options.max_generations)
+    } else {
+      MemoryStorage::new()
+    })),
+    CacheOptions::FileSystem(options) => Some(Box::new(FileSystemStorage::new(options, name))),
   }
 }
@@ -0,0 +1,59 @@
+use rspack_base64::base64;
+use rspack_sources::{
+  BoxSource, MapOptions, RawSource, SourceExt, SourceMap, SourceMapSource, WithoutOriginalOptions,
+};
+use serde::{Deserialize, Serialize};
+
+/// Items which can be written to and restored from the filesystem cache.
+///
+/// Returning `None` from [Persistable::to_bytes] keeps the item in memory only,
+/// which is used for items holding data that can not be restored faithfully.
+pub trait Persistable: Sized {
+  fn to_bytes(&self) -> Option<Vec<u8>>;
+  fn from_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub(crate) enum PersistedSource {
+  Raw { source: String },
+  Buffer { base64: String },
+  SourceMap { source: String, map: String },
+}
+
+impl PersistedSource {
+  pub(crate) fn from_source(source: &BoxSource) -> Option<Self> {
+    let buffer = source.buffer().into_owned();
+    let source_text = match String::from_utf8(buffer) {
+      Ok(source_text) => source_text,
+      Err(err) => {
+        return Some(Self::Buffer {
+          base64: base64::encode_to_string(err.as_bytes()),
+        })
+      }
+    };
+    match source.map(&MapOptions::default()) {
+      Some(map) => Some(Self::SourceMap {
+        source: source_text,
+        map: map.to_json().ok()?,
+      }),
+      None => Some(Self::Raw {
+        source: source_text,
+      }),
+    }
+  }
+
+  pub(crate) fn into_source(self, name: &str) -> Option<BoxSource> {
+    let source = match self {
+      Self::Raw { source } => RawSource::from(source).boxed(),
+      Self::Buffer { base64 } => RawSource::from(base64::decode_to_vec(base64).ok()?).boxed(),
+      Self::SourceMap { source, map } => SourceMapSource::new(WithoutOriginalOptions {
+        value: source,
+        name,
+        source_map: SourceMap::from_json(&map).ok()?,
+      })
+      .boxed(),
+    };
+    Some(source)
+  }
+}
@@ -1,6 +1,7 @@
 #[derive(Debug, Clone, Default)]
 pub struct MemoryCacheOptions {
   /// Define the lifespan of unused cache entries in the memory cache.
+  /// `0` keeps unused entries until they are invalidated.
   pub max_generations: u32,
 }
 
@@ -0,0 +1,79 @@
+use rspack_error::Diagnostic;
+use rspack_regex::RspackRegex;
+
+/// A rule of `ignoreWarnings`, a warning is ignored when it matches every condition set in the rule.
+#[derive(Debug, Default)]
+pub struct IgnoreWarning {
+  /// The stable code of the warning, one of [rspack_error::codes].
+  pub code: Option<String>,
+  /// Tested against the resource of the module which emitted the warning.
+  pub module: Option<RspackRegex>,
+  pub message: Option<RspackRegex>,
+}
+
+impl IgnoreWarning {
+  pub fn matches(&self, diagnostic: &Diagnostic, module_resource: Option<&str>) -> bool {
+    if let Some(code) = &self.code
+      && diagnostic.diagnostic_code() != Some(code.as_str())
+    {
+      return false;
+    }
+    if let Some(module) = &self.module
+      && !module_resource.is_some_and(|resource| module.test(resource))
+    {
+      return false;
+    }
+    if let Some(message) = &self.message {
+      let matched = message.test(&diagnostic.message())
+        || diagnostic
+          .traceable_error()
+          .is_some_and(|e| message.test(e.message()));
+      if !matched {
+        return false;
+      }
+    }
+    true
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use rspack_error::{codes, DiagnosticExt, RspackSeverity, TraceableError};
+  use rspack_regex::RspackRegex;
+
+  use super::IgnoreWarning;
+  use crate::diagnostics::map_box_diagnostics_to_module_parse_diagnostics;
+
+  #[test]
+  fn match_module_parse_warning() {
+    let warning = TraceableError::from_file(
+      "require(name)".to_string(),
+      0,
+      7,
+      "Critical dependency".to_string(),
+      "require function is used in a way in which dependencies cannot be statically extracted"
+        .to_string(),
+    )
+    .with_severity(RspackSeverity::Warn)
+    .with_diagnostic_code(codes::CRITICAL_DEPENDENCY);
+    let diagnostic =
+      map_box_diagnostics_to_module_parse_diagnostics(vec![warning.boxed()], &[]).remove(0);
+
+    let by_code = IgnoreWarning {
+      code: Some(codes::CRITICAL_DEPENDENCY.to_string()),
+      ..Default::default()
+    };
+    assert!(by_code.matches(&diagnostic, None));
+    let by_message = IgnoreWarning {
+      message: Some(RspackRegex::new("statically extracted").expect("should be valid")),
+      ..Default::default()
+    };
+    assert!(by_message.matches(&diagnostic, Some("/project/src/index.js")));
+    let by_module = IgnoreWarning {
+      code: Some(codes::CRITICAL_DEPENDENCY.to_string()),
+      module: Some(RspackRegex::new("node_modules").expect("should be valid")),
+      ..Default::default()
+    };
+    assert!(!by_module.matches(&diagnostic, Some("/project/src/index.js")));
+  }
+}
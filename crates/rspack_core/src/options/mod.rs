@@ -25,6 +25,8 @@ mod externals;
 pub use externals::*;
 mod stats;
 pub use stats::*;
+mod ignore_warnings;
+pub use ignore_warnings::*;
 mod cache;
 pub use cache::*;
 mod snapshot;
@@ -1,2 +1,56 @@
+use std::path::Path;
+
+use rspack_regex::RspackRegex;
+
+#[derive(Debug, Clone)]
+pub enum SnapshotPath {
+  /// Matches paths starting with the string.
+  String(String),
+  Regexp(RspackRegex),
+}
+
+impl SnapshotPath {
+  pub fn matches(&self, path: &Path) -> bool {
+    match self {
+      Self::String(prefix) => path.starts_with(prefix),
+      Self::Regexp(regexp) => regexp.test(&path.to_string_lossy()),
+    }
+  }
+}
+
+/// How to decide whether a file has changed since a module was built.
+///
+/// When `hash` is enabled the content hash decides, otherwise the modified time is compared.
+/// Files are always considered changed when both are disabled.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SnapshotStrategy {
+  pub hash: bool,
+  pub timestamp: bool,
+}
+
+impl SnapshotStrategy {
+  pub fn is_enabled(&self) -> bool {
+    self.hash || self.timestamp
+  }
+}
+
 #[derive(Debug, Clone, Default)]
-pub struct SnapshotOptions;
+pub struct SnapshotOptions {
+  /// Paths managed by a package manager, e.g. `node_modules`. Files inside are considered unchanged
+  /// as long as the `version` in the `package.json` of their package is unchanged.
+  pub managed_paths: Vec<SnapshotPath>,
+  /// Paths whose content never changes, e.g. versioned directories of a package manager cache.
+  pub immutable_paths: Vec<SnapshotPath>,
+  /// Strategy for the file dependencies of modules.
+  pub module: SnapshotStrategy,
+}
+
+impl SnapshotOptions {
+  pub fn is_managed_path(&self, path: &Path) -> bool {
+    self.managed_paths.iter().any(|item| item.matches(path))
+  }
+
+  pub fn is_immutable_path(&self, path: &Path) -> bool {
+    self.immutable_paths.iter().any(|item| item.matches(path))
+  }
+}
@@ -1,4 +1,103 @@
-#[derive(Debug, Default)]
+use std::str::FromStr;
+
+use rspack_error::{error, Error};
+
+/// Named presets of [StatsOptions], as `stats: "errors-only"` in webpack.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StatsPreset {
+  None,
+  ErrorsOnly,
+  #[default]
+  Normal,
+  Verbose,
+}
+
+impl FromStr for StatsPreset {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "none" => Ok(Self::None),
+      "errors-only" => Ok(Self::ErrorsOnly),
+      "normal" => Ok(Self::Normal),
+      "verbose" => Ok(Self::Verbose),
+      _ => Err(error!(
+        "Unknown stats preset '{s}', expected 'none', 'errors-only', 'normal' or 'verbose'"
+      )),
+    }
+  }
+}
+
+/// Which fields are included by [crate::Stats::to_json], the defaults are the ones of webpack's
+/// `stats.toJson()`.
+#[derive(Debug, Clone)]
 pub struct StatsOptions {
   pub colors: bool,
+  pub hash: bool,
+  pub public_path: bool,
+  pub output_path: bool,
+  pub assets: bool,
+  pub chunks: bool,
+  pub chunk_modules: bool,
+  pub chunk_relations: bool,
+  pub modules: bool,
+  pub nested_modules: bool,
+  pub module_assets: bool,
+  pub reasons: bool,
+  pub source: bool,
+  pub used_exports: bool,
+  pub provided_exports: bool,
+  pub entrypoints: bool,
+  pub chunk_groups: bool,
+  pub chunk_group_auxiliary: bool,
+  pub chunk_group_children: bool,
+  pub errors: bool,
+  pub errors_count: bool,
+  pub warnings: bool,
+  pub warnings_count: bool,
+  pub module_trace: bool,
+  pub error_details: bool,
+  pub error_stack: bool,
+}
+
+impl Default for StatsOptions {
+  fn default() -> Self {
+    Self::from_preset(StatsPreset::Normal)
+  }
+}
+
+impl StatsOptions {
+  pub fn from_preset(preset: StatsPreset) -> Self {
+    let all = !matches!(preset, StatsPreset::None | StatsPreset::ErrorsOnly);
+    let errors = preset != StatsPreset::None;
+    Self {
+      colors: false,
+      hash: all,
+      public_path: all,
+      output_path: all,
+      assets: all,
+      chunks: all,
+      chunk_modules: all,
+      chunk_relations: all,
+      // modules are listed in the chunks instead
+      modules: all && preset != StatsPreset::Verbose,
+      nested_modules: all,
+      module_assets: all,
+      reasons: all,
+      source: false,
+      used_exports: all,
+      provided_exports: all,
+      entrypoints: all,
+      chunk_groups: all,
+      chunk_group_auxiliary: all,
+      chunk_group_children: all,
+      errors,
+      errors_count: errors,
+      warnings: all,
+      warnings_count: all,
+      module_trace: errors,
+      error_details: all,
+      error_stack: all,
+    }
+  }
 }
@@ -1,9 +1,10 @@
 use std::{hash::BuildHasherDefault, sync::Arc};
 
 use dashmap::DashMap;
+use rspack_fs::AsyncReadableFileSystem;
 use rustc_hash::FxHasher;
 
-use super::resolver_impl::Resolver;
+use super::{file_system::ResolverFileSystem, resolver_impl::Resolver};
 use crate::{DependencyCategory, Resolve};
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone)]
@@ -37,7 +38,19 @@ impl ResolverFactory {
   pub fn new(options: Resolve) -> Self {
     Self {
       base_options: options.clone(),
-      resolver: Resolver::new(options),
+      resolver: Resolver::new(options, ResolverFileSystem::Native),
+      resolvers: Default::default(),
+    }
+  }
+
+  /// Create a factory whose resolvers read through the given input file system instead of the disk.
+  pub fn new_with_input_filesystem(
+    options: Resolve,
+    input_filesystem: Arc<dyn AsyncReadableFileSystem + Send + Sync>,
+  ) -> Self {
+    Self {
+      base_options: options.clone(),
+      resolver: Resolver::new(options, ResolverFileSystem::Input(input_filesystem)),
       resolvers: Default::default(),
     }
   }
@@ -0,0 +1,75 @@
+use std::{
+  fs, io,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use futures::Future;
+use rspack_fs::AsyncReadableFileSystem;
+use rspack_resolver::FileMetadata;
+use tokio::runtime::{Handle, RuntimeFlavor};
+
+/// The file system used by [super::Resolver].
+///
+/// `Native` reads from the disk directly. `Input` reads through the input file system of the
+/// compiler, since the resolver is synchronous, its futures are blocked on, see [block_on].
+#[derive(Debug, Clone, Default)]
+pub enum ResolverFileSystem {
+  #[default]
+  Native,
+  Input(Arc<dyn AsyncReadableFileSystem + Send + Sync>),
+}
+
+/// Drives `future` to completion. On a worker of a multi-thread tokio runtime, the worker is
+/// handed over to the runtime while blocking, so the futures of the input file system depending on
+/// the runtime, like `tokio::fs`, keep making progress.
+fn block_on<F: Future>(future: F) -> F::Output {
+  match Handle::try_current() {
+    Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+      tokio::task::block_in_place(|| handle.block_on(future))
+    }
+    _ => futures::executor::block_on(future),
+  }
+}
+
+fn to_io_error(error: rspack_fs::Error) -> io::Error {
+  match error {
+    rspack_fs::Error::Io(error) => error,
+  }
+}
+
+impl rspack_resolver::FileSystem for ResolverFileSystem {
+  fn read_to_string(&self, path: &Path) -> io::Result<String> {
+    match self {
+      Self::Native => fs::read_to_string(path),
+      Self::Input(fs) => {
+        let content = block_on(fs.read(path)).map_err(to_io_error)?;
+        String::from_utf8(content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+      }
+    }
+  }
+
+  fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    match self {
+      Self::Native => fs::metadata(path).map(FileMetadata::from),
+      Self::Input(fs) => block_on(fs.metadata(path))
+        .map(|metadata| FileMetadata::new(metadata.is_file, metadata.is_directory, false))
+        .map_err(to_io_error),
+    }
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    match self {
+      Self::Native => fs::symlink_metadata(path).map(FileMetadata::from),
+      // symlinks are not exposed by the input file system
+      Self::Input(_) => self.metadata(path),
+    }
+  }
+
+  fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+    match self {
+      Self::Native => dunce::canonicalize(path),
+      Self::Input(fs) => block_on(fs.canonicalize(path)).map_err(to_io_error),
+    }
+  }
+}
@@ -1,4 +1,5 @@
 mod factory;
+mod file_system;
 mod resolver_impl;
 use std::borrow::Borrow;
 use std::fs;
@@ -13,7 +14,7 @@ use rustc_hash::FxHashSet;
 use sugar_path::SugarPath;
 
 pub use self::factory::{ResolveOptionsWithDependencyType, ResolverFactory};
-pub use self::resolver_impl::{ResolveInnerOptions, Resolver};
+pub use self::resolver_impl::{ResolveInnerError, ResolveInnerOptions, Resolver};
 use crate::{
   Context, DependencyCategory, DependencyType, ErrorSpan, ModuleIdentifier, Resolve,
   SharedPluginDriver,
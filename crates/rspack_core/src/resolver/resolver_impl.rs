@@ -11,7 +11,7 @@ use rspack_error::{
 use rspack_loader_runner::DescriptionData;
 use rustc_hash::FxHashSet as HashSet;
 
-use super::{ResolveResult, Resource};
+use super::{file_system::ResolverFileSystem, ResolveResult, Resource};
 use crate::{AliasMap, DependencyCategory, Resolve, ResolveArgs, ResolveOptionsWithDependencyType};
 
 #[derive(Debug, Default, Clone)]
@@ -77,17 +77,17 @@ impl<'a> ResolveInnerOptions<'a> {
 /// Internal caches are shared.
 #[derive(Debug)]
 pub enum Resolver {
-  RspackResolver(rspack_resolver::Resolver),
+  RspackResolver(rspack_resolver::ResolverGeneric<ResolverFileSystem>),
 }
 
 impl Resolver {
-  pub fn new(options: Resolve) -> Self {
-    Self::new_rspack_resolver(options)
+  pub fn new(options: Resolve, file_system: ResolverFileSystem) -> Self {
+    Self::new_rspack_resolver(options, file_system)
   }
 
-  fn new_rspack_resolver(options: Resolve) -> Self {
+  fn new_rspack_resolver(options: Resolve, file_system: ResolverFileSystem) -> Self {
     let options = to_rspack_resolver_options(options, false, DependencyCategory::Unknown);
-    let resolver = rspack_resolver::Resolver::new(options);
+    let resolver = rspack_resolver::ResolverGeneric::new_with_file_system(file_system, options);
     Self::RspackResolver(resolver)
   }
 
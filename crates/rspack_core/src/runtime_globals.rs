@@ -248,6 +248,11 @@ bitflags! {
     const RSPACK_UNIQUE_ID = 1 << 64;
 
     const HAS_FETCH_PRIORITY = 1 << 65;
+
+    /**
+     * the integrity of the async chunk files, keyed by `js` or `css` and the chunk id
+     */
+    const SRI_HASHES = 1 << 66;
   }
 }
 
@@ -334,6 +339,7 @@ impl RuntimeGlobals {
       R::HAS_CSS_MODULES => "has css modules",
 
       R::HAS_FETCH_PRIORITY => "has fetch priority",
+      R::SRI_HASHES => "__webpack_require__.sriHashes",
       _ => unreachable!(),
     }
   }
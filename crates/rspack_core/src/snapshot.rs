@@ -0,0 +1,270 @@
+use std::hash::Hasher;
+use std::path::{Path, PathBuf};
+
+use rspack_fs::AsyncReadableFileSystem;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use serde::{Deserialize, Serialize};
+
+use crate::{Compilation, SnapshotOptions};
+
+/// The state of the file dependencies of a module at the time it was built, used to find out
+/// whether a file reported as modified really changed on rebuild.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Snapshot {
+  /// Content hashes, `None` if the file could not be read.
+  file_hashes: HashMap<PathBuf, Option<String>>,
+  /// Modified times, `None` if the file did not exist.
+  file_timestamps: HashMap<PathBuf, Option<u64>>,
+  /// Files in managed paths and the `package.json` of the package they belong to.
+  managed_files: HashMap<PathBuf, PathBuf>,
+  /// Versions of the packages in managed paths, keyed by their `package.json`.
+  package_versions: HashMap<PathBuf, Option<String>>,
+  immutable_files: HashSet<PathBuf>,
+}
+
+impl Snapshot {
+  /// `contents` are the contents of files that were already read during the build, they are
+  /// hashed instead of reading the files again.
+  pub async fn create<'a>(
+    fs: &(dyn AsyncReadableFileSystem + Send + Sync),
+    options: &SnapshotOptions,
+    files: impl Iterator<Item = &'a PathBuf>,
+    contents: &[(&Path, &[u8])],
+  ) -> Option<Self> {
+    let strategy = options.module;
+    if !strategy.is_enabled() {
+      return None;
+    }
+    let mut snapshot = Self::default();
+    for file in files {
+      if options.is_immutable_path(file) {
+        snapshot.immutable_files.insert(file.clone());
+        continue;
+      }
+      if options.is_managed_path(file)
+        && let Some(package_json) = find_package_json(fs, file).await
+      {
+        if !snapshot.package_versions.contains_key(&package_json) {
+          let version = read_package_version(fs, &package_json).await;
+          snapshot
+            .package_versions
+            .insert(package_json.clone(), version);
+        }
+        snapshot.managed_files.insert(file.clone(), package_json);
+        continue;
+      }
+      if strategy.hash {
+        let hash = match contents.iter().find(|(path, _)| path == file) {
+          Some((_, content)) => Some(hash_content(content)),
+          None => hash_file(fs, file).await,
+        };
+        snapshot.file_hashes.insert(file.clone(), hash);
+      } else {
+        let timestamp = fs.metadata(file).await.ok().map(|m| m.mtime_ms);
+        snapshot.file_timestamps.insert(file.clone(), timestamp);
+      }
+    }
+    Some(snapshot)
+  }
+
+  /// Whether `file` is unchanged since the snapshot was created. Files that are not part of the
+  /// snapshot are considered changed.
+  pub async fn is_unchanged(
+    &self,
+    fs: &(dyn AsyncReadableFileSystem + Send + Sync),
+    file: &Path,
+  ) -> bool {
+    if self.immutable_files.contains(file) {
+      return true;
+    }
+    if let Some(package_json) = self.managed_files.get(file) {
+      let Some(version) = self.package_versions.get(package_json) else {
+        return false;
+      };
+      return version.is_some() && *version == read_package_version(fs, package_json).await;
+    }
+    if let Some(hash) = self.file_hashes.get(file) {
+      return hash.is_some() && *hash == hash_file(fs, file).await;
+    }
+    if let Some(timestamp) = self.file_timestamps.get(file) {
+      return *timestamp == fs.metadata(file).await.ok().map(|m| m.mtime_ms);
+    }
+    false
+  }
+}
+
+/// Remove the files whose content did not change according to the snapshots of all modules
+/// depending on them. Files that are not covered by a snapshot are kept.
+pub async fn retain_changed_files(compilation: &Compilation, files: &mut HashSet<PathBuf>) {
+  let module_graph = compilation.get_module_graph();
+  let modules = module_graph.modules();
+  // the snapshots of the modules depending on each file, `None` if some module depends on the
+  // file without a snapshot or as a context, missing or build dependency
+  let mut snapshots: HashMap<&PathBuf, Option<Vec<&Snapshot>>> = HashMap::default();
+  for module in modules.values() {
+    let Some(build_info) = module.build_info() else {
+      continue;
+    };
+    for file in build_info
+      .context_dependencies
+      .iter()
+      .chain(&build_info.missing_dependencies)
+      .chain(&build_info.build_dependencies)
+    {
+      if let Some(file) = files.get(file) {
+        snapshots.insert(file, None);
+      }
+    }
+    for file in &build_info.file_dependencies {
+      let Some(file) = files.get(file) else {
+        continue;
+      };
+      let entry = snapshots.entry(file).or_insert_with(|| Some(vec![]));
+      match (entry.as_mut(), &build_info.snapshot) {
+        (Some(file_snapshots), Some(snapshot)) => file_snapshots.push(snapshot),
+        _ => *entry = None,
+      }
+    }
+  }
+
+  let mut unchanged = vec![];
+  'files: for (file, file_snapshots) in snapshots {
+    let Some(file_snapshots) = file_snapshots else {
+      continue;
+    };
+    for snapshot in file_snapshots {
+      if !snapshot
+        .is_unchanged(&*compilation.input_filesystem, file)
+        .await
+      {
+        continue 'files;
+      }
+    }
+    unchanged.push(file.clone());
+  }
+  for file in unchanged {
+    files.remove(&file);
+  }
+}
+
+async fn hash_file(
+  fs: &(dyn AsyncReadableFileSystem + Send + Sync),
+  file: &Path,
+) -> Option<String> {
+  let content = fs.read(file).await.ok()?;
+  Some(hash_content(&content))
+}
+
+fn hash_content(content: &[u8]) -> String {
+  let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+  hasher.write(content);
+  hasher.digest(&HashDigest::Hex).encoded().to_string()
+}
+
+/// The `package.json` of the nearest ancestor directory of `file` which has one.
+async fn find_package_json(
+  fs: &(dyn AsyncReadableFileSystem + Send + Sync),
+  file: &Path,
+) -> Option<PathBuf> {
+  for directory in file.ancestors().skip(1) {
+    let package_json = directory.join("package.json");
+    if fs
+      .metadata(&package_json)
+      .await
+      .map(|m| m.is_file)
+      .unwrap_or(false)
+    {
+      return Some(package_json);
+    }
+  }
+  None
+}
+
+async fn read_package_version(
+  fs: &(dyn AsyncReadableFileSystem + Send + Sync),
+  package_json: &Path,
+) -> Option<String> {
+  let content = fs.read(package_json).await.ok()?;
+  let json: serde_json::Value = serde_json::from_slice(&content).ok()?;
+  json.get("version")?.as_str().map(ToString::to_string)
+}
+
+#[cfg(test)]
+mod test {
+  use rspack_fs::{MemoryFileSystem, WritableFileSystem};
+
+  use super::*;
+  use crate::{SnapshotPath, SnapshotStrategy};
+
+  #[tokio::test]
+  async fn snapshot_detects_content_changes() {
+    let fs = MemoryFileSystem::default();
+    let file = PathBuf::from("/project/src/index.js");
+    let dependency = PathBuf::from("/project/node_modules/lib/index.js");
+    fs.create_dir_all(Path::new("/project/src")).unwrap();
+    fs.create_dir_all(Path::new("/project/node_modules/lib"))
+      .unwrap();
+    fs.write(&file, b"console.log(1)").unwrap();
+    fs.write(&dependency, b"module.exports = 1").unwrap();
+    fs.write(
+      Path::new("/project/node_modules/lib/package.json"),
+      br#"{ "version": "1.0.0" }"#,
+    )
+    .unwrap();
+
+    let options = SnapshotOptions {
+      managed_paths: vec![SnapshotPath::String("/project/node_modules".into())],
+      module: SnapshotStrategy {
+        hash: true,
+        timestamp: false,
+      },
+      ..Default::default()
+    };
+    let files = [file.clone(), dependency.clone()];
+    let snapshot = Snapshot::create(&fs, &options, files.iter(), &[])
+      .await
+      .unwrap();
+    assert!(snapshot.is_unchanged(&fs, &file).await);
+    assert!(snapshot.is_unchanged(&fs, &dependency).await);
+
+    fs.write(&file, b"console.log(1)").unwrap();
+    fs.write(&dependency, b"module.exports = 2").unwrap();
+    assert!(snapshot.is_unchanged(&fs, &file).await);
+    assert!(snapshot.is_unchanged(&fs, &dependency).await);
+
+    fs.write(&file, b"console.log(2)").unwrap();
+    fs.write(
+      Path::new("/project/node_modules/lib/package.json"),
+      br#"{ "version": "1.0.1" }"#,
+    )
+    .unwrap();
+    assert!(!snapshot.is_unchanged(&fs, &file).await);
+    assert!(!snapshot.is_unchanged(&fs, &dependency).await);
+  }
+
+  #[tokio::test]
+  async fn snapshot_hashes_contents_already_read() {
+    let fs = MemoryFileSystem::default();
+    let file = PathBuf::from("/project/index.js");
+    fs.create_dir_all(Path::new("/project")).unwrap();
+    fs.write(&file, b"console.log(2)").unwrap();
+
+    let options = SnapshotOptions {
+      module: SnapshotStrategy {
+        hash: true,
+        timestamp: false,
+      },
+      ..Default::default()
+    };
+    let files = [file.clone()];
+    let contents: &[(&Path, &[u8])] = &[(&file, b"console.log(1)")];
+    let snapshot = Snapshot::create(&fs, &options, files.iter(), contents)
+      .await
+      .unwrap();
+    assert!(!snapshot.is_unchanged(&fs, &file).await);
+
+    fs.write(&file, b"console.log(1)").unwrap();
+    assert!(snapshot.is_unchanged(&fs, &file).await);
+  }
+}
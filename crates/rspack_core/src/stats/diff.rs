@@ -0,0 +1,501 @@
+use std::collections::BTreeMap;
+
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rspack_error::Result;
+use rspack_util::size::format_size;
+use serde::{Deserialize, Serialize};
+
+use super::{Stats, StatsChunk};
+
+static HASH_SEGMENT_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"[0-9a-fA-F]{8,}").expect("Invalid regex"));
+
+/// An owned summary of the sizes of a build, which can be stored and compared with the one of a
+/// later build by [StatsSnapshot::diff].
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsSnapshot {
+  pub assets: Vec<StatsSnapshotAsset>,
+  pub chunks: Vec<StatsSnapshotChunk>,
+  pub modules: Vec<StatsSnapshotModule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsSnapshotAsset {
+  pub name: String,
+  pub size: f64,
+  pub chunk_names: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsSnapshotChunk {
+  /// The names of the chunk, or its id for an unnamed chunk.
+  pub key: String,
+  pub size: f64,
+  /// Sizes by source type, as [super::StatsSourceTypeSize].
+  pub sizes: BTreeMap<String, f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsSnapshotModule {
+  pub identifier: String,
+  pub name: String,
+  pub size: f64,
+  /// Keys of the chunks containing the module.
+  pub chunks: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsAssetChange {
+  pub name: String,
+  pub old_size: f64,
+  pub new_size: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsAssetRename {
+  pub old_name: String,
+  pub new_name: String,
+  pub old_size: f64,
+  pub new_size: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsChunkSizeDelta {
+  pub key: String,
+  pub size: f64,
+  /// Delta by source type, only the source types whose size changed are included.
+  pub sizes: BTreeMap<String, f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsModuleMove {
+  pub identifier: String,
+  pub name: String,
+  pub old_chunks: Vec<String>,
+  pub new_chunks: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsDiff {
+  pub added_assets: Vec<StatsSnapshotAsset>,
+  pub removed_assets: Vec<StatsSnapshotAsset>,
+  pub renamed_assets: Vec<StatsAssetRename>,
+  pub changed_assets: Vec<StatsAssetChange>,
+  pub chunk_size_deltas: Vec<StatsChunkSizeDelta>,
+  pub moved_modules: Vec<StatsModuleMove>,
+  pub changed_modules: Vec<StatsAssetChange>,
+  pub old_total_size: f64,
+  pub new_total_size: f64,
+}
+
+/// Limits of size growth checked by [StatsDiff::check], a `None` limit is not checked.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsDiffThreshold {
+  /// Maximum growth in bytes of the total size of all assets.
+  pub max_total_increase: Option<f64>,
+  /// Maximum growth in bytes of a single asset, renamed assets included.
+  pub max_asset_increase: Option<f64>,
+  /// Maximum relative growth of a single asset, e.g. `0.1` for 10%.
+  pub max_asset_increase_ratio: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "failures", rename_all = "camelCase")]
+pub enum StatsDiffResult {
+  Pass,
+  Fail(Vec<String>),
+}
+
+impl StatsDiffResult {
+  pub fn is_pass(&self) -> bool {
+    matches!(self, Self::Pass)
+  }
+}
+
+impl Stats<'_> {
+  pub fn snapshot(&self) -> Result<StatsSnapshot> {
+    let (assets, _) = self.get_assets();
+    let assets = assets
+      .into_iter()
+      .map(|asset| StatsSnapshotAsset {
+        name: asset.name,
+        size: asset.size,
+        chunk_names: asset.chunk_names,
+      })
+      .sorted_by(|a, b| a.name.cmp(&b.name))
+      .collect();
+
+    let (chunks, modules) = self.get_chunks(
+      true,
+      false,
+      false,
+      false,
+      false,
+      false,
+      false,
+      false,
+      |chunks| {
+        let mut modules: BTreeMap<String, StatsSnapshotModule> = BTreeMap::new();
+        let chunks = chunks
+          .iter()
+          .map(|chunk| {
+            let key = chunk_key(chunk);
+            for module in chunk.modules.iter().flatten() {
+              modules
+                .entry(module.identifier.to_string())
+                .or_insert_with(|| StatsSnapshotModule {
+                  identifier: module.identifier.to_string(),
+                  name: module.name.to_string(),
+                  size: module.size,
+                  chunks: vec![],
+                })
+                .chunks
+                .push(key.clone());
+            }
+            StatsSnapshotChunk {
+              key,
+              size: chunk.size,
+              sizes: chunk
+                .sizes
+                .iter()
+                .map(|(source_type, size)| (source_type.to_string(), *size))
+                .collect(),
+            }
+          })
+          .sorted_by(|a, b| a.key.cmp(&b.key))
+          .collect::<Vec<_>>();
+        let modules = modules
+          .into_values()
+          .map(|mut module| {
+            module.chunks.sort();
+            module
+          })
+          .collect::<Vec<_>>();
+        (chunks, modules)
+      },
+    )?;
+
+    Ok(StatsSnapshot {
+      assets,
+      chunks,
+      modules,
+    })
+  }
+}
+
+fn chunk_key(chunk: &StatsChunk) -> String {
+  if chunk.names.is_empty() {
+    chunk.id.clone().unwrap_or_default()
+  } else {
+    chunk.names.iter().sorted().join(",")
+  }
+}
+
+/// Key to pair a removed asset with an added one, which are the same asset under a new name when
+/// e.g. its content hash changed.
+fn rename_key(asset: &StatsSnapshotAsset) -> String {
+  let extension = asset
+    .name
+    .rsplit_once('.')
+    .map(|(_, ext)| ext)
+    .unwrap_or("");
+  if asset.chunk_names.is_empty() {
+    HASH_SEGMENT_REGEX
+      .replace_all(&asset.name, "*")
+      .into_owned()
+  } else {
+    format!(
+      "{}.{extension}",
+      asset.chunk_names.iter().sorted().join(",")
+    )
+  }
+}
+
+impl StatsSnapshot {
+  /// Compares this snapshot of a previous build with the snapshot of the `new` build.
+  pub fn diff(&self, new: &StatsSnapshot) -> StatsDiff {
+    let mut diff = StatsDiff {
+      old_total_size: self.assets.iter().map(|asset| asset.size).sum(),
+      new_total_size: new.assets.iter().map(|asset| asset.size).sum(),
+      ..Default::default()
+    };
+
+    let old_assets = self
+      .assets
+      .iter()
+      .map(|asset| (asset.name.as_str(), asset))
+      .collect::<BTreeMap<_, _>>();
+    let new_assets = new
+      .assets
+      .iter()
+      .map(|asset| (asset.name.as_str(), asset))
+      .collect::<BTreeMap<_, _>>();
+    for (name, old) in &old_assets {
+      match new_assets.get(name) {
+        Some(new) if new.size != old.size => diff.changed_assets.push(StatsAssetChange {
+          name: name.to_string(),
+          old_size: old.size,
+          new_size: new.size,
+        }),
+        Some(_) => {}
+        None => diff.removed_assets.push((*old).clone()),
+      }
+    }
+    let mut added = new_assets
+      .iter()
+      .filter(|(name, _)| !old_assets.contains_key(*name))
+      .map(|(_, asset)| (*asset).clone())
+      .collect::<Vec<_>>();
+    diff.removed_assets.retain(|removed| {
+      let key = rename_key(removed);
+      let Some(index) = added.iter().position(|added| rename_key(added) == key) else {
+        return true;
+      };
+      let added = added.remove(index);
+      diff.renamed_assets.push(StatsAssetRename {
+        old_name: removed.name.clone(),
+        new_name: added.name,
+        old_size: removed.size,
+        new_size: added.size,
+      });
+      false
+    });
+    diff.added_assets = added;
+
+    let old_chunks = self
+      .chunks
+      .iter()
+      .map(|chunk| (chunk.key.as_str(), chunk))
+      .collect::<BTreeMap<_, _>>();
+    for chunk in &new.chunks {
+      let old = old_chunks.get(chunk.key.as_str());
+      let old_size = old.map(|old| old.size).unwrap_or_default();
+      let mut sizes = BTreeMap::new();
+      for source_type in chunk
+        .sizes
+        .keys()
+        .chain(old.into_iter().flat_map(|old| old.sizes.keys()))
+      {
+        let old_size = old
+          .and_then(|old| old.sizes.get(source_type))
+          .copied()
+          .unwrap_or_default();
+        let new_size = chunk.sizes.get(source_type).copied().unwrap_or_default();
+        if new_size != old_size {
+          sizes.insert(source_type.clone(), new_size - old_size);
+        }
+      }
+      if chunk.size != old_size || !sizes.is_empty() {
+        diff.chunk_size_deltas.push(StatsChunkSizeDelta {
+          key: chunk.key.clone(),
+          size: chunk.size - old_size,
+          sizes,
+        });
+      }
+    }
+    let new_chunk_keys = new
+      .chunks
+      .iter()
+      .map(|chunk| chunk.key.as_str())
+      .collect::<Vec<_>>();
+    for chunk in self
+      .chunks
+      .iter()
+      .filter(|chunk| !new_chunk_keys.contains(&chunk.key.as_str()))
+    {
+      diff.chunk_size_deltas.push(StatsChunkSizeDelta {
+        key: chunk.key.clone(),
+        size: -chunk.size,
+        sizes: chunk
+          .sizes
+          .iter()
+          .map(|(source_type, size)| (source_type.clone(), -size))
+          .collect(),
+      });
+    }
+
+    let old_modules = self
+      .modules
+      .iter()
+      .map(|module| (module.identifier.as_str(), module))
+      .collect::<BTreeMap<_, _>>();
+    for module in &new.modules {
+      let Some(old) = old_modules.get(module.identifier.as_str()) else {
+        continue;
+      };
+      if old.chunks != module.chunks {
+        diff.moved_modules.push(StatsModuleMove {
+          identifier: module.identifier.clone(),
+          name: module.name.clone(),
+          old_chunks: old.chunks.clone(),
+          new_chunks: module.chunks.clone(),
+        });
+      }
+      if old.size != module.size {
+        diff.changed_modules.push(StatsAssetChange {
+          name: module.name.clone(),
+          old_size: old.size,
+          new_size: module.size,
+        });
+      }
+    }
+
+    diff
+  }
+}
+
+impl StatsDiff {
+  pub fn check(&self, threshold: &StatsDiffThreshold) -> StatsDiffResult {
+    let mut failures = vec![];
+    if let Some(max) = threshold.max_total_increase {
+      let increase = self.new_total_size - self.old_total_size;
+      if increase > max {
+        failures.push(format!(
+          "total size grew by {}, more than {}",
+          format_size(increase),
+          format_size(max)
+        ));
+      }
+    }
+    let growths = self
+      .changed_assets
+      .iter()
+      .map(|change| (&change.name, change.old_size, change.new_size))
+      .chain(
+        self
+          .renamed_assets
+          .iter()
+          .map(|rename| (&rename.new_name, rename.old_size, rename.new_size)),
+      );
+    for (name, old_size, new_size) in growths {
+      let increase = new_size - old_size;
+      if let Some(max) = threshold.max_asset_increase
+        && increase > max
+      {
+        failures.push(format!(
+          "asset {name} grew by {}, more than {}",
+          format_size(increase),
+          format_size(max)
+        ));
+      }
+      if let Some(max_ratio) = threshold.max_asset_increase_ratio
+        && old_size > 0.0
+        && increase / old_size > max_ratio
+      {
+        failures.push(format!(
+          "asset {name} grew by {:.1}%, more than {:.1}%",
+          increase / old_size * 100.0,
+          max_ratio * 100.0
+        ));
+      }
+    }
+    if failures.is_empty() {
+      StatsDiffResult::Pass
+    } else {
+      StatsDiffResult::Fail(failures)
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn asset(name: &str, size: f64, chunk_names: &[&str]) -> StatsSnapshotAsset {
+    StatsSnapshotAsset {
+      name: name.to_string(),
+      size,
+      chunk_names: chunk_names.iter().map(|n| n.to_string()).collect(),
+    }
+  }
+
+  #[test]
+  fn diff_snapshots() {
+    let old = StatsSnapshot {
+      assets: vec![
+        asset("main.1a2b3c4d.js", 1000.0, &["main"]),
+        asset("logo.png", 500.0, &[]),
+        asset("legacy.js", 100.0, &["legacy"]),
+      ],
+      chunks: vec![StatsSnapshotChunk {
+        key: "main".to_string(),
+        size: 900.0,
+        sizes: BTreeMap::from([("javascript".to_string(), 900.0)]),
+      }],
+      modules: vec![StatsSnapshotModule {
+        identifier: "./a.js".to_string(),
+        name: "./a.js".to_string(),
+        size: 10.0,
+        chunks: vec!["main".to_string()],
+      }],
+    };
+    let new = StatsSnapshot {
+      assets: vec![
+        asset("main.5e6f7a8b.js", 1200.0, &["main"]),
+        asset("logo.png", 500.0, &[]),
+        asset("vendors.js", 300.0, &["vendors"]),
+      ],
+      chunks: vec![StatsSnapshotChunk {
+        key: "main".to_string(),
+        size: 1100.0,
+        sizes: BTreeMap::from([
+          ("javascript".to_string(), 1000.0),
+          ("css/mini-extract".to_string(), 100.0),
+        ]),
+      }],
+      modules: vec![StatsSnapshotModule {
+        identifier: "./a.js".to_string(),
+        name: "./a.js".to_string(),
+        size: 10.0,
+        chunks: vec!["vendors".to_string()],
+      }],
+    };
+
+    let diff = old.diff(&new);
+    assert_eq!(diff.renamed_assets.len(), 1);
+    assert_eq!(diff.renamed_assets[0].new_name, "main.5e6f7a8b.js");
+    assert_eq!(diff.added_assets[0].name, "vendors.js");
+    assert_eq!(diff.removed_assets[0].name, "legacy.js");
+    assert_eq!(diff.chunk_size_deltas[0].sizes["javascript"], 100.0);
+    assert_eq!(diff.moved_modules.len(), 1);
+
+    let json = serde_json::to_value(&diff).expect("should serialize");
+    assert_eq!(json["renamedAssets"][0]["oldName"], "main.1a2b3c4d.js");
+    assert_eq!(json["chunkSizeDeltas"][0]["sizes"]["javascript"], 100.0);
+    assert_eq!(json["movedModules"][0]["newChunks"][0], "vendors");
+    assert_eq!(
+      serde_json::from_value::<StatsDiff>(json).expect("should deserialize"),
+      diff
+    );
+
+    assert!(diff
+      .check(&StatsDiffThreshold {
+        max_total_increase: Some(1000.0),
+        ..Default::default()
+      })
+      .is_pass());
+    let result = diff.check(&StatsDiffThreshold {
+      max_asset_increase_ratio: Some(0.1),
+      ..Default::default()
+    });
+    assert!(!result.is_pass());
+    let json = serde_json::to_value(&result).expect("should serialize");
+    assert_eq!(json["type"], "fail");
+    assert_eq!(
+      serde_json::from_value::<StatsDiffResult>(json).expect("should deserialize"),
+      result
+    );
+  }
+}
@@ -0,0 +1,485 @@
+use itertools::Itertools;
+use rspack_error::Result;
+use serde_json::{json, Map, Value};
+
+use super::{
+  Stats, StatsAsset, StatsChunk, StatsChunkGroup, StatsChunkGroupAsset, StatsModule,
+  StatsModuleTrace, StatsUsedExports,
+};
+use crate::{PublicPath, StatsOptions};
+
+impl Stats<'_> {
+  /// Serializes the stats into the shape of webpack's `stats.toJson()`, fields disabled in
+  /// `options` are omitted.
+  pub fn to_json(&self, options: &StatsOptions) -> Result<Value> {
+    let mut obj = Map::new();
+
+    if options.hash {
+      obj.insert("hash".into(), json!(self.get_hash()));
+    }
+    if options.public_path {
+      let public_path = match &self.compilation.options.output.public_path {
+        PublicPath::Auto => "auto".to_string(),
+        PublicPath::Filename(filename) => filename.template().unwrap_or_default().to_string(),
+      };
+      obj.insert("publicPath".into(), json!(public_path));
+    }
+    if options.output_path {
+      obj.insert(
+        "outputPath".into(),
+        json!(self.compilation.options.output.path),
+      );
+    }
+    if options.assets {
+      let (assets, assets_by_chunk_name) = self.get_assets();
+      obj.insert(
+        "assetsByChunkName".into(),
+        Value::Object(
+          assets_by_chunk_name
+            .into_iter()
+            .map(|item| (item.name, json!(item.files)))
+            .collect(),
+        ),
+      );
+      obj.insert(
+        "assets".into(),
+        Value::Array(assets.iter().map(asset_to_json).collect()),
+      );
+    }
+    if options.chunks {
+      let chunks = self.get_chunks(
+        options.chunk_modules,
+        options.chunk_relations,
+        options.reasons,
+        options.module_assets,
+        options.nested_modules,
+        options.source,
+        options.used_exports,
+        options.provided_exports,
+        |chunks| {
+          chunks
+            .iter()
+            .map(|chunk| chunk_to_json(chunk, options))
+            .collect::<Vec<_>>()
+        },
+      )?;
+      obj.insert("chunks".into(), Value::Array(chunks));
+    }
+    if options.modules {
+      let modules = self.get_modules(
+        options.reasons,
+        options.module_assets,
+        options.nested_modules,
+        options.source,
+        options.used_exports,
+        options.provided_exports,
+        |modules| {
+          modules
+            .iter()
+            .map(|module| module_to_json(module, options))
+            .collect::<Vec<_>>()
+        },
+      )?;
+      obj.insert("modules".into(), Value::Array(modules));
+    }
+    if options.entrypoints {
+      let entrypoints =
+        self.get_entrypoints(options.chunk_group_auxiliary, options.chunk_group_children);
+      obj.insert("entrypoints".into(), chunk_groups_to_json(&entrypoints));
+    }
+    if options.chunk_groups {
+      let named_chunk_groups =
+        self.get_named_chunk_groups(options.chunk_group_auxiliary, options.chunk_group_children);
+      obj.insert(
+        "namedChunkGroups".into(),
+        chunk_groups_to_json(&named_chunk_groups),
+      );
+    }
+    if options.errors || options.errors_count {
+      let errors = self.get_errors();
+      if options.errors {
+        obj.insert(
+          "errors".into(),
+          Value::Array(
+            errors
+              .iter()
+              .map(|e| {
+                let mut error = json!({
+                  "message": e.message,
+                  "moduleIdentifier": e.module_identifier,
+                  "moduleName": e.module_name,
+                  "moduleId": e.module_id,
+                  "file": e.file,
+                  "chunkName": e.chunk_name,
+                  "chunkEntry": e.chunk_entry,
+                  "chunkInitial": e.chunk_initial,
+                  "chunkId": e.chunk_id,
+                });
+                diagnostic_extras(&mut error, &e.details, &e.stack, &e.module_trace, options);
+                error
+              })
+              .collect(),
+          ),
+        );
+      }
+      if options.errors_count {
+        obj.insert("errorsCount".into(), json!(errors.len()));
+      }
+    }
+    if options.warnings || options.warnings_count {
+      let warnings = self.get_warnings();
+      if options.warnings {
+        obj.insert(
+          "warnings".into(),
+          Value::Array(
+            warnings
+              .iter()
+              .map(|w| {
+                let mut warning = json!({
+                  "message": w.message,
+                  "moduleIdentifier": w.module_identifier,
+                  "moduleName": w.module_name,
+                  "moduleId": w.module_id,
+                  "file": w.file,
+                  "chunkName": w.chunk_name,
+                  "chunkEntry": w.chunk_entry,
+                  "chunkInitial": w.chunk_initial,
+                  "chunkId": w.chunk_id,
+                });
+                diagnostic_extras(&mut warning, &w.details, &w.stack, &w.module_trace, options);
+                warning
+              })
+              .collect(),
+          ),
+        );
+      }
+      if options.warnings_count {
+        obj.insert("warningsCount".into(), json!(warnings.len()));
+      }
+    }
+
+    Ok(Value::Object(obj))
+  }
+}
+
+fn diagnostic_extras(
+  value: &mut Value,
+  details: &Option<String>,
+  stack: &Option<String>,
+  module_trace: &[StatsModuleTrace],
+  options: &StatsOptions,
+) {
+  let Value::Object(obj) = value else {
+    unreachable!()
+  };
+  if options.error_details {
+    obj.insert("details".into(), json!(details));
+  }
+  if options.error_stack {
+    obj.insert("stack".into(), json!(stack));
+  }
+  if options.module_trace {
+    obj.insert(
+      "moduleTrace".into(),
+      Value::Array(
+        module_trace
+          .iter()
+          .map(|trace| {
+            json!({
+              "originIdentifier": trace.origin.identifier,
+              "originName": trace.origin.name,
+              "originId": trace.origin.id,
+              "moduleIdentifier": trace.module.identifier,
+              "moduleName": trace.module.name,
+              "moduleId": trace.module.id,
+            })
+          })
+          .collect(),
+      ),
+    );
+  }
+}
+
+fn asset_to_json(asset: &StatsAsset) -> Value {
+  let info = &asset.info;
+  let mut info_json = json!({
+    "minimized": info.minimized,
+    "development": info.development,
+    "hotModuleReplacement": info.hot_module_replacement,
+    "immutable": info.immutable,
+    "chunkhash": info.chunk_hash,
+    "contenthash": info.content_hash,
+    "fullhash": info.full_hash,
+    "related": info
+      .related
+      .iter()
+      .map(|related| (related.name.clone(), json!(related.value)))
+      .collect::<Map<_, _>>(),
+  });
+  if let Value::Object(obj) = &mut info_json {
+    if let Some(source_filename) = &info.source_filename {
+      obj.insert("sourceFilename".into(), json!(source_filename));
+    }
+    if let Some(javascript_module) = info.javascript_module {
+      obj.insert("javascriptModule".into(), json!(javascript_module));
+    }
+  }
+  json!({
+    "type": asset.r#type,
+    "name": asset.name,
+    "size": asset.size,
+    "emitted": asset.emitted,
+    "chunks": asset.chunks,
+    "chunkNames": asset.chunk_names,
+    "chunkIdHints": asset.chunk_id_hints,
+    "auxiliaryChunks": asset.auxiliary_chunks,
+    "auxiliaryChunkNames": asset.auxiliary_chunk_names,
+    "auxiliaryChunkIdHints": asset.auxiliary_chunk_id_hints,
+    "info": info_json,
+  })
+}
+
+fn module_to_json(module: &StatsModule, options: &StatsOptions) -> Value {
+  let sizes = module
+    .sizes
+    .iter()
+    .map(|size| (size.source_type.to_string(), json!(size.size)))
+    .sorted_by(|a, b| a.0.cmp(&b.0))
+    .collect::<Map<_, _>>();
+  let mut value = json!({
+    "type": module.r#type,
+    "moduleType": module.module_type.as_str(),
+    "identifier": module.identifier.as_str(),
+    "name": module.name,
+    "nameForCondition": module.name_for_condition,
+    "id": module.id,
+    "chunks": module.chunks,
+    "size": module.size,
+    "sizes": sizes,
+    "issuer": module.issuer,
+    "issuerName": module.issuer_name,
+    "issuerId": module.issuer_id,
+    "issuerPath": module
+      .issuer_path
+      .iter()
+      .map(|issuer| json!({
+        "identifier": issuer.identifier,
+        "name": issuer.name,
+        "id": issuer.id,
+      }))
+      .collect::<Vec<_>>(),
+    "orphan": module.orphan,
+    "optimizationBailout": module.optimization_bailout,
+    "depth": module.depth,
+    "preOrderIndex": module.pre_order_index,
+    "postOrderIndex": module.post_order_index,
+    "index": module.pre_order_index,
+    "index2": module.post_order_index,
+    "built": module.built,
+    "codeGenerated": module.code_generated,
+    "buildTimeExecuted": module.build_time_executed,
+    "cached": module.cached,
+    "cacheable": module.cacheable,
+    "optional": module.optional,
+    "failed": module.failed,
+    "errors": module.errors,
+    "warnings": module.warnings,
+  });
+  let Value::Object(obj) = &mut value else {
+    unreachable!()
+  };
+  if let Some(dependent) = module.dependent {
+    obj.insert("dependent".into(), json!(dependent));
+  }
+  if let Some(reasons) = &module.reasons {
+    obj.insert(
+      "reasons".into(),
+      Value::Array(
+        reasons
+          .iter()
+          .map(|reason| {
+            json!({
+              "moduleIdentifier": reason.module_identifier,
+              "moduleName": reason.module_name,
+              "moduleId": reason.module_id,
+              "type": reason.r#type,
+              "userRequest": reason.user_request,
+            })
+          })
+          .collect(),
+      ),
+    );
+  }
+  if let Some(assets) = &module.assets {
+    obj.insert("assets".into(), json!(assets));
+  }
+  if let Some(modules) = &module.modules {
+    obj.insert(
+      "modules".into(),
+      Value::Array(
+        modules
+          .iter()
+          .map(|module| module_to_json(module, options))
+          .collect(),
+      ),
+    );
+  }
+  if let Some(source) = &module.source {
+    obj.insert("source".into(), json!(source.source()));
+  }
+  if let Some(profile) = &module.profile {
+    obj.insert(
+      "profile".into(),
+      json!({
+        "factory": profile.factory.secs * 1000 + profile.factory.subsec_millis as u64,
+        "building": profile.building.secs * 1000 + profile.building.subsec_millis as u64,
+      }),
+    );
+  }
+  if options.provided_exports {
+    obj.insert("providedExports".into(), json!(module.provided_exports));
+  }
+  if options.used_exports {
+    let used_exports = match &module.used_exports {
+      Some(StatsUsedExports::Vec(exports)) => json!(exports),
+      Some(StatsUsedExports::Bool(used)) => json!(used),
+      Some(StatsUsedExports::Null) | None => Value::Null,
+    };
+    obj.insert("usedExports".into(), used_exports);
+  }
+  value
+}
+
+fn chunk_to_json(chunk: &StatsChunk, options: &StatsOptions) -> Value {
+  let sizes = chunk
+    .sizes
+    .iter()
+    .map(|(source_type, size)| (source_type.to_string(), json!(size)))
+    .sorted_by(|a, b| a.0.cmp(&b.0))
+    .collect::<Map<_, _>>();
+  let children_by_order = chunk
+    .children_by_order
+    .iter()
+    .map(|(order, children)| (order.to_string(), json!(children)))
+    .sorted_by(|a, b| a.0.cmp(&b.0))
+    .collect::<Map<_, _>>();
+  let mut value = json!({
+    "type": chunk.r#type,
+    "rendered": chunk.rendered,
+    "initial": chunk.initial,
+    "entry": chunk.entry,
+    "reason": chunk.reason,
+    "size": chunk.size,
+    "sizes": sizes,
+    "names": chunk.names,
+    "idHints": chunk.id_hints,
+    "runtime": chunk.runtime.iter().map(|r| r.to_string()).sorted().collect::<Vec<_>>(),
+    "files": chunk.files,
+    "auxiliaryFiles": chunk.auxiliary_files,
+    "hash": chunk.hash,
+    "childrenByOrder": children_by_order,
+    "id": chunk.id,
+    "origins": chunk
+      .origins
+      .iter()
+      .map(|origin| json!({
+        "module": origin.module,
+        "moduleIdentifier": origin.module_identifier,
+        "moduleName": origin.module_name,
+        "moduleId": origin.module_id,
+        "loc": origin.loc,
+        "request": origin.request,
+      }))
+      .collect::<Vec<_>>(),
+  });
+  let Value::Object(obj) = &mut value else {
+    unreachable!()
+  };
+  if let Some(siblings) = &chunk.siblings {
+    obj.insert("siblings".into(), json!(siblings));
+  }
+  if let Some(parents) = &chunk.parents {
+    obj.insert("parents".into(), json!(parents));
+  }
+  if let Some(children) = &chunk.children {
+    obj.insert("children".into(), json!(children));
+  }
+  if let Some(modules) = &chunk.modules {
+    obj.insert(
+      "modules".into(),
+      Value::Array(
+        modules
+          .iter()
+          .map(|module| module_to_json(module, options))
+          .collect(),
+      ),
+    );
+  }
+  value
+}
+
+fn chunk_groups_to_json(chunk_groups: &[StatsChunkGroup]) -> Value {
+  Value::Object(
+    chunk_groups
+      .iter()
+      .map(|chunk_group| (chunk_group.name.clone(), chunk_group_to_json(chunk_group)))
+      .collect(),
+  )
+}
+
+fn chunk_group_assets_to_json(assets: &[StatsChunkGroupAsset]) -> Value {
+  Value::Array(
+    assets
+      .iter()
+      .map(|asset| json!({ "name": asset.name, "size": asset.size }))
+      .collect(),
+  )
+}
+
+fn chunk_group_to_json(chunk_group: &StatsChunkGroup) -> Value {
+  let mut value = json!({
+    "name": chunk_group.name,
+    "chunks": chunk_group.chunks,
+    "assets": chunk_group_assets_to_json(&chunk_group.assets),
+    "assetsSize": chunk_group.assets_size,
+  });
+  let Value::Object(obj) = &mut value else {
+    unreachable!()
+  };
+  if let Some(auxiliary_assets) = &chunk_group.auxiliary_assets {
+    obj.insert(
+      "auxiliaryAssets".into(),
+      chunk_group_assets_to_json(auxiliary_assets),
+    );
+    obj.insert(
+      "auxiliaryAssetsSize".into(),
+      json!(chunk_group.auxiliary_assets_size),
+    );
+  }
+  if let Some(children) = &chunk_group.children {
+    obj.insert(
+      "children".into(),
+      json!({
+        "preload": children.preload.iter().map(chunk_group_to_json).collect::<Vec<_>>(),
+        "prefetch": children.prefetch.iter().map(chunk_group_to_json).collect::<Vec<_>>(),
+      }),
+    );
+  }
+  value
+}
+
+#[cfg(test)]
+mod test {
+  use crate::{StatsOptions, StatsPreset};
+
+  #[test]
+  fn stats_presets() {
+    let none = StatsOptions::from_preset("none".parse().expect("should parse"));
+    assert!(!none.errors && !none.assets);
+    let errors_only = StatsOptions::from_preset("errors-only".parse().expect("should parse"));
+    assert!(errors_only.errors && errors_only.module_trace && !errors_only.warnings);
+    let verbose = StatsOptions::from_preset(StatsPreset::Verbose);
+    assert!(verbose.chunk_modules && !verbose.modules);
+    assert!("detailed".parse::<StatsPreset>().is_err());
+  }
+}
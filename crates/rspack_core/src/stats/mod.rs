@@ -1,7 +1,12 @@
+mod diff;
+mod json;
+mod printer;
+
 use std::borrow::Cow;
 use std::fmt::Debug;
 use std::path::PathBuf;
 
+pub use diff::*;
 use either::Either;
 use itertools::Itertools;
 use rayon::prelude::*;
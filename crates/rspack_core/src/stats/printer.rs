@@ -0,0 +1,343 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use itertools::Itertools;
+use owo_colors::{OwoColorize, Style};
+use rspack_error::emitter::{DiagnosticDisplay, DiagnosticDisplayer};
+use rspack_error::Result;
+use rspack_util::size::format_size;
+
+use super::{Stats, StatsAsset, StatsChunk, StatsChunkGroup, StatsMillisecond, StatsModule};
+use crate::StatsOptions;
+
+fn format_millisecond(ms: &StatsMillisecond) -> u64 {
+  ms.secs * 1000 + ms.subsec_millis as u64
+}
+
+struct Painter {
+  colors: bool,
+}
+
+impl Painter {
+  fn paint(&self, text: impl AsRef<str>, style: Style) -> String {
+    if self.colors {
+      text.as_ref().style(style).to_string()
+    } else {
+      text.as_ref().to_string()
+    }
+  }
+
+  fn bold(&self, text: impl AsRef<str>) -> String {
+    self.paint(text, Style::new().bold())
+  }
+
+  fn green(&self, text: impl AsRef<str>) -> String {
+    self.paint(text, Style::new().green().bold())
+  }
+
+  fn yellow(&self, text: impl AsRef<str>) -> String {
+    self.paint(text, Style::new().yellow().bold())
+  }
+
+  fn red(&self, text: impl AsRef<str>) -> String {
+    self.paint(text, Style::new().red().bold())
+  }
+
+  fn cyan(&self, text: impl AsRef<str>) -> String {
+    self.paint(text, Style::new().cyan())
+  }
+}
+
+impl Stats<'_> {
+  /// Renders the human readable summary printed by the CLI, sections disabled in `options` are
+  /// omitted. `time` is the duration of the build measured by the caller.
+  pub fn to_text(&self, options: &StatsOptions, time: Option<Duration>) -> Result<String> {
+    let painter = Painter {
+      colors: options.colors,
+    };
+    let mut out = String::new();
+
+    if options.assets {
+      let (assets, _) = self.get_assets();
+      for asset in &assets {
+        print_asset(&mut out, &painter, asset);
+      }
+    }
+    if options.entrypoints {
+      for entrypoint in self.get_entrypoints(options.chunk_group_auxiliary, false) {
+        print_chunk_group(&mut out, &painter, "Entrypoint", &entrypoint);
+      }
+    }
+    if options.chunks {
+      let chunks = self.get_chunks(
+        options.chunk_modules,
+        false,
+        false,
+        false,
+        options.nested_modules,
+        false,
+        false,
+        false,
+        |chunks| {
+          let mut out = String::new();
+          for chunk in &chunks {
+            print_chunk(&mut out, &painter, chunk);
+          }
+          out
+        },
+      )?;
+      out.push_str(&chunks);
+    }
+    if options.modules {
+      let modules = self.get_modules(
+        false,
+        false,
+        options.nested_modules,
+        false,
+        false,
+        false,
+        |modules| {
+          let mut out = String::new();
+          for module in &modules {
+            print_module(&mut out, &painter, module, 0);
+          }
+          out
+        },
+      )?;
+      out.push_str(&modules);
+    }
+
+    let mut displayer = DiagnosticDisplayer::new(options.colors);
+    let warnings = self.compilation.get_warnings_sorted().collect::<Vec<_>>();
+    if options.warnings {
+      for warning in &warnings {
+        let message = displayer.emit_diagnostic(warning)?;
+        let _ = writeln!(
+          out,
+          "\n{}\n{}",
+          painter.yellow("WARNING"),
+          message.trim_end()
+        );
+      }
+    }
+    let errors = self.compilation.get_errors_sorted().collect::<Vec<_>>();
+    if options.errors {
+      for error in &errors {
+        let message = displayer.emit_diagnostic(error)?;
+        let _ = writeln!(out, "\n{}\n{}", painter.red("ERROR"), message.trim_end());
+      }
+    }
+
+    let summary = match (errors.len(), warnings.len()) {
+      (0, 0) => painter.green("successfully"),
+      (0, w) => format!("with {}", painter.yellow(plural(w, "warning"))),
+      (e, 0) => format!("with {}", painter.red(plural(e, "error"))),
+      (e, w) => format!(
+        "with {} and {}",
+        painter.red(plural(e, "error")),
+        painter.yellow(plural(w, "warning"))
+      ),
+    };
+    let _ = write!(out, "\nRspack compiled {summary}");
+    if let Some(time) = time {
+      let _ = write!(out, " in {} ms", time.as_millis());
+    }
+    out.push('\n');
+    Ok(out)
+  }
+}
+
+fn plural(count: usize, noun: &str) -> String {
+  if count == 1 {
+    format!("{count} {noun}")
+  } else {
+    format!("{count} {noun}s")
+  }
+}
+
+fn print_asset(out: &mut String, painter: &Painter, asset: &StatsAsset) {
+  let _ = write!(
+    out,
+    "asset {} {}",
+    painter.green(&asset.name),
+    format_size(asset.size)
+  );
+  if asset.emitted {
+    let _ = write!(out, " {}", painter.green("[emitted]"));
+  }
+  if asset.info.immutable {
+    let _ = write!(out, " {}", painter.green("[immutable]"));
+  }
+  if asset.info.development {
+    let _ = write!(out, " {}", painter.green("[dev]"));
+  }
+  if asset.info.hot_module_replacement {
+    let _ = write!(out, " {}", painter.green("[hmr]"));
+  }
+  if asset.info.minimized {
+    let _ = write!(out, " {}", painter.green("[minimized]"));
+  }
+  if let Some(source_filename) = &asset.info.source_filename {
+    let _ = write!(out, " [from: {source_filename}]");
+  }
+  if !asset.chunk_names.is_empty() {
+    let _ = write!(out, " (name: {})", asset.chunk_names.join(", "));
+  }
+  if !asset.chunk_id_hints.is_empty() {
+    let _ = write!(out, " (id hint: {})", asset.chunk_id_hints.join(", "));
+  }
+  if !asset.auxiliary_chunk_names.is_empty() {
+    let _ = write!(
+      out,
+      " (auxiliary name: {})",
+      asset.auxiliary_chunk_names.join(", ")
+    );
+  }
+  out.push('\n');
+}
+
+fn print_chunk_group(
+  out: &mut String,
+  painter: &Painter,
+  kind: &str,
+  chunk_group: &StatsChunkGroup,
+) {
+  let _ = write!(
+    out,
+    "{kind} {} {}",
+    painter.bold(&chunk_group.name),
+    format_size(chunk_group.assets_size)
+  );
+  if let Some(auxiliary_assets_size) = chunk_group.auxiliary_assets_size
+    && auxiliary_assets_size > 0.0
+  {
+    let _ = write!(out, " ({})", format_size(auxiliary_assets_size));
+  }
+  let assets = chunk_group
+    .assets
+    .iter()
+    .map(|asset| painter.green(&asset.name))
+    .join(" ");
+  let _ = write!(out, " = {assets}");
+  if let Some(auxiliary_assets) = &chunk_group.auxiliary_assets
+    && !auxiliary_assets.is_empty()
+  {
+    let _ = write!(
+      out,
+      " {}",
+      plural(auxiliary_assets.len(), "auxiliary asset")
+    );
+  }
+  out.push('\n');
+}
+
+fn print_chunk(out: &mut String, painter: &Painter, chunk: &StatsChunk) {
+  let _ = write!(out, "chunk");
+  if !chunk.runtime.is_empty() {
+    let _ = write!(
+      out,
+      " (runtime: {})",
+      chunk
+        .runtime
+        .iter()
+        .map(|r| r.to_string())
+        .sorted()
+        .join(", ")
+    );
+  }
+  for file in &chunk.files {
+    let _ = write!(out, " {}", painter.green(file));
+  }
+  if !chunk.names.is_empty() {
+    let _ = write!(out, " ({})", chunk.names.join(", "));
+  }
+  if !chunk.id_hints.is_empty() {
+    let _ = write!(out, " (id hint: {})", chunk.id_hints.join(", "));
+  }
+  let _ = write!(out, " {}", format_size(chunk.size));
+  if chunk.entry {
+    let _ = write!(out, " {}", painter.yellow("[entry]"));
+  } else if chunk.initial {
+    let _ = write!(out, " {}", painter.yellow("[initial]"));
+  }
+  if chunk.rendered {
+    let _ = write!(out, " {}", painter.green("[rendered]"));
+  }
+  if let Some(reason) = &chunk.reason {
+    let _ = write!(out, " {}", painter.yellow(reason));
+  }
+  out.push('\n');
+  if let Some(modules) = &chunk.modules {
+    for module in modules {
+      print_module(out, painter, module, 1);
+    }
+  }
+}
+
+fn print_module(out: &mut String, painter: &Painter, module: &StatsModule, depth: usize) {
+  let _ = write!(
+    out,
+    "{}{} {}",
+    "  ".repeat(depth),
+    painter.bold(&module.name),
+    format_size(module.size)
+  );
+  if let Some(id) = module.id
+    && !id.is_empty()
+    && id != module.name
+  {
+    let _ = write!(out, " [{}]", painter.cyan(id));
+  }
+  if module.orphan {
+    let _ = write!(out, " {}", painter.yellow("[orphan]"));
+  }
+  if module.dependent == Some(true) {
+    let _ = write!(out, " {}", painter.cyan("[dependent]"));
+  }
+  if module.built {
+    let _ = write!(out, " {}", painter.green("[built]"));
+  }
+  if module.code_generated {
+    let _ = write!(out, " {}", painter.green("[code generated]"));
+  }
+  if module.build_time_executed {
+    let _ = write!(out, " {}", painter.green("[only used at build time]"));
+  }
+  if module.cached {
+    let _ = write!(out, " {}", painter.green("[cached]"));
+  }
+  if module.optional {
+    let _ = write!(out, " {}", painter.yellow("[optional]"));
+  }
+  if module.failed {
+    let _ = write!(out, " {}", painter.red("[failed]"));
+  }
+  if module.errors > 0 {
+    let _ = write!(
+      out,
+      " {}",
+      painter.red(plural(module.errors as usize, "error"))
+    );
+  }
+  if module.warnings > 0 {
+    let _ = write!(
+      out,
+      " {}",
+      painter.yellow(plural(module.warnings as usize, "warning"))
+    );
+  }
+  if let Some(profile) = &module.profile {
+    let _ = write!(
+      out,
+      " (factory: {} ms, building: {} ms)",
+      format_millisecond(&profile.factory),
+      format_millisecond(&profile.building)
+    );
+  }
+  out.push('\n');
+  if let Some(modules) = &module.modules {
+    for module in modules {
+      print_module(out, painter, module, depth + 1);
+    }
+  }
+}
@@ -0,0 +1,9 @@
+//! Stable codes of diagnostics. Unlike titles and messages they don't change with the wording, so
+//! they can be used to ignore a specific kind of warning.
+
+pub const EXPORT_NOT_FOUND: &str = "export-not-found";
+pub const CRITICAL_DEPENDENCY: &str = "critical-dependency";
+pub const CASE_SENSITIVE_MODULES: &str = "case-sensitive-modules";
+pub const ASSET_SIZE_LIMIT: &str = "asset-size-limit";
+pub const ENTRYPOINT_SIZE_LIMIT: &str = "entrypoint-size-limit";
+pub const NO_ASYNC_CHUNKS: &str = "no-async-chunks";
@@ -8,7 +8,7 @@ use std::{
 use miette::{GraphicalTheme, IntoDiagnostic, MietteDiagnostic};
 use rspack_identifier::Identifier;
 
-use crate::{graphical::GraphicalReportHandler, Error};
+use crate::{graphical::GraphicalReportHandler, Error, TraceableError};
 
 #[derive(Debug, Clone, Default, Copy, PartialEq, Eq, Hash)]
 pub enum RspackSeverity {
@@ -69,6 +69,7 @@ pub struct Diagnostic {
   hide_stack: Option<bool>,
   chunk: Option<usize>,
   stack: Option<String>,
+  code: Option<String>,
 }
 
 impl From<Box<dyn miette::Diagnostic + Send + Sync>> for Diagnostic {
@@ -86,6 +87,7 @@ impl From<miette::Error> for Diagnostic {
       hide_stack: None,
       chunk: None,
       stack: None,
+      code: None,
     }
   }
 }
@@ -112,6 +114,7 @@ impl Diagnostic {
       hide_stack: None,
       chunk: None,
       stack: None,
+      code: None,
     }
   }
 
@@ -128,6 +131,7 @@ impl Diagnostic {
       hide_stack: None,
       chunk: None,
       stack: None,
+      code: None,
     }
   }
 }
@@ -200,6 +204,44 @@ impl Diagnostic {
     self
   }
 
+  /// The stable code of [crate::codes], set on the diagnostic or on the [TraceableError] it was
+  /// created from.
+  pub fn diagnostic_code(&self) -> Option<&str> {
+    self
+      .code
+      .as_deref()
+      .or_else(|| self.traceable_error()?.diagnostic_code())
+  }
+
+  pub fn with_diagnostic_code(mut self, code: impl Into<String>) -> Self {
+    self.code = Some(code.into());
+    self
+  }
+
+  /// The [TraceableError] this diagnostic was created from, either directly or as the source of a
+  /// wrapping error such as a module parse error.
+  pub fn traceable_error(&self) -> Option<&TraceableError> {
+    fn downcast<'a>(error: &'a (dyn std::error::Error + 'static)) -> Option<&'a TraceableError> {
+      error.downcast_ref::<TraceableError>().or_else(|| {
+        let boxed = error.downcast_ref::<Box<dyn miette::Diagnostic + Send + Sync>>()?;
+        let error: &(dyn std::error::Error + 'static) = boxed.as_ref();
+        error.downcast_ref::<TraceableError>()
+      })
+    }
+    if let Some(error) = self.inner.downcast_ref::<TraceableError>() {
+      return Some(error);
+    }
+    if let Some(boxed) = self
+      .inner
+      .downcast_ref::<Box<dyn miette::Diagnostic + Send + Sync>>()
+    {
+      let error: &(dyn std::error::Error + 'static) = boxed.as_ref();
+      return std::iter::successors(Some(error), |error| error.source()).find_map(downcast);
+    }
+    let error: &(dyn std::error::Error + 'static) = &**self.inner;
+    std::iter::successors(Some(error), |error| error.source()).find_map(downcast)
+  }
+
   pub fn details(&self) -> Option<String> {
     let hide_stack = self.hide_stack.unwrap_or_default();
     if hide_stack {
@@ -1,11 +1,13 @@
-use std::io::Write;
+use std::{io::Write, path::Path};
 
 use anyhow::Context;
-use miette::IntoDiagnostic;
+use miette::{IntoDiagnostic, SourceSpan};
+use serde_json::{json, Value};
 use termcolor::{Buffer, ColorSpec, StandardStreamLock, WriteColor};
 use termcolor::{ColorChoice, StandardStream};
+use url::Url;
 
-use crate::Diagnostic;
+use crate::{Diagnostic, DiagnosticKind, RspackSeverity};
 
 pub trait FlushDiagnostic {
   fn flush_diagnostic(&mut self) {}
@@ -200,3 +202,368 @@ impl DiagnosticDisplay for DiagnosticDisplayer {
     }
   }
 }
+
+/// Location of a labeled span of a [Diagnostic], lines and columns are 1-based.
+struct DiagnosticSpan {
+  label: Option<String>,
+  offset: usize,
+  length: usize,
+  start_line: usize,
+  start_column: usize,
+  end_line: usize,
+  end_column: usize,
+}
+
+fn diagnostic_spans(diagnostic: &Diagnostic) -> Vec<DiagnosticSpan> {
+  // the labels of a wrapped error are not passed through by the wrapper
+  let diagnostic: &dyn miette::Diagnostic = match diagnostic.traceable_error() {
+    Some(e) => e,
+    None => diagnostic.as_ref(),
+  };
+  let (Some(source_code), Some(labels)) = (diagnostic.source_code(), diagnostic.labels()) else {
+    return vec![];
+  };
+  let location = |offset: usize| {
+    let contents = source_code
+      .read_span(&SourceSpan::new(offset.into(), 0.into()), 0, 0)
+      .ok()?;
+    Some((contents.line() + 1, contents.column() + 1))
+  };
+  labels
+    .filter_map(|label| {
+      let (start_line, start_column) = location(label.offset())?;
+      let (end_line, end_column) = location(label.offset() + label.len())?;
+      Some(DiagnosticSpan {
+        label: label.label().map(ToOwned::to_owned),
+        offset: label.offset(),
+        length: label.len(),
+        start_line,
+        start_column,
+        end_line,
+        end_column,
+      })
+    })
+    .collect()
+}
+
+/// Title, message and kind of a diagnostic, taken from the [TraceableError] if there is one.
+fn diagnostic_parts(diagnostic: &Diagnostic) -> (Option<String>, String, DiagnosticKind) {
+  match diagnostic.traceable_error() {
+    Some(e) => (
+      Some(e.title().to_string()),
+      e.message().to_string(),
+      e.kind(),
+    ),
+    None => (
+      diagnostic.code().map(|code| code.to_string()),
+      diagnostic.message(),
+      DiagnosticKind::default(),
+    ),
+  }
+}
+
+fn diagnostic_to_json(diagnostic: &Diagnostic) -> Value {
+  let (title, message, kind) = diagnostic_parts(diagnostic);
+  json!({
+    "severity": diagnostic.severity().to_string(),
+    "kind": kind.to_string(),
+    "code": diagnostic.diagnostic_code(),
+    "title": title,
+    "message": message,
+    "file": diagnostic.file(),
+    "moduleIdentifier": diagnostic.module_identifier().map(|identifier| identifier.as_str()),
+    "help": diagnostic.help().map(|help| help.to_string()),
+    "url": diagnostic.url().map(|url| url.to_string()),
+    "spans": diagnostic_spans(diagnostic)
+      .into_iter()
+      .map(|span| json!({
+        "label": span.label,
+        "offset": span.offset,
+        "length": span.length,
+        "startLine": span.start_line,
+        "startColumn": span.start_column,
+        "endLine": span.end_line,
+        "endColumn": span.end_column,
+      }))
+      .collect::<Vec<_>>(),
+  })
+}
+
+/// Emits every diagnostic as a JSON object on its own line, for tools which consume
+/// diagnostics without parsing the graphical report.
+#[derive(Debug, Default, Clone)]
+pub struct JsonLinesDiagnosticDisplay;
+
+impl DiagnosticDisplay for JsonLinesDiagnosticDisplay {
+  type Output = crate::Result<String>;
+
+  fn emit_batch_diagnostic(
+    &mut self,
+    diagnostics: impl Iterator<Item = &Diagnostic>,
+  ) -> Self::Output {
+    diagnostics.map(|d| self.emit_diagnostic(d)).collect()
+  }
+
+  fn emit_diagnostic(&mut self, diagnostic: &Diagnostic) -> Self::Output {
+    Ok(format!("{}\n", diagnostic_to_json(diagnostic)))
+  }
+}
+
+/// Emits diagnostics as a [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+/// log with a single run, which is understood by code scanning services and editors.
+#[derive(Debug, Clone)]
+pub struct SarifDiagnosticDisplay {
+  tool_name: String,
+}
+
+impl Default for SarifDiagnosticDisplay {
+  fn default() -> Self {
+    Self {
+      tool_name: "rspack".to_string(),
+    }
+  }
+}
+
+impl SarifDiagnosticDisplay {
+  pub fn with_tool_name(self, tool_name: String) -> Self {
+    Self { tool_name }
+  }
+
+  fn result(diagnostic: &Diagnostic) -> Value {
+    let (title, message, kind) = diagnostic_parts(diagnostic);
+    let rule_id = diagnostic
+      .diagnostic_code()
+      .map(ToOwned::to_owned)
+      .or(title)
+      .unwrap_or_else(|| kind.to_string());
+    let level = match diagnostic.severity() {
+      RspackSeverity::Error => "error",
+      RspackSeverity::Warn => "warning",
+    };
+    let mut locations = vec![];
+    if let Some(file) = diagnostic.file() {
+      let spans = diagnostic_spans(diagnostic);
+      let artifact_location = artifact_location(file);
+      if spans.is_empty() {
+        locations.push(json!({ "physicalLocation": { "artifactLocation": artifact_location } }));
+      }
+      for span in spans {
+        let mut location = json!({
+          "physicalLocation": {
+            "artifactLocation": artifact_location,
+            "region": {
+              "startLine": span.start_line,
+              "startColumn": span.start_column,
+              "endLine": span.end_line,
+              "endColumn": span.end_column,
+              "charOffset": span.offset,
+              "charLength": span.length,
+            },
+          },
+        });
+        if let Some(label) = span.label {
+          location["message"] = json!({ "text": label });
+        }
+        locations.push(location);
+      }
+    }
+    if let Some(module_identifier) = diagnostic.module_identifier() {
+      locations.push(json!({
+        "logicalLocations": [{ "fullyQualifiedName": module_identifier.as_str(), "kind": "module" }],
+      }));
+    }
+    json!({
+      "ruleId": rule_id,
+      "level": level,
+      "message": { "text": message },
+      "locations": locations,
+      "properties": {
+        "kind": kind.to_string(),
+        "help": diagnostic.help().map(|help| help.to_string()),
+      },
+    })
+  }
+
+  fn log(&self, results: Vec<Value>) -> String {
+    let mut rule_ids = results
+      .iter()
+      .filter_map(|result| result["ruleId"].as_str())
+      .collect::<Vec<_>>();
+    rule_ids.sort_unstable();
+    rule_ids.dedup();
+    json!({
+      "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+      "version": "2.1.0",
+      "runs": [{
+        "tool": {
+          "driver": {
+            "name": self.tool_name,
+            "informationUri": "https://www.rspack.dev",
+            "rules": rule_ids.into_iter().map(|id| json!({ "id": id })).collect::<Vec<_>>(),
+          },
+        },
+        "results": results,
+      }],
+    })
+    .to_string()
+  }
+}
+
+/// The SARIF `artifactLocation` of `file`, absolute paths are `file://` URIs and relative paths
+/// are relative to the `%SRCROOT%` base of the consumer.
+fn artifact_location(file: &Path) -> Value {
+  if let Ok(uri) = Url::from_file_path(file) {
+    return json!({ "uri": uri.as_str() });
+  }
+  let uri = file
+    .components()
+    .map(|component| {
+      component
+        .as_os_str()
+        .to_string_lossy()
+        .bytes()
+        .map(|byte| match byte {
+          b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+            char::from(byte).to_string()
+          }
+          _ => format!("%{byte:02X}"),
+        })
+        .collect::<String>()
+    })
+    .collect::<Vec<_>>()
+    .join("/");
+  json!({ "uri": uri, "uriBaseId": "%SRCROOT%" })
+}
+
+impl DiagnosticDisplay for SarifDiagnosticDisplay {
+  type Output = crate::Result<String>;
+
+  fn emit_batch_diagnostic(
+    &mut self,
+    diagnostics: impl Iterator<Item = &Diagnostic>,
+  ) -> Self::Output {
+    Ok(self.log(diagnostics.map(Self::result).collect()))
+  }
+
+  fn emit_diagnostic(&mut self, diagnostic: &Diagnostic) -> Self::Output {
+    Ok(self.log(vec![Self::result(diagnostic)]))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::path::PathBuf;
+
+  use serde_json::{json, Value};
+
+  use super::{DiagnosticDisplay, JsonLinesDiagnosticDisplay, SarifDiagnosticDisplay};
+  use crate::{codes, Diagnostic, RspackSeverity, TraceableError};
+
+  const SOURCE: &str = "const a = 1;\nfoo(;\n";
+
+  fn diagnostic(file: &str, code: Option<&str>, severity: RspackSeverity) -> Diagnostic {
+    let mut error = TraceableError::from_file(
+      SOURCE.to_string(),
+      17,
+      18,
+      "JavaScript parse error".to_string(),
+      "Expression expected".to_string(),
+    )
+    .with_severity(severity);
+    if let Some(code) = code {
+      error = error.with_diagnostic_code(code);
+    }
+    Diagnostic::from(miette::Error::new(error)).with_file(Some(PathBuf::from(file)))
+  }
+
+  #[test]
+  fn json_lines() {
+    let diagnostics = [
+      diagnostic(
+        "/project/src/index.js",
+        Some(codes::CRITICAL_DEPENDENCY),
+        RspackSeverity::Warn,
+      ),
+      diagnostic("/project/src/index.js", None, RspackSeverity::Error),
+    ];
+    let output = JsonLinesDiagnosticDisplay
+      .emit_batch_diagnostic(diagnostics.iter())
+      .expect("should emit");
+    let lines = output
+      .lines()
+      .map(|line| serde_json::from_str::<Value>(line).expect("should be json"))
+      .collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["severity"], json!("warning"));
+    assert_eq!(lines[0]["code"], json!(codes::CRITICAL_DEPENDENCY));
+    assert_eq!(lines[0]["title"], json!("JavaScript parse error"));
+    assert_eq!(lines[0]["message"], json!("Expression expected"));
+    assert_eq!(lines[0]["file"], json!("/project/src/index.js"));
+    assert_eq!(
+      lines[0]["spans"],
+      json!([{
+        "label": null,
+        "offset": 17,
+        "length": 1,
+        "startLine": 2,
+        "startColumn": 5,
+        "endLine": 2,
+        "endColumn": 6,
+      }])
+    );
+    assert_eq!(lines[1]["severity"], json!("error"));
+    assert_eq!(lines[1]["code"], json!(null));
+  }
+
+  #[test]
+  fn sarif() {
+    let diagnostics = [
+      diagnostic(
+        "/project/src/index.js",
+        Some(codes::CRITICAL_DEPENDENCY),
+        RspackSeverity::Warn,
+      ),
+      diagnostic("src/my file.js", None, RspackSeverity::Error),
+    ];
+    let output = SarifDiagnosticDisplay::default()
+      .emit_batch_diagnostic(diagnostics.iter())
+      .expect("should emit");
+    let log = serde_json::from_str::<Value>(&output).expect("should be json");
+    assert_eq!(log["version"], json!("2.1.0"));
+    let run = &log["runs"][0];
+    assert_eq!(
+      run["tool"]["driver"]["rules"],
+      json!([{ "id": "JavaScript parse error" }, { "id": codes::CRITICAL_DEPENDENCY }])
+    );
+
+    let warning = &run["results"][0];
+    assert_eq!(warning["ruleId"], json!(codes::CRITICAL_DEPENDENCY));
+    assert_eq!(warning["level"], json!("warning"));
+    assert_eq!(warning["message"], json!({ "text": "Expression expected" }));
+    let location = &warning["locations"][0];
+    // the span of a traceable error has no label
+    assert!(location.get("message").is_none());
+    assert_eq!(
+      location["physicalLocation"],
+      json!({
+        "artifactLocation": { "uri": "file:///project/src/index.js" },
+        "region": {
+          "startLine": 2,
+          "startColumn": 5,
+          "endLine": 2,
+          "endColumn": 6,
+          "charOffset": 17,
+          "charLength": 1,
+        },
+      })
+    );
+
+    let error = &run["results"][1];
+    assert_eq!(error["ruleId"], json!("JavaScript parse error"));
+    assert_eq!(error["level"], json!("error"));
+    assert_eq!(
+      error["locations"][0]["physicalLocation"]["artifactLocation"],
+      json!({ "uri": "src/my%20file.js", "uriBaseId": "%SRCROOT%" })
+    );
+  }
+}
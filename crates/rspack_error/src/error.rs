@@ -58,6 +58,7 @@ pub struct TraceableError {
   help: Option<String>,
   url: Option<String>,
   hide_stack: Option<bool>,
+  code: Option<String>,
 }
 
 impl Diagnostic for TraceableError {
@@ -124,6 +125,12 @@ impl TraceableError {
     self
   }
 
+  /// Sets the stable code of [crate::codes].
+  pub fn with_diagnostic_code(mut self, code: impl Into<String>) -> Self {
+    self.code = Some(code.into());
+    self
+  }
+
   pub fn from_source_file(
     source_file: &SourceFile,
     start: usize,
@@ -165,12 +172,46 @@ impl TraceableError {
       help: None,
       url: None,
       hide_stack: None,
+      code: None,
     }
   }
 
   pub fn hide_stack(&self) -> Option<bool> {
     self.hide_stack
   }
+
+  pub fn title(&self) -> &str {
+    &self.title
+  }
+
+  pub fn message(&self) -> &str {
+    &self.message
+  }
+
+  pub fn kind(&self) -> DiagnosticKind {
+    self.kind
+  }
+
+  pub fn diagnostic_code(&self) -> Option<&str> {
+    self.code.as_deref()
+  }
+
+  pub fn src(&self) -> &str {
+    &self.src
+  }
+
+  /// Start and end offsets of the label in [TraceableError::src].
+  pub fn span(&self) -> (usize, usize) {
+    (self.label.offset(), self.label.offset() + self.label.len())
+  }
+
+  /// Points the error to `start..end` of another source, e.g. the source of a resource before it
+  /// was transformed by loaders.
+  pub fn with_source(mut self, src: String, start: usize, end: usize) -> Self {
+    self.src = Arc::new(src);
+    self.label = SourceSpan::new(start.into(), end.saturating_sub(start).into());
+    self
+  }
 }
 
 /// Multiple errors to represent different kinds of errors.
@@ -2,6 +2,7 @@
 #![feature(anonymous_lifetime_in_impl_trait)]
 
 mod catch_unwind;
+pub mod codes;
 mod diagnostic;
 mod error;
 mod ext;
@@ -1,8 +1,8 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use futures::future::BoxFuture;
 
-use crate::Result;
+use crate::{memory::normalize, sync::unsupported, FileMetadata, Result};
 
 pub trait AsyncWritableFileSystem {
   /// Creates a new, empty directory at the provided path.
@@ -36,6 +36,48 @@ pub trait AsyncReadableFileSystem {
   ///
   /// Error: This function will return an error if path does not already exist.
   fn read(&self, file: &Path) -> BoxFuture<'_, Result<Vec<u8>>>;
+
+  /// Returns the names of the entries within a directory.
+  ///
+  /// Error: This function will return an error if path does not exist or is not a directory.
+  /// The default implementation returns an [`std::io::ErrorKind::Unsupported`] error.
+  fn read_dir(&self, dir: &Path) -> BoxFuture<'_, Result<Vec<String>>> {
+    let err = unsupported("read_dir", dir);
+    Box::pin(async move { Err(err) })
+  }
+
+  /// Queries the metadata about a file or a directory.
+  ///
+  /// Error: This function will return an error if path does not exist.
+  /// The default implementation reads the file, so it fails for directories.
+  fn metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>> {
+    let content = self.read(path);
+    Box::pin(async move {
+      content
+        .await
+        .map(|content| FileMetadata::from_content(&content))
+    })
+  }
+
+  /// Returns the canonical, absolute form of a path.
+  ///
+  /// Error: This function will return an error if path does not exist.
+  /// The default implementation only normalizes `.` and `..` lexically, file systems supporting
+  /// symlinks should resolve them.
+  fn canonicalize(&self, path: &Path) -> BoxFuture<'_, Result<PathBuf>> {
+    let metadata = self.metadata(path);
+    let normalized = normalize(path);
+    Box::pin(async move {
+      metadata.await?;
+      Ok(normalized)
+    })
+  }
+}
+
+impl std::fmt::Debug for dyn AsyncReadableFileSystem + Send + Sync {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str("AsyncReadableFileSystem")
+  }
 }
 
 /// Async readable and writable file system representation.
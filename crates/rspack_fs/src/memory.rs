@@ -0,0 +1,321 @@
+use std::{
+  collections::BTreeMap,
+  io::{self, ErrorKind},
+  path::{Component, Path, PathBuf},
+  sync::Mutex,
+  time::SystemTime,
+};
+
+use super::{
+  cfg_async,
+  sync::{ReadableFileSystem, WritableFileSystem},
+  Error, FileMetadata, Result,
+};
+
+#[derive(Debug, Clone)]
+enum Entry {
+  File { content: Vec<u8>, mtime: SystemTime },
+  Directory { mtime: SystemTime },
+}
+
+impl Entry {
+  fn metadata(&self) -> FileMetadata {
+    match self {
+      Entry::File { content, mtime } => FileMetadata {
+        is_file: true,
+        is_directory: false,
+        size: content.len() as u64,
+        mtime_ms: FileMetadata::mtime_ms(*mtime),
+      },
+      Entry::Directory { mtime } => FileMetadata {
+        is_file: false,
+        is_directory: true,
+        size: 0,
+        mtime_ms: FileMetadata::mtime_ms(*mtime),
+      },
+    }
+  }
+}
+
+/// A file system that keeps everything in memory.
+///
+/// Root directories always exist. Paths are compared after normalizing `.` and `..` components,
+/// no symlinks are supported.
+#[derive(Debug, Default)]
+pub struct MemoryFileSystem {
+  entries: Mutex<BTreeMap<PathBuf, Entry>>,
+}
+
+pub(crate) fn normalize(path: &Path) -> PathBuf {
+  let mut normalized = PathBuf::new();
+  for component in path.components() {
+    match component {
+      Component::CurDir => {}
+      Component::ParentDir => {
+        normalized.pop();
+      }
+      component => normalized.push(component),
+    }
+  }
+  normalized
+}
+
+fn is_root(path: &Path) -> bool {
+  path.parent().is_none() || path.as_os_str().is_empty()
+}
+
+fn error(kind: ErrorKind, message: &str, path: &Path) -> Error {
+  Error::Io(io::Error::new(
+    kind,
+    format!("{message}: {}", path.display()),
+  ))
+}
+
+impl MemoryFileSystem {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  fn is_directory(entries: &BTreeMap<PathBuf, Entry>, path: &Path) -> bool {
+    is_root(path) || matches!(entries.get(path), Some(Entry::Directory { .. }))
+  }
+
+  fn create_dir_impl(&self, dir: &Path) -> Result<()> {
+    let dir = normalize(dir);
+    let mut entries = self.entries.lock().expect("should lock entries");
+    if is_root(&dir) || entries.contains_key(&dir) {
+      return Err(error(ErrorKind::AlreadyExists, "File exists", &dir));
+    }
+    if !dir
+      .parent()
+      .is_some_and(|parent| Self::is_directory(&entries, parent))
+    {
+      return Err(error(ErrorKind::NotFound, "No such directory", &dir));
+    }
+    entries.insert(
+      dir,
+      Entry::Directory {
+        mtime: SystemTime::now(),
+      },
+    );
+    Ok(())
+  }
+
+  fn create_dir_all_impl(&self, dir: &Path) -> Result<()> {
+    let dir = normalize(dir);
+    let mut entries = self.entries.lock().expect("should lock entries");
+    for ancestor in dir.ancestors().filter(|ancestor| !is_root(ancestor)) {
+      match entries.get(ancestor) {
+        Some(Entry::Directory { .. }) => {}
+        Some(Entry::File { .. }) => {
+          return Err(error(ErrorKind::AlreadyExists, "Not a directory", ancestor))
+        }
+        None => {
+          entries.insert(
+            ancestor.to_path_buf(),
+            Entry::Directory {
+              mtime: SystemTime::now(),
+            },
+          );
+        }
+      }
+    }
+    Ok(())
+  }
+
+  fn write_impl(&self, file: &Path, data: &[u8]) -> Result<()> {
+    let file = normalize(file);
+    let mut entries = self.entries.lock().expect("should lock entries");
+    if !file
+      .parent()
+      .is_some_and(|parent| Self::is_directory(&entries, parent))
+    {
+      return Err(error(ErrorKind::NotFound, "No such directory", &file));
+    }
+    if Self::is_directory(&entries, &file) {
+      return Err(error(ErrorKind::Other, "Is a directory", &file));
+    }
+    entries.insert(
+      file,
+      Entry::File {
+        content: data.to_vec(),
+        mtime: SystemTime::now(),
+      },
+    );
+    Ok(())
+  }
+
+  fn read_impl(&self, file: &Path) -> Result<Vec<u8>> {
+    let file = normalize(file);
+    let entries = self.entries.lock().expect("should lock entries");
+    match entries.get(&file) {
+      Some(Entry::File { content, .. }) => Ok(content.clone()),
+      Some(Entry::Directory { .. }) => Err(error(ErrorKind::Other, "Is a directory", &file)),
+      None => Err(error(ErrorKind::NotFound, "No such file", &file)),
+    }
+  }
+
+  fn read_dir_impl(&self, dir: &Path) -> Result<Vec<String>> {
+    let dir = normalize(dir);
+    let entries = self.entries.lock().expect("should lock entries");
+    if !Self::is_directory(&entries, &dir) {
+      return Err(error(ErrorKind::NotFound, "No such directory", &dir));
+    }
+    Ok(
+      entries
+        .keys()
+        .filter(|path| path.parent() == Some(dir.as_path()))
+        .filter_map(|path| path.file_name())
+        .map(|name| name.to_string_lossy().to_string())
+        .collect(),
+    )
+  }
+
+  fn metadata_impl(&self, path: &Path) -> Result<FileMetadata> {
+    let path = normalize(path);
+    let entries = self.entries.lock().expect("should lock entries");
+    match entries.get(&path) {
+      Some(entry) => Ok(entry.metadata()),
+      None if is_root(&path) => Ok(FileMetadata {
+        is_file: false,
+        is_directory: true,
+        size: 0,
+        mtime_ms: 0,
+      }),
+      None => Err(error(
+        ErrorKind::NotFound,
+        "No such file or directory",
+        &path,
+      )),
+    }
+  }
+}
+
+impl WritableFileSystem for MemoryFileSystem {
+  fn create_dir(&self, dir: &Path) -> Result<()> {
+    self.create_dir_impl(dir)
+  }
+
+  fn create_dir_all(&self, dir: &Path) -> Result<()> {
+    self.create_dir_all_impl(dir)
+  }
+
+  fn write(&self, file: &Path, data: &[u8]) -> Result<()> {
+    self.write_impl(file, data)
+  }
+}
+
+impl ReadableFileSystem for MemoryFileSystem {
+  fn read(&self, file: &Path) -> Result<Vec<u8>> {
+    self.read_impl(file)
+  }
+
+  fn read_dir(&self, dir: &Path) -> Result<Vec<String>> {
+    self.read_dir_impl(dir)
+  }
+
+  fn metadata(&self, path: &Path) -> Result<FileMetadata> {
+    self.metadata_impl(path)
+  }
+}
+
+cfg_async! {
+  use futures::future::BoxFuture;
+
+  use crate::{AsyncReadableFileSystem, AsyncWritableFileSystem};
+
+  impl MemoryFileSystem {
+    fn remove_file_impl(&self, file: &Path) -> Result<()> {
+      let file = normalize(file);
+      let mut entries = self.entries.lock().expect("should lock entries");
+      match entries.get(&file) {
+        Some(Entry::File { .. }) => {
+          entries.remove(&file);
+          Ok(())
+        }
+        Some(Entry::Directory { .. }) => Err(error(ErrorKind::Other, "Is a directory", &file)),
+        None => Err(error(ErrorKind::NotFound, "No such file", &file)),
+      }
+    }
+
+    fn remove_dir_all_impl(&self, dir: &Path) -> Result<()> {
+      let dir = normalize(dir);
+      let mut entries = self.entries.lock().expect("should lock entries");
+      if !is_root(&dir) && !matches!(entries.get(&dir), Some(Entry::Directory { .. })) {
+        return Err(error(ErrorKind::NotFound, "No such directory", &dir));
+      }
+      entries.retain(|path, _| !path.starts_with(&dir));
+      Ok(())
+    }
+  }
+
+  impl AsyncWritableFileSystem for MemoryFileSystem {
+    fn create_dir(&self, dir: &Path) -> BoxFuture<'_, Result<()>> {
+      let res = self.create_dir_impl(dir);
+      Box::pin(async move { res })
+    }
+
+    fn create_dir_all(&self, dir: &Path) -> BoxFuture<'_, Result<()>> {
+      let res = self.create_dir_all_impl(dir);
+      Box::pin(async move { res })
+    }
+
+    fn write(&self, file: &Path, data: &[u8]) -> BoxFuture<'_, Result<()>> {
+      let res = self.write_impl(file, data);
+      Box::pin(async move { res })
+    }
+
+    fn remove_file(&self, file: &Path) -> BoxFuture<'_, Result<()>> {
+      let res = self.remove_file_impl(file);
+      Box::pin(async move { res })
+    }
+
+    fn remove_dir_all(&self, dir: &Path) -> BoxFuture<'_, Result<()>> {
+      let res = self.remove_dir_all_impl(dir);
+      Box::pin(async move { res })
+    }
+  }
+
+  impl AsyncReadableFileSystem for MemoryFileSystem {
+    fn read(&self, file: &Path) -> BoxFuture<'_, Result<Vec<u8>>> {
+      let res = self.read_impl(file);
+      Box::pin(async move { res })
+    }
+
+    fn read_dir(&self, dir: &Path) -> BoxFuture<'_, Result<Vec<String>>> {
+      let res = self.read_dir_impl(dir);
+      Box::pin(async move { res })
+    }
+
+    fn metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>> {
+      let res = self.metadata_impl(path);
+      Box::pin(async move { res })
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::path::Path;
+
+  use super::MemoryFileSystem;
+  use crate::sync::{ReadableFileSystem, WritableFileSystem};
+
+  #[test]
+  fn memory_file_system_read_write() {
+    let fs = MemoryFileSystem::new();
+    let dist = Path::new("/project/dist");
+    assert!(fs.write(&dist.join("main.js"), b"1").is_err());
+
+    fs.create_dir_all(dist).unwrap();
+    fs.write(&dist.join("main.js"), b"console.log(1)").unwrap();
+    fs.write(&dist.join("./assets/../main.css"), b"a{}")
+      .unwrap();
+    assert_eq!(fs.read(&dist.join("main.js")).unwrap(), b"console.log(1)");
+    assert_eq!(fs.read_dir(dist).unwrap(), vec!["main.css", "main.js"]);
+    assert_eq!(fs.metadata(&dist.join("main.css")).unwrap().size, 3);
+    assert!(fs.metadata(dist).unwrap().is_directory);
+
+    assert!(fs.read_dir(&dist.join("main.js")).is_err());
+  }
+}
@@ -0,0 +1,42 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Metadata information about a file or a directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileMetadata {
+  pub is_file: bool,
+  pub is_directory: bool,
+  /// Size of the file in bytes, `0` for directories.
+  pub size: u64,
+  /// Last modification time in milliseconds since the unix epoch.
+  pub mtime_ms: u64,
+}
+
+impl FileMetadata {
+  /// The metadata of a file with `content` whose modification time is unknown.
+  pub(crate) fn from_content(content: &[u8]) -> Self {
+    Self {
+      is_file: true,
+      is_directory: false,
+      size: content.len() as u64,
+      mtime_ms: 0,
+    }
+  }
+
+  pub(crate) fn mtime_ms(time: SystemTime) -> u64 {
+    time
+      .duration_since(UNIX_EPOCH)
+      .map(|duration| duration.as_millis() as u64)
+      .unwrap_or_default()
+  }
+}
+
+impl From<std::fs::Metadata> for FileMetadata {
+  fn from(metadata: std::fs::Metadata) -> Self {
+    Self {
+      is_file: metadata.is_file(),
+      is_directory: metadata.is_dir(),
+      size: metadata.len(),
+      mtime_ms: metadata.modified().map(Self::mtime_ms).unwrap_or_default(),
+    }
+  }
+}
@@ -3,7 +3,7 @@ use std::{fs, path::Path};
 use super::{
   cfg_async,
   sync::{ReadableFileSystem, WritableFileSystem},
-  Error, Result,
+  Error, FileMetadata, Result,
 };
 
 pub struct NativeFileSystem;
@@ -26,9 +26,27 @@ impl ReadableFileSystem for NativeFileSystem {
   fn read(&self, file: &Path) -> Result<Vec<u8>> {
     fs::read(file).map_err(Error::from)
   }
+
+  fn read_dir(&self, dir: &Path) -> Result<Vec<String>> {
+    fs::read_dir(dir)
+      .and_then(|entries| {
+        entries
+          .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().to_string()))
+          .collect()
+      })
+      .map_err(Error::from)
+  }
+
+  fn metadata(&self, path: &Path) -> Result<FileMetadata> {
+    fs::metadata(path)
+      .map(FileMetadata::from)
+      .map_err(Error::from)
+  }
 }
 
 cfg_async! {
+  use std::path::PathBuf;
+
   use futures::future::BoxFuture;
 
   use crate::{AsyncReadableFileSystem, AsyncWritableFileSystem};
@@ -77,5 +95,35 @@ cfg_async! {
       let fut = async move { tokio::fs::read(file).await.map_err(Error::from) };
       Box::pin(fut)
     }
+
+    fn read_dir(&self, dir: &Path) -> BoxFuture<'_, Result<Vec<String>>> {
+      let dir = dir.to_string_lossy().to_string();
+      let fut = async move {
+        let mut entries = tokio::fs::read_dir(dir).await.map_err(Error::from)?;
+        let mut names = vec![];
+        while let Some(entry) = entries.next_entry().await.map_err(Error::from)? {
+          names.push(entry.file_name().to_string_lossy().to_string());
+        }
+        Ok(names)
+      };
+      Box::pin(fut)
+    }
+
+    fn metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>> {
+      let path = path.to_string_lossy().to_string();
+      let fut = async move {
+        tokio::fs::metadata(path)
+          .await
+          .map(FileMetadata::from)
+          .map_err(Error::from)
+      };
+      Box::pin(fut)
+    }
+
+    fn canonicalize(&self, path: &Path) -> BoxFuture<'_, Result<PathBuf>> {
+      let path = path.to_path_buf();
+      let fut = async move { dunce::canonicalize(path).map_err(Error::from) };
+      Box::pin(fut)
+    }
   }
 }
@@ -1,6 +1,6 @@
 use std::path::Path;
 
-use super::Result;
+use super::{Error, FileMetadata, Result};
 
 pub trait WritableFileSystem {
   /// Creates a new, empty directory at the provided path.
@@ -28,6 +28,34 @@ pub trait ReadableFileSystem {
   ///
   /// Error: This function will return an error if path does not already exist.
   fn read(&self, file: &Path) -> Result<Vec<u8>>;
+
+  /// Returns the names of the entries within a directory.
+  ///
+  /// Error: This function will return an error if path does not exist or is not a directory.
+  /// The default implementation returns an [`std::io::ErrorKind::Unsupported`] error.
+  fn read_dir(&self, dir: &Path) -> Result<Vec<String>> {
+    Err(unsupported("read_dir", dir))
+  }
+
+  /// Queries the metadata about a file or a directory.
+  ///
+  /// Error: This function will return an error if path does not exist.
+  /// The default implementation reads the file, so it fails for directories.
+  fn metadata(&self, path: &Path) -> Result<FileMetadata> {
+    self
+      .read(path)
+      .map(|content| FileMetadata::from_content(&content))
+  }
+}
+
+pub(crate) fn unsupported(method: &str, path: &Path) -> Error {
+  Error::Io(std::io::Error::new(
+    std::io::ErrorKind::Unsupported,
+    format!(
+      "`{method}` is not supported by this file system: {}",
+      path.display()
+    ),
+  ))
 }
 
 /// Readable and writable file system representation.
@@ -35,3 +63,28 @@ pub trait FileSystem: ReadableFileSystem + WritableFileSystem {}
 
 // Blanket implementation for all types that implement both [`ReadableFileSystem`] and [`WritableFileSystem`].
 impl<T: ReadableFileSystem + WritableFileSystem> FileSystem for T {}
+
+#[cfg(test)]
+mod test {
+  use std::path::Path;
+
+  use super::ReadableFileSystem;
+  use crate::Result;
+
+  struct ReadOnlyFileSystem;
+
+  impl ReadableFileSystem for ReadOnlyFileSystem {
+    fn read(&self, _file: &Path) -> Result<Vec<u8>> {
+      Ok(b"content".to_vec())
+    }
+  }
+
+  #[test]
+  fn default_implementations() {
+    let fs = ReadOnlyFileSystem;
+    let metadata = fs.metadata(Path::new("/a.js")).unwrap();
+    assert!(metadata.is_file);
+    assert_eq!(metadata.size, 7);
+    assert!(fs.read_dir(Path::new("/")).is_err());
+  }
+}
@@ -0,0 +1,53 @@
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE36_ALPHABET: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+pub fn base58(bytes: &[u8]) -> String {
+  encode_with_alphabet(bytes, BASE58_ALPHABET)
+}
+
+pub fn base36(bytes: &[u8]) -> String {
+  encode_with_alphabet(bytes, BASE36_ALPHABET)
+}
+
+/// Encode `bytes` as a big-endian number in the base of the alphabet's length.
+/// Each leading zero byte is encoded as the first character of the alphabet, as in base58check.
+fn encode_with_alphabet(bytes: &[u8], alphabet: &[u8]) -> String {
+  let base = alphabet.len() as u32;
+  let leading_zeros = bytes.iter().take_while(|byte| **byte == 0).count();
+  // digits in the target base, least significant first
+  let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 2);
+  for byte in &bytes[leading_zeros..] {
+    let mut carry = *byte as u32;
+    for digit in digits.iter_mut() {
+      carry += (*digit as u32) << 8;
+      *digit = (carry % base) as u8;
+      carry /= base;
+    }
+    while carry > 0 {
+      digits.push((carry % base) as u8);
+      carry /= base;
+    }
+  }
+  std::iter::repeat(alphabet[0] as char)
+    .take(leading_zeros)
+    .chain(
+      digits
+        .iter()
+        .rev()
+        .map(|digit| alphabet[*digit as usize] as char),
+    )
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn encode_base58_and_base36() {
+    assert_eq!(base58(b"hello world"), "StV1DL6CwTryKyV");
+    assert_eq!(base58(&[0, 0, 1]), "112");
+    assert_eq!(base36(&[0xff, 0xff]), "1ekf");
+    assert_eq!(base36(&[]), "");
+  }
+}
@@ -1,25 +1,47 @@
+mod encode;
+
 use std::{
   fmt,
   hash::{Hash, Hasher},
+  str::FromStr,
 };
 
-use data_encoding::HEXLOWER_PERMISSIVE;
+use data_encoding::{BASE64, BASE64URL_NOPAD, HEXLOWER_PERMISSIVE};
 use md4::Digest;
+use sha2::{Sha256, Sha512};
 use smol_str::SmolStr;
-use xxhash_rust::xxh64::Xxh64;
+use xxhash_rust::{xxh3::Xxh3, xxh64::Xxh64};
 
 #[derive(Debug, Clone, Copy)]
 pub enum HashFunction {
   Xxhash64,
   MD4,
+  Sha256,
+  Sha512,
+  /// 64-bit XXH3
+  Xxhash3,
+  /// 128-bit XXH3
+  Xxhash128,
+  Blake3,
 }
 
-impl From<&str> for HashFunction {
-  fn from(value: &str) -> Self {
+impl FromStr for HashFunction {
+  type Err = InvalidHashOption;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
     match value {
-      "xxhash64" => HashFunction::Xxhash64,
-      "md4" => HashFunction::MD4,
-      _ => unimplemented!("{}", value),
+      "xxhash64" => Ok(HashFunction::Xxhash64),
+      "md4" => Ok(HashFunction::MD4),
+      "sha256" => Ok(HashFunction::Sha256),
+      "sha512" => Ok(HashFunction::Sha512),
+      "xxhash3" => Ok(HashFunction::Xxhash3),
+      "xxhash128" => Ok(HashFunction::Xxhash128),
+      "blake3" => Ok(HashFunction::Blake3),
+      _ => Err(InvalidHashOption {
+        option: "hashFunction",
+        value: value.to_string(),
+        expected: "'xxhash64', 'md4', 'sha256', 'sha512', 'xxhash3', 'xxhash128' or 'blake3'",
+      }),
     }
   }
 }
@@ -27,17 +49,55 @@ impl From<&str> for HashFunction {
 #[derive(Debug, Clone, Copy)]
 pub enum HashDigest {
   Hex,
+  /// Standard base64 with padding
+  Base64,
+  /// Url and filename safe base64 without padding
+  Base64Url,
+  /// Base58 with the bitcoin alphabet
+  Base58,
+  /// Lowercase base36
+  Base36,
 }
 
-impl From<&str> for HashDigest {
-  fn from(value: &str) -> Self {
+impl FromStr for HashDigest {
+  type Err = InvalidHashOption;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
     match value {
-      "hex" => HashDigest::Hex,
-      _ => unimplemented!(),
+      "hex" => Ok(HashDigest::Hex),
+      "base64" => Ok(HashDigest::Base64),
+      "base64url" => Ok(HashDigest::Base64Url),
+      "base58" => Ok(HashDigest::Base58),
+      "base36" => Ok(HashDigest::Base36),
+      _ => Err(InvalidHashOption {
+        option: "hashDigest",
+        value: value.to_string(),
+        expected: "'hex', 'base64', 'base64url', 'base58' or 'base36'",
+      }),
     }
   }
 }
 
+/// Returned when parsing an unsupported `output.hashFunction` or `output.hashDigest`.
+#[derive(Debug, Clone)]
+pub struct InvalidHashOption {
+  option: &'static str,
+  value: String,
+  expected: &'static str,
+}
+
+impl fmt::Display for InvalidHashOption {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "Unsupported output.{} '{}', expected {}",
+      self.option, self.value, self.expected
+    )
+  }
+}
+
+impl std::error::Error for InvalidHashOption {}
+
 #[derive(Debug, Clone, Hash)]
 pub enum HashSalt {
   None,
@@ -57,6 +117,11 @@ impl From<Option<String>> for HashSalt {
 pub enum RspackHash {
   Xxhash64(Box<Xxh64>),
   MD4(md4::Md4),
+  Sha256(Sha256),
+  Sha512(Sha512),
+  Xxhash3(Box<Xxh3>),
+  Xxhash128(Box<Xxh3>),
+  Blake3(Box<blake3::Hasher>),
 }
 
 impl fmt::Debug for RspackHash {
@@ -64,6 +129,11 @@ impl fmt::Debug for RspackHash {
     match self {
       Self::Xxhash64(_) => write!(f, "RspackHash(Xxhash64)"),
       Self::MD4(_) => write!(f, "RspackHash(MD4)"),
+      Self::Sha256(_) => write!(f, "RspackHash(Sha256)"),
+      Self::Sha512(_) => write!(f, "RspackHash(Sha512)"),
+      Self::Xxhash3(_) => write!(f, "RspackHash(Xxhash3)"),
+      Self::Xxhash128(_) => write!(f, "RspackHash(Xxhash128)"),
+      Self::Blake3(_) => write!(f, "RspackHash(Blake3)"),
     }
   }
 }
@@ -73,6 +143,11 @@ impl RspackHash {
     match function {
       HashFunction::Xxhash64 => Self::Xxhash64(Box::new(Xxh64::new(0))),
       HashFunction::MD4 => Self::MD4(md4::Md4::new()),
+      HashFunction::Sha256 => Self::Sha256(Sha256::new()),
+      HashFunction::Sha512 => Self::Sha512(Sha512::new()),
+      HashFunction::Xxhash3 => Self::Xxhash3(Box::new(Xxh3::new())),
+      HashFunction::Xxhash128 => Self::Xxhash128(Box::new(Xxh3::new())),
+      HashFunction::Blake3 => Self::Blake3(Box::new(blake3::Hasher::new())),
     }
   }
 
@@ -88,6 +163,11 @@ impl RspackHash {
     let inner = match self {
       RspackHash::Xxhash64(hasher) => hasher.finish().to_be_bytes().to_vec(),
       RspackHash::MD4(hash) => hash.finalize().to_vec(),
+      RspackHash::Sha256(hash) => hash.finalize().to_vec(),
+      RspackHash::Sha512(hash) => hash.finalize().to_vec(),
+      RspackHash::Xxhash3(hasher) => hasher.digest().to_be_bytes().to_vec(),
+      RspackHash::Xxhash128(hasher) => hasher.digest128().to_be_bytes().to_vec(),
+      RspackHash::Blake3(hasher) => hasher.finalize().as_bytes().to_vec(),
     };
     RspackHashDigest::new(inner, digest)
   }
@@ -97,19 +177,13 @@ impl Hasher for RspackHash {
   fn finish(&self) -> u64 {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.finish(),
-      RspackHash::MD4(hasher) => {
-        // finalize take ownership, so we need to clone it
-        let hash = hasher.clone().finalize();
-        let msb_u64: u64 = ((hash[0] as u64) << 56)
-          | ((hash[1] as u64) << 48)
-          | ((hash[2] as u64) << 40)
-          | ((hash[3] as u64) << 32)
-          | ((hash[4] as u64) << 24)
-          | ((hash[5] as u64) << 16)
-          | ((hash[6] as u64) << 8)
-          | (hash[7] as u64);
-        msb_u64
-      }
+      // finalize take ownership, so we need to clone it
+      RspackHash::MD4(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::Sha256(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::Sha512(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::Xxhash3(hasher) => hasher.digest(),
+      RspackHash::Xxhash128(hasher) => hasher.digest(),
+      RspackHash::Blake3(hasher) => msb_u64(hasher.finalize().as_bytes()),
     }
   }
 
@@ -117,10 +191,23 @@ impl Hasher for RspackHash {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.write(bytes),
       RspackHash::MD4(hasher) => hasher.update(bytes),
+      RspackHash::Sha256(hasher) => hasher.update(bytes),
+      RspackHash::Sha512(hasher) => hasher.update(bytes),
+      RspackHash::Xxhash3(hasher) => hasher.update(bytes),
+      RspackHash::Xxhash128(hasher) => hasher.update(bytes),
+      RspackHash::Blake3(hasher) => {
+        hasher.update(bytes);
+      }
     }
   }
 }
 
+fn msb_u64(hash: &[u8]) -> u64 {
+  let mut bytes = [0u8; 8];
+  bytes.copy_from_slice(&hash[..8]);
+  u64::from_be_bytes(bytes)
+}
+
 #[derive(Debug, Clone, Eq)]
 pub struct RspackHashDigest {
   encoded: SmolStr,
@@ -130,6 +217,10 @@ impl RspackHashDigest {
   pub fn new(inner: Vec<u8>, digest: &HashDigest) -> Self {
     let encoded = match digest {
       HashDigest::Hex => HEXLOWER_PERMISSIVE.encode(&inner).into(),
+      HashDigest::Base64 => BASE64.encode(&inner).into(),
+      HashDigest::Base64Url => BASE64URL_NOPAD.encode(&inner).into(),
+      HashDigest::Base58 => encode::base58(&inner).into(),
+      HashDigest::Base36 => encode::base36(&inner).into(),
     };
     Self { encoded }
   }
@@ -144,6 +235,14 @@ impl RspackHashDigest {
   }
 }
 
+impl From<&str> for RspackHashDigest {
+  fn from(value: &str) -> Self {
+    Self {
+      encoded: value.into(),
+    }
+  }
+}
+
 impl Hash for RspackHashDigest {
   fn hash<H: Hasher>(&self, state: &mut H) {
     self.encoded.hash(state);
@@ -155,3 +254,52 @@ impl PartialEq for RspackHashDigest {
     self.encoded == other.encoded
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn hex_digest(function: &str, input: &[u8]) -> String {
+    let function = function.parse::<HashFunction>().expect("should parse");
+    let mut hasher = RspackHash::new(&function);
+    hasher.write(input);
+    hasher.digest(&HashDigest::Hex).encoded().to_string()
+  }
+
+  #[test]
+  fn parse_hash_functions() {
+    assert!(matches!("sha256".parse(), Ok(HashFunction::Sha256)));
+    assert!(matches!("sha512".parse(), Ok(HashFunction::Sha512)));
+    assert!(matches!("xxhash3".parse(), Ok(HashFunction::Xxhash3)));
+    assert!(matches!("xxhash128".parse(), Ok(HashFunction::Xxhash128)));
+    assert!(matches!("blake3".parse(), Ok(HashFunction::Blake3)));
+    let err = "sha1"
+      .parse::<HashFunction>()
+      .expect_err("should not parse");
+    assert!(err
+      .to_string()
+      .starts_with("Unsupported output.hashFunction 'sha1'"));
+  }
+
+  #[test]
+  fn known_digests() {
+    assert_eq!(
+      hex_digest("sha256", b"abc"),
+      "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert_eq!(
+      hex_digest("sha512", b"abc"),
+      "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+       2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+    );
+    assert_eq!(hex_digest("xxhash3", b""), "2d06800538d394c2");
+    assert_eq!(
+      hex_digest("xxhash128", b""),
+      "99aa06d3014798d86001c324468d497f"
+    );
+    assert_eq!(
+      hex_digest("blake3", b""),
+      "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+    );
+  }
+}
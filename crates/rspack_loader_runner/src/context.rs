@@ -5,6 +5,7 @@ use std::{
 
 use derivative::Derivative;
 use rspack_error::Diagnostic;
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_sources::SourceMap;
 use rustc_hash::FxHashSet as HashSet;
 
@@ -41,6 +42,10 @@ pub struct LoaderContext<Context: 'static> {
   pub resource_data: Arc<ResourceData>,
 
   pub content: Option<Content>,
+  /// The content of the resource as it was read from the file system, before any loader ran.
+  /// `None` if the content was provided by a plugin.
+  #[derivative(Debug = "ignore")]
+  pub resource_content: Option<Content>,
   #[derivative(Debug = "ignore")]
   pub context: Context,
   pub source_map: Option<SourceMap>,
@@ -54,6 +59,9 @@ pub struct LoaderContext<Context: 'static> {
 
   pub diagnostics: Vec<Diagnostic>,
 
+  /// The file system used to read the resource.
+  pub fs: Arc<dyn AsyncReadableFileSystem + Send + Sync>,
+
   /// Loader States
   pub(crate) state: State,
   pub loader_index: i32,
@@ -13,7 +13,7 @@ pub use context::{LoaderContext, State};
 pub use loader::{parse_resource, DisplayWithSuffix, Loader, LoaderItem, ResourceParsedData};
 pub use plugin::LoaderRunnerPlugin;
 pub use rspack_identifier::{Identifiable, Identifier};
-pub use runner::run_loaders;
+pub use runner::{run_loaders, LoaderResult};
 pub use scheme::{get_scheme, Scheme};
 
 pub const BUILTIN_LOADER_PREFIX: &str = "builtin:";
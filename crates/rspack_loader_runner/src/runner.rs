@@ -1,6 +1,8 @@
-use std::{fmt::Debug, path::PathBuf, sync::Arc};
+use std::{path::PathBuf, sync::Arc};
 
+use derivative::Derivative;
 use rspack_error::{error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_sources::SourceMap;
 use rustc_hash::FxHashSet as HashSet;
 
@@ -39,11 +41,13 @@ async fn process_resource<Context: Send>(
     if let Some(resource_path) = resource_data.resource_path.as_deref()
       && !resource_path.to_string_lossy().is_empty()
     {
-      let result = tokio::fs::read(resource_path).await.map_err(|e| {
+      let result = loader_context.fs.read(resource_path).await.map_err(|e| {
         let r = resource_path.to_string_lossy().to_string();
         error!("{e}, failed to read {r}")
       })?;
-      loader_context.content = Some(Content::from(result));
+      let content = Content::from(result);
+      loader_context.resource_content = Some(content.clone());
+      loader_context.content = Some(content);
     } else if !resource_data.get_scheme().is_none() {
       let resource = &resource_data.resource;
       let scheme = resource_data.get_scheme();
@@ -63,6 +67,7 @@ async fn create_loader_context<Context: 'static>(
   resource_data: Arc<ResourceData>,
   plugin: Option<Arc<dyn LoaderRunnerPlugin<Context = Context>>>,
   context: Context,
+  fs: Arc<dyn AsyncReadableFileSystem + Send + Sync>,
   additional_data: AdditionalData,
 ) -> Result<LoaderContext<Context>> {
   let mut file_dependencies: HashSet<PathBuf> = Default::default();
@@ -80,6 +85,7 @@ async fn create_loader_context<Context: 'static>(
     missing_dependencies: Default::default(),
     build_dependencies: Default::default(),
     content: None,
+    resource_content: None,
     context,
     source_map: None,
     additional_data,
@@ -89,6 +95,7 @@ async fn create_loader_context<Context: 'static>(
     plugin,
     resource_data,
     diagnostics: vec![],
+    fs,
   };
 
   if let Some(plugin) = loader_context.plugin.clone() {
@@ -103,15 +110,23 @@ pub async fn run_loaders<Context: 'static + Send>(
   resource_data: Arc<ResourceData>,
   plugins: Option<Arc<dyn LoaderRunnerPlugin<Context = Context>>>,
   context: Context,
+  fs: Arc<dyn AsyncReadableFileSystem + Send + Sync>,
   additional_data: AdditionalData,
-) -> Result<TWithDiagnosticArray<LoaderResult>> {
+) -> Result<TWithDiagnosticArray<LoaderResult<Context>>> {
   let loaders = loaders
     .iter()
     .map(|i| i.clone().into())
     .collect::<Vec<LoaderItem<Context>>>();
 
-  let mut cx =
-    create_loader_context(loaders, resource_data, plugins, context, additional_data).await?;
+  let mut cx = create_loader_context(
+    loaders,
+    resource_data,
+    plugins,
+    context,
+    fs,
+    additional_data,
+  )
+  .await?;
 
   loop {
     match cx.state {
@@ -182,19 +197,25 @@ pub async fn run_loaders<Context: 'static + Send>(
   cx.try_into()
 }
 
-#[derive(Debug)]
-pub struct LoaderResult {
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct LoaderResult<Context> {
   pub cacheable: bool,
   pub file_dependencies: HashSet<PathBuf>,
   pub context_dependencies: HashSet<PathBuf>,
   pub missing_dependencies: HashSet<PathBuf>,
   pub build_dependencies: HashSet<PathBuf>,
   pub content: Content,
+  /// See [LoaderContext::resource_content].
+  pub resource_content: Option<Content>,
   pub source_map: Option<SourceMap>,
   pub additional_data: AdditionalData,
+  /// The context after all loaders have run, e.g. with the files emitted by the loaders.
+  #[derivative(Debug = "ignore")]
+  pub context: Context,
 }
 
-impl<Context> TryFrom<LoaderContext<Context>> for TWithDiagnosticArray<LoaderResult> {
+impl<Context> TryFrom<LoaderContext<Context>> for TWithDiagnosticArray<LoaderResult<Context>> {
   type Error = rspack_error::Error;
   fn try_from(loader_context: LoaderContext<Context>) -> std::result::Result<Self, Self::Error> {
     let content = loader_context.content.ok_or_else(|| {
@@ -214,8 +235,10 @@ impl<Context> TryFrom<LoaderContext<Context>> for TWithDiagnosticArray<LoaderRes
         missing_dependencies: loader_context.missing_dependencies,
         build_dependencies: loader_context.build_dependencies,
         content,
+        resource_content: loader_context.resource_content,
         source_map: loader_context.source_map,
         additional_data: loader_context.additional_data,
+        context: loader_context.context,
       }
       .with_diagnostic(loader_context.diagnostics),
     )
@@ -228,6 +251,7 @@ mod test {
 
   use once_cell::sync::OnceCell;
   use rspack_error::Result;
+  use rspack_fs::AsyncNativeFileSystem;
   use rspack_identifier::{Identifiable, Identifier};
 
   use super::{run_loaders, Loader, LoaderContext, ResourceData};
@@ -410,6 +434,7 @@ mod test {
       rs.clone(),
       Some(Arc::new(TestContentPlugin)),
       (),
+      Arc::new(AsyncNativeFileSystem),
       Default::default(),
     )
     .await
@@ -426,6 +451,7 @@ mod test {
       rs.clone(),
       Some(Arc::new(TestContentPlugin)),
       (),
+      Arc::new(AsyncNativeFileSystem),
       Default::default(),
     )
     .await
@@ -497,6 +523,7 @@ mod test {
       rs,
       Some(Arc::new(TestContentPlugin)),
       (),
+      Arc::new(AsyncNativeFileSystem),
       Default::default(),
     )
     .await
@@ -0,0 +1,165 @@
+use rspack_core::{
+  AsContextDependency, AsDependency, AsDependencyTemplate, Compilation, Dependency,
+  DependencyCategory, DependencyId, DependencyTemplate, DependencyType, ErrorSpan, Module,
+  ModuleDependency, TemplateContext, TemplateReplaceSource,
+};
+
+use crate::parser_and_generator::CssParserAndGenerator;
+use crate::utils::export_locals_convention;
+
+/// The module of `:import("./tokens.css")` or `@value primary from "./tokens.css"`.
+#[derive(Debug, Clone)]
+pub struct CssIcssImportDependency {
+  id: DependencyId,
+  request: String,
+  span: Option<ErrorSpan>,
+}
+
+impl CssIcssImportDependency {
+  pub fn new(request: String, span: Option<ErrorSpan>) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      span,
+    }
+  }
+}
+
+impl Dependency for CssIcssImportDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CssImport
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::CssIcssImport
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    self.span
+  }
+}
+
+impl ModuleDependency for CssIcssImportDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+}
+
+impl AsDependencyTemplate for CssIcssImportDependency {}
+impl AsContextDependency for CssIcssImportDependency {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CssIcssValue {
+  /// `@value primary: #BF4040;`
+  Local(String),
+  /// `@value primary as p from "./tokens.css";` or an entry of `:import("./tokens.css") {}`
+  Imported { request: String, name: String },
+}
+
+/// An usage of a value which is replaced by the value, the imported ones are looked up in the
+/// exports of the imported module when generating.
+#[derive(Debug, Clone)]
+pub struct CssIcssSymbolDependency {
+  value: CssIcssValue,
+  start: u32,
+  end: u32,
+}
+
+impl CssIcssSymbolDependency {
+  pub fn new(value: CssIcssValue, start: u32, end: u32) -> Self {
+    Self { value, start, end }
+  }
+}
+
+impl DependencyTemplate for CssIcssSymbolDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      module,
+      ..
+    } = code_generatable_context;
+    let value = match &self.value {
+      CssIcssValue::Local(value) => Some(value.clone()),
+      CssIcssValue::Imported { request, name } => {
+        resolve_imported_value(compilation, *module, request, name, 0)
+      }
+    };
+    if let Some(value) = value {
+      source.replace(self.start, self.end, &value, None);
+    }
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    None
+  }
+}
+
+impl AsDependency for CssIcssSymbolDependency {}
+
+// values can be re-exported, stop following them in case of a cycle
+const MAX_IMPORT_DEPTH: usize = 32;
+
+/// Looks up `name` in the exports of the module imported by `request`, following the values
+/// which are imported again by that module.
+pub(crate) fn resolve_imported_value(
+  compilation: &Compilation,
+  module: &dyn Module,
+  request: &str,
+  name: &str,
+  depth: usize,
+) -> Option<String> {
+  if depth > MAX_IMPORT_DEPTH {
+    return None;
+  }
+  let module_graph = compilation.get_module_graph();
+  let imported = module.get_dependencies().iter().find_map(|id| {
+    let dependency = module_graph
+      .dependency_by_id(id)?
+      .downcast_ref::<CssIcssImportDependency>()?;
+    if dependency.request() != request {
+      return None;
+    }
+    let module_identifier = module_graph.module_identifier_by_dependency_id(id)?;
+    module_graph.module_by_identifier(module_identifier)
+  })?;
+  let parser_and_generator = imported
+    .as_normal_module()?
+    .parser_and_generator()
+    .as_any()
+    .downcast_ref::<CssParserAndGenerator>()?;
+  let exports = parser_and_generator.exports.as_ref()?;
+  let export = match &parser_and_generator.convention {
+    Some(convention) => export_locals_convention(name, convention)
+      .iter()
+      .find_map(|name| exports.get(name)),
+    None => None,
+  }
+  .or_else(|| exports.get(name))?
+  .first()?;
+  match &export.from {
+    Some(from) => resolve_imported_value(
+      compilation,
+      imported.as_ref(),
+      from,
+      &export.ident,
+      depth + 1,
+    ),
+    None => Some(export.ident.clone()),
+  }
+}
@@ -3,25 +3,109 @@ use rspack_core::{
   DependencyType, ErrorSpan, ModuleDependency, TemplateContext, TemplateReplaceSource,
 };
 
+/// The `layer(...)`, `supports(...)` and media query list of an `@import`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CssImportConditions {
+  /// `Some("")` is the anonymous layer of `@import url(x.css) layer`
+  pub layer: Option<String>,
+  pub supports: Option<String>,
+  pub media: Option<String>,
+}
+
+impl CssImportConditions {
+  pub fn new(layer: Option<&str>, supports: Option<&str>, media: Option<&str>) -> Self {
+    Self {
+      layer: layer.map(|layer| layer.trim().to_string()),
+      supports: non_empty(supports),
+      media: non_empty(media),
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.layer.is_none() && self.supports.is_none() && self.media.is_none()
+  }
+
+  /// The block rules wrapping the imported css, the layer is the innermost one and the media is
+  /// the outermost one.
+  pub fn wrap_block(&self) -> (String, String) {
+    let mut before = String::new();
+    let mut after = String::new();
+    if let Some(media) = &self.media {
+      before.push_str(&format!("@media {media} {{\n"));
+      after.push_str("}\n");
+    }
+    if let Some(supports) = &self.supports {
+      before.push_str(&format!("@supports ({supports}) {{\n"));
+      after.push_str("}\n");
+    }
+    if let Some(layer) = &self.layer {
+      if layer.is_empty() {
+        before.push_str("@layer {\n");
+      } else {
+        before.push_str(&format!("@layer {layer} {{\n"));
+      }
+      after.push_str("}\n");
+    }
+    (before, after)
+  }
+
+  /// The conditions in the `@import` syntax, for the external imports which can't be wrapped.
+  pub fn to_import_suffix(&self) -> String {
+    let mut suffix = String::new();
+    if let Some(layer) = &self.layer {
+      if layer.is_empty() {
+        suffix.push_str(" layer");
+      } else {
+        suffix.push_str(&format!(" layer({layer})"));
+      }
+    }
+    if let Some(supports) = &self.supports {
+      suffix.push_str(&format!(" supports({supports})"));
+    }
+    if let Some(media) = &self.media {
+      suffix.push_str(&format!(" {media}"));
+    }
+    suffix
+  }
+}
+
+fn non_empty(s: Option<&str>) -> Option<String> {
+  s.map(str::trim)
+    .filter(|s| !s.is_empty())
+    .map(ToString::to_string)
+}
+
 #[derive(Debug, Clone)]
 pub struct CssImportDependency {
   id: DependencyId,
   request: String,
+  conditions: CssImportConditions,
   span: Option<ErrorSpan>,
   start: u32,
   end: u32,
 }
 
 impl CssImportDependency {
-  pub fn new(request: String, span: Option<ErrorSpan>, start: u32, end: u32) -> Self {
+  pub fn new(
+    request: String,
+    conditions: CssImportConditions,
+    span: Option<ErrorSpan>,
+    start: u32,
+    end: u32,
+  ) -> Self {
     Self {
       id: DependencyId::new(),
       request,
+      conditions,
       span,
       start,
       end,
     }
   }
+
+  pub fn conditions(&self) -> &CssImportConditions {
+    &self.conditions
+  }
 }
 
 impl Dependency for CssImportDependency {
@@ -7,6 +7,8 @@ use rspack_core::{
 #[derive(Debug, Clone)]
 pub struct CssLocalIdentDependency {
   id: DependencyId,
+  // the name in the source, recorded in the `names` of the source map
+  name: String,
   local_ident: String,
   convention_names: Vec<String>,
   start: u32,
@@ -14,9 +16,16 @@ pub struct CssLocalIdentDependency {
 }
 
 impl CssLocalIdentDependency {
-  pub fn new(local_ident: String, convention_names: Vec<String>, start: u32, end: u32) -> Self {
+  pub fn new(
+    name: String,
+    local_ident: String,
+    convention_names: Vec<String>,
+    start: u32,
+    end: u32,
+  ) -> Self {
     Self {
       id: DependencyId::new(),
+      name,
       local_ident,
       convention_names,
       start,
@@ -64,7 +73,7 @@ impl DependencyTemplate for CssLocalIdentDependency {
     source: &mut TemplateReplaceSource,
     _code_generatable_context: &mut TemplateContext,
   ) {
-    source.replace(self.start, self.end, &self.local_ident, None);
+    source.replace(self.start, self.end, &self.local_ident, Some(&self.name));
   }
 
   fn dependency_id(&self) -> Option<DependencyId> {
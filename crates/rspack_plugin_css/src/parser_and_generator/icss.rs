@@ -0,0 +1,351 @@
+//! `@value` of CSS Modules and the usages of the values, the ICSS `:import` and `:export` blocks
+//! are handled by the lexer.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static VALUE_IMPORT_REGEX: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(r#"^(?s)(.+?|\(.+?\))\s+from\s+("[^"]*"|'[^']*'|[\w-]+)$"#).expect("Invalid regex")
+});
+static VALUE_IMPORT_NAME_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^([\w-]+)(?:\s+as\s+([\w-]+))?$").expect("Invalid regex"));
+static VALUE_DEFINITION_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^(?s)([\w-]+)\s*:?(.*)$").expect("Invalid regex"));
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum ValueDefinition<'s> {
+  /// `@value primary: #BF4040;`
+  Local { name: &'s str, value: &'s str },
+  /// `@value primary, secondary as s from "./tokens.css";`, `from` is unquoted and may be the
+  /// name of a value holding the request. `names` are pairs of the imported name and the alias.
+  Import {
+    names: Vec<(&'s str, &'s str)>,
+    from: &'s str,
+  },
+}
+
+#[derive(Debug)]
+pub(crate) struct AtValue<'s> {
+  pub definition: ValueDefinition<'s>,
+  pub start: u32,
+  pub end: u32,
+}
+
+/// Collects the top level `@value` rules, `end` includes the `;`.
+pub(crate) fn collect_at_values(source: &str) -> Vec<AtValue<'_>> {
+  let bytes = source.as_bytes();
+  let mut at_values = vec![];
+  let mut depth = 0usize;
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'/' if bytes.get(i + 1) == Some(&b'*') => {
+        i = skip_comment(bytes, i);
+        continue;
+      }
+      b'"' | b'\'' => {
+        i = skip_string(bytes, i);
+        continue;
+      }
+      b'{' => depth += 1,
+      b'}' => depth = depth.saturating_sub(1),
+      b'@'
+        if depth == 0
+          && source[i + 1..].starts_with("value")
+          && bytes.get(i + 6).is_some_and(u8::is_ascii_whitespace) =>
+      {
+        let params_start = i + 6;
+        let params_end = find_statement_end(bytes, params_start);
+        let end = (params_end + 1).min(bytes.len());
+        if let Some(definition) = parse_value_params(source[params_start..params_end].trim()) {
+          at_values.push(AtValue {
+            definition,
+            start: i as u32,
+            end: end as u32,
+          });
+        }
+        i = end;
+        continue;
+      }
+      _ => {}
+    }
+    i += 1;
+  }
+  at_values
+}
+
+fn parse_value_params(params: &str) -> Option<ValueDefinition<'_>> {
+  if let Some(captures) = VALUE_IMPORT_REGEX.captures(params) {
+    let names = captures.get(1)?.as_str();
+    let names = names
+      .strip_prefix('(')
+      .and_then(|names| names.strip_suffix(')'))
+      .unwrap_or(names);
+    let names = names
+      .split(',')
+      .filter_map(|name| {
+        let captures = VALUE_IMPORT_NAME_REGEX.captures(name.trim())?;
+        let imported = captures.get(1)?.as_str();
+        let alias = captures.get(2).map_or(imported, |alias| alias.as_str());
+        Some((imported, alias))
+      })
+      .collect::<Vec<_>>();
+    let from = captures
+      .get(2)?
+      .as_str()
+      .trim_matches(|c| c == '"' || c == '\'');
+    return Some(ValueDefinition::Import { names, from });
+  }
+  let captures = VALUE_DEFINITION_REGEX.captures(params)?;
+  Some(ValueDefinition::Local {
+    name: captures.get(1)?.as_str(),
+    value: captures.get(2)?.as_str().trim(),
+  })
+}
+
+/// Collects the identifiers in `names` used in declaration values and at-rule preludes, like
+/// `color: primary` and `@media small`, the ranges in `skipped` are ignored.
+pub(crate) fn collect_value_usages<'s>(
+  source: &'s str,
+  is_value: impl Fn(&str) -> bool,
+  skipped: &[(u32, u32)],
+) -> Vec<(&'s str, u32, u32)> {
+  let bytes = source.as_bytes();
+  let mut ranges = vec![];
+  let mut depth = 0usize;
+  let mut statement_start = 0;
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'/' if bytes.get(i + 1) == Some(&b'*') => {
+        i = skip_comment(bytes, i);
+        continue;
+      }
+      b'"' | b'\'' => {
+        i = skip_string(bytes, i);
+        continue;
+      }
+      b'{' => {
+        // the prelude of an at-rule, the selectors are kept as they are
+        let prelude = &source[statement_start..i];
+        let trimmed = prelude.trim_start();
+        if trimmed.starts_with('@') {
+          let at_rule_start = statement_start + (prelude.len() - trimmed.len());
+          let name_end = trimmed
+            .find(|c: char| c.is_ascii_whitespace() || c == '(')
+            .unwrap_or(trimmed.len());
+          ranges.push((at_rule_start + name_end, i));
+        }
+        depth += 1;
+        statement_start = i + 1;
+      }
+      b';' | b'}' => {
+        if depth > 0
+          && let Some(colon) = find_declaration_colon(bytes, statement_start, i)
+        {
+          ranges.push((colon + 1, i));
+        }
+        if bytes[i] == b'}' {
+          depth = depth.saturating_sub(1);
+        }
+        statement_start = i + 1;
+      }
+      _ => {}
+    }
+    i += 1;
+  }
+
+  let mut usages = vec![];
+  for (start, end) in ranges {
+    collect_identifiers(source, start, end, &mut |name, start, end| {
+      if is_value(name)
+        && !skipped
+          .iter()
+          .any(|(skip_start, skip_end)| start < *skip_end && end > *skip_start)
+      {
+        usages.push((name, start, end));
+      }
+    });
+  }
+  usages
+}
+
+/// Replaces the identifiers of `value` which `lookup` knows, used for values referring to the
+/// values defined before them.
+pub(crate) fn replace_identifiers<'s>(
+  value: &str,
+  lookup: impl Fn(&str) -> Option<&'s str>,
+) -> String {
+  let mut replaced = String::with_capacity(value.len());
+  let mut last = 0;
+  collect_identifiers(value, 0, value.len(), &mut |name, start, end| {
+    if let Some(resolved) = lookup(name) {
+      replaced.push_str(&value[last..start as usize]);
+      replaced.push_str(resolved);
+      last = end as usize;
+    }
+  });
+  replaced.push_str(&value[last..]);
+  replaced
+}
+
+fn collect_identifiers<'s>(
+  source: &'s str,
+  start: usize,
+  end: usize,
+  on_identifier: &mut impl FnMut(&'s str, u32, u32),
+) {
+  let bytes = source.as_bytes();
+  let mut i = start;
+  while i < end {
+    match bytes[i] {
+      b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_comment(bytes, i),
+      b'"' | b'\'' => i = skip_string(bytes, i),
+      c if is_identifier_byte(c) => {
+        let identifier_start = i;
+        while i < end && is_identifier_byte(bytes[i]) {
+          i += 1;
+        }
+        let identifier = &source[identifier_start..i];
+        if identifier.eq_ignore_ascii_case("url") && bytes.get(i) == Some(&b'(') {
+          i = find_closing_parenthesis(bytes, i, end);
+          continue;
+        }
+        let prev = identifier_start
+          .checked_sub(1)
+          .and_then(|prev| bytes.get(prev));
+        // hex colors, ids and classes
+        if !identifier.starts_with("--") && !matches!(prev, Some(b'#' | b'.')) {
+          on_identifier(identifier, identifier_start as u32, i as u32);
+        }
+      }
+      _ => i += 1,
+    }
+  }
+}
+
+fn is_identifier_byte(c: u8) -> bool {
+  c.is_ascii_alphanumeric() || c == b'-' || c == b'_' || c >= 0x80
+}
+
+fn find_declaration_colon(bytes: &[u8], start: usize, end: usize) -> Option<usize> {
+  let mut i = start;
+  while i < end {
+    match bytes[i] {
+      b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_comment(bytes, i),
+      b'"' | b'\'' => i = skip_string(bytes, i),
+      b':' => return Some(i),
+      _ => i += 1,
+    }
+  }
+  None
+}
+
+fn find_statement_end(bytes: &[u8], start: usize) -> usize {
+  let mut parentheses = 0usize;
+  let mut i = start;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'/' if bytes.get(i + 1) == Some(&b'*') => {
+        i = skip_comment(bytes, i);
+        continue;
+      }
+      b'"' | b'\'' => {
+        i = skip_string(bytes, i);
+        continue;
+      }
+      b'(' => parentheses += 1,
+      b')' => parentheses = parentheses.saturating_sub(1),
+      b';' if parentheses == 0 => return i,
+      _ => {}
+    }
+    i += 1;
+  }
+  bytes.len()
+}
+
+fn find_closing_parenthesis(bytes: &[u8], start: usize, end: usize) -> usize {
+  let mut i = start;
+  while i < end {
+    match bytes[i] {
+      b'"' | b'\'' => i = skip_string(bytes, i),
+      b')' => return i + 1,
+      _ => i += 1,
+    }
+  }
+  end
+}
+
+fn skip_comment(bytes: &[u8], start: usize) -> usize {
+  let mut i = start + 2;
+  while i + 1 < bytes.len() {
+    if bytes[i] == b'*' && bytes[i + 1] == b'/' {
+      return i + 2;
+    }
+    i += 1;
+  }
+  bytes.len()
+}
+
+fn skip_string(bytes: &[u8], start: usize) -> usize {
+  let quote = bytes[start];
+  let mut i = start + 1;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'\\' => i += 2,
+      c if c == quote => return i + 1,
+      b'\n' => return i,
+      _ => i += 1,
+    }
+  }
+  bytes.len()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn collect_values_and_usages() {
+    let source = r#"@value primary: #BF4040;
+@value secondary, small as s from "./tokens.css";
+@media s { .a { color: primary; border: 1px solid secondary; background: url(primary.png); } }
+.primary { color: #primary; }
+"#;
+    let at_values = collect_at_values(source);
+    assert_eq!(
+      at_values
+        .iter()
+        .map(|at_value| &at_value.definition)
+        .collect::<Vec<_>>(),
+      vec![
+        &ValueDefinition::Local {
+          name: "primary",
+          value: "#BF4040"
+        },
+        &ValueDefinition::Import {
+          names: vec![("secondary", "secondary"), ("small", "s")],
+          from: "./tokens.css"
+        }
+      ]
+    );
+    assert_eq!(
+      &source[at_values[0].start as usize..at_values[0].end as usize],
+      "@value primary: #BF4040;"
+    );
+
+    let skipped = at_values
+      .iter()
+      .map(|at_value| (at_value.start, at_value.end))
+      .collect::<Vec<_>>();
+    let usages = collect_value_usages(
+      source,
+      |name| matches!(name, "primary" | "secondary" | "s"),
+      &skipped,
+    );
+    assert_eq!(
+      usages.iter().map(|(name, _, _)| *name).collect::<Vec<_>>(),
+      vec!["s", "primary", "secondary"]
+    );
+  }
+}
@@ -1,3 +1,5 @@
+mod icss;
+
 use std::{borrow::Cow, sync::Arc};
 
 use indexmap::{IndexMap, IndexSet};
@@ -15,13 +17,14 @@ use rspack_core::{ModuleInitFragments, RuntimeGlobals};
 use rspack_error::{
   miette::Diagnostic, IntoTWithDiagnosticArray, Result, RspackSeverity, TWithDiagnosticArray,
 };
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::utils::{css_modules_exports_to_string, LocalIdentOptions};
 use crate::utils::{export_locals_convention, unescape};
 use crate::{
   dependency::{
-    CssComposeDependency, CssExportDependency, CssImportDependency, CssLocalIdentDependency,
+    CssComposeDependency, CssExportDependency, CssIcssImportDependency, CssIcssSymbolDependency,
+    CssIcssValue, CssImportConditions, CssImportDependency, CssLocalIdentDependency,
     CssUrlDependency,
   },
   utils::{
@@ -67,6 +70,38 @@ pub struct CssParserAndGenerator {
   pub exports: Option<CssExports>,
 }
 
+impl CssParserAndGenerator {
+  /// Exports a value of `@value` or `:export`, the imported ones are required from the module
+  /// they are imported from like `composes`.
+  fn add_icss_value_export(
+    &mut self,
+    name: &str,
+    value: &CssIcssValue,
+    dependencies: &mut Vec<Box<dyn Dependency>>,
+  ) {
+    let css_export = match value {
+      CssIcssValue::Local(value) => CssExport {
+        ident: value.clone(),
+        from: None,
+      },
+      CssIcssValue::Imported { request, name } => CssExport {
+        ident: name.clone(),
+        from: Some(request.clone()),
+      },
+    };
+    let exports = self.exports.get_or_insert_default();
+    let convention = self
+      .convention
+      .as_ref()
+      .expect("should have local_ident_name for module_type css/auto or css/module");
+    let convention_names = export_locals_convention(name, convention);
+    for name in convention_names.iter() {
+      update_css_exports(exports, name.to_owned(), css_export.clone());
+    }
+    dependencies.push(Box::new(CssExportDependency::new(convention_names)));
+  }
+}
+
 impl ParserAndGenerator for CssParserAndGenerator {
   fn source_types(&self) -> &[SourceType] {
     if self.exports_only {
@@ -132,6 +167,63 @@ impl ParserAndGenerator for CssParserAndGenerator {
     let mut presentational_dependencies: Vec<Box<dyn DependencyTemplate>> = vec![];
     let mut code_generation_dependencies: Vec<Box<dyn ModuleDependency>> = vec![];
 
+    // the values of `@value` and the aliases of `:import`, keyed by the name used in this module
+    let mut icss_values: FxHashMap<&str, CssIcssValue> = FxHashMap::default();
+    let mut icss_requests: FxHashSet<String> = FxHashSet::default();
+    // the ranges replaced by other dependencies, the values used there are not replaced again
+    let mut icss_skipped: Vec<(u32, u32)> = vec![];
+    if matches!(mode, css_module_lexer::Mode::Local) {
+      for at_value in icss::collect_at_values(&source_code) {
+        icss_skipped.push((at_value.start, at_value.end));
+        presentational_dependencies.push(Box::new(ConstDependency::new(
+          at_value.start,
+          at_value.end,
+          "".into(),
+          None,
+        )));
+        let span = Some(ErrorSpan::new(at_value.start, at_value.end));
+        match at_value.definition {
+          icss::ValueDefinition::Local { name, value } => {
+            let value = match icss_values.get(value) {
+              Some(imported @ CssIcssValue::Imported { .. }) => imported.clone(),
+              _ => CssIcssValue::Local(icss::replace_identifiers(value, |name| match icss_values
+                .get(name)
+              {
+                Some(CssIcssValue::Local(value)) => Some(value.as_str()),
+                _ => None,
+              })),
+            };
+            self.add_icss_value_export(name, &value, &mut dependencies);
+            icss_values.insert(name, value);
+          }
+          icss::ValueDefinition::Import { names, from } => {
+            // `@value primary from tokens;` with `@value tokens: "./tokens.css";`
+            let request = match icss_values.get(from) {
+              Some(CssIcssValue::Local(request)) => {
+                request.trim_matches(|c| c == '"' || c == '\'').to_string()
+              }
+              _ => from.to_string(),
+            };
+            if icss_requests.insert(request.clone()) {
+              dependencies.push(Box::new(CssIcssImportDependency::new(
+                request.clone(),
+                span,
+              )));
+            }
+            for (imported, alias) in names {
+              let value = CssIcssValue::Imported {
+                request: request.clone(),
+                name: imported.to_string(),
+              };
+              self.add_icss_value_export(alias, &value, &mut dependencies);
+              icss_values.insert(alias, value);
+            }
+          }
+        }
+      }
+    }
+    let mut icss_import_request: Option<String> = None;
+
     let (deps, warnings) = css_module_lexer::collect_dependencies(&source_code, mode);
     for dependency in deps {
       match dependency {
@@ -140,6 +232,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
           range,
           kind,
         } => {
+          icss_skipped.push((range.start, range.end));
           if request.is_empty() {
             continue;
           }
@@ -161,7 +254,13 @@ impl ParserAndGenerator for CssParserAndGenerator {
           dependencies.push(dep.clone());
           code_generation_dependencies.push(dep);
         }
-        css_module_lexer::Dependency::Import { request, range, .. } => {
+        css_module_lexer::Dependency::Import {
+          request,
+          range,
+          layer,
+          supports,
+          media,
+        } => {
           if request.is_empty() {
             presentational_dependencies.push(Box::new(ConstDependency::new(
               range.start,
@@ -180,18 +279,21 @@ impl ParserAndGenerator for CssParserAndGenerator {
           );
           dependencies.push(Box::new(CssImportDependency::new(
             request.to_string(),
+            CssImportConditions::new(layer, supports, media),
             Some(ErrorSpan::new(range.start, range.end)),
             range.start,
             range.end,
           )));
         }
-        css_module_lexer::Dependency::Replace { content, range } => presentational_dependencies
-          .push(Box::new(ConstDependency::new(
+        css_module_lexer::Dependency::Replace { content, range } => {
+          icss_skipped.push((range.start, range.end));
+          presentational_dependencies.push(Box::new(ConstDependency::new(
             range.start,
             range.end,
             content.into(),
             None,
-          ))),
+          )));
+        }
         css_module_lexer::Dependency::LocalClass { name, range, .. }
         | css_module_lexer::Dependency::LocalId { name, range, .. } => {
           let (prefix, name) = name.split_at(1); // split '#' or '.'
@@ -221,6 +323,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
             );
           }
           dependencies.push(Box::new(CssLocalIdentDependency::new(
+            name.to_string(),
             format!("{prefix}{local_ident}"),
             convention_names,
             range.start,
@@ -229,6 +332,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
         }
         css_module_lexer::Dependency::LocalKeyframes { name, range, .. }
         | css_module_lexer::Dependency::LocalKeyframesDecl { name, range, .. } => {
+          icss_skipped.push((range.start, range.end));
           let local_ident = LocalIdentOptions::new(
             resource_data,
             self
@@ -255,6 +359,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
             );
           }
           dependencies.push(Box::new(CssLocalIdentDependency::new(
+            name.to_string(),
             local_ident.clone(),
             convention_names,
             range.start,
@@ -301,28 +406,50 @@ impl ParserAndGenerator for CssParserAndGenerator {
             }
           }
         }
-        css_module_lexer::Dependency::ICSSExportValue { prop, value } => {
-          let exports = self.exports.get_or_insert_default();
-          let convention = self
-            .convention
-            .as_ref()
-            .expect("should have local_ident_name for module_type css/auto or css/module");
-          let convention_names = export_locals_convention(prop, convention);
-          for name in convention_names.iter() {
-            update_css_exports(
-              exports,
-              name.to_owned(),
-              CssExport {
-                ident: value.to_string(),
-                from: None,
+        css_module_lexer::Dependency::ICSSImportFrom { path } => {
+          let request = path.trim_matches(|c| c == '\'' || c == '"').to_string();
+          if icss_requests.insert(request.clone()) {
+            dependencies.push(Box::new(CssIcssImportDependency::new(
+              request.clone(),
+              None,
+            )));
+          }
+          icss_import_request = Some(request);
+        }
+        css_module_lexer::Dependency::ICSSImportValue { prop, value } => {
+          if let Some(request) = &icss_import_request {
+            icss_values.insert(
+              prop,
+              CssIcssValue::Imported {
+                request: request.clone(),
+                name: value.to_string(),
               },
             );
           }
-          dependencies.push(Box::new(CssExportDependency::new(convention_names)));
+        }
+        css_module_lexer::Dependency::ICSSExportValue { prop, value } => {
+          let value = icss_values
+            .get(value)
+            .cloned()
+            .unwrap_or_else(|| CssIcssValue::Local(value.to_string()));
+          self.add_icss_value_export(prop, &value, &mut dependencies);
         }
         _ => {}
       }
     }
+    if !icss_values.is_empty() {
+      for (name, start, end) in icss::collect_value_usages(
+        &source_code,
+        |name| icss_values.contains_key(name),
+        &icss_skipped,
+      ) {
+        presentational_dependencies.push(Box::new(CssIcssSymbolDependency::new(
+          icss_values[name].clone(),
+          start,
+          end,
+        )));
+      }
+    }
     for warning in warnings {
       let range = warning.range();
       let error = css_parsing_traceable_error(
@@ -8,7 +8,7 @@ use rayon::prelude::*;
 use rspack_core::rspack_sources::ReplaceSource;
 use rspack_core::{
   get_css_chunk_filename_template,
-  rspack_sources::{ConcatSource, RawSource, Source, SourceExt},
+  rspack_sources::{BoxSource, ConcatSource, RawSource, Source, SourceExt},
   Chunk, ChunkKind, Module, ModuleType, ParserAndGenerator, PathData, Plugin, RenderManifestEntry,
   SourceType,
 };
@@ -25,6 +25,7 @@ use rspack_plugin_runtime::is_enabled_for_chunk;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::parser_and_generator::{CodeGenerationDataUnusedLocalIdent, CssParserAndGenerator};
+use crate::plugin::CssImportConditionChain;
 use crate::runtime::CssLoadingRuntimeModule;
 use crate::utils::AUTO_PUBLIC_PATH_PLACEHOLDER_REGEX;
 use crate::{plugin::CssPluginInner, CssPlugin};
@@ -60,6 +61,13 @@ impl CssPlugin {
     chunk: &Chunk,
     ordered_css_modules: &[&dyn Module],
   ) -> rspack_error::Result<ConcatSource> {
+    let module_graph = compilation.get_module_graph();
+    let mut condition_chains = Self::get_chunk_import_condition_chains(
+      chunk,
+      &compilation.chunk_graph,
+      &module_graph,
+      ordered_css_modules,
+    );
     let module_sources = ordered_css_modules
       .iter()
       .map(|module| {
@@ -68,11 +76,15 @@ impl CssPlugin {
           .code_generation_results
           .get(module_id, Some(&chunk.runtime));
 
-        Ok(
-          code_gen_result
-            .get(&SourceType::Css)
-            .map(|source| (CssModuleDebugInfo { module: *module }, source)),
-        )
+        Ok(code_gen_result.get(&SourceType::Css).map(|source| {
+          (
+            CssModuleDebugInfo { module: *module },
+            source,
+            condition_chains
+              .remove(module_id)
+              .unwrap_or_else(|| vec![vec![]]),
+          )
+        }))
       })
       .collect::<Result<Vec<_>>>()?;
 
@@ -84,11 +96,16 @@ impl CssPlugin {
       .flatten()
       .fold(
         ConcatSource::default,
-        |mut acc, (debug_info, cur_source)| {
+        |mut acc, (debug_info, cur_source, chains)| {
           let (start, end) = Self::render_module_debug_info(compilation, &debug_info);
           acc.add(start);
-          acc.add(cur_source.clone());
-          acc.add(RawSource::from("\n"));
+          let is_external_import = debug_info
+            .module
+            .source_types()
+            .contains(&SourceType::CssImport);
+          for chain in &chains {
+            Self::render_module_with_conditions(&mut acc, cur_source, chain, is_external_import);
+          }
           acc.add(end);
           acc
         },
@@ -101,6 +118,48 @@ impl CssPlugin {
     Ok(source)
   }
 
+  fn render_module_with_conditions(
+    acc: &mut ConcatSource,
+    source: &BoxSource,
+    chain: &CssImportConditionChain,
+    is_external_import: bool,
+  ) {
+    if is_external_import {
+      // `@import` rules can't be nested in blocks, so the conditions of the direct import are
+      // appended to the rule instead
+      match chain.last() {
+        Some(conditions) => {
+          let content = source.source();
+          let rule = content.trim_end().trim_end_matches(';');
+          acc.add(RawSource::from(format!(
+            "{rule}{};\n",
+            conditions.to_import_suffix()
+          )));
+        }
+        None => {
+          acc.add(source.clone());
+          acc.add(RawSource::from("\n"));
+        }
+      }
+      return;
+    }
+    let mut before = String::new();
+    let mut after = String::new();
+    for conditions in chain {
+      let (block_start, block_end) = conditions.wrap_block();
+      before.push_str(&block_start);
+      after.insert_str(0, &block_end);
+    }
+    if !before.is_empty() {
+      acc.add(RawSource::from(before));
+    }
+    acc.add(source.clone());
+    acc.add(RawSource::from("\n"));
+    if !after.is_empty() {
+      acc.add(RawSource::from(after));
+    }
+  }
+
   fn render_module_debug_info(
     compilation: &Compilation,
     debug_info: &CssModuleDebugInfo,
@@ -152,6 +211,10 @@ async fn compilation(
     DependencyType::CssCompose,
     params.normal_module_factory.clone(),
   );
+  compilation.set_dependency_factory(
+    DependencyType::CssIcssImport,
+    params.normal_module_factory.clone(),
+  );
   Ok(())
 }
 
@@ -196,6 +259,12 @@ async fn content_hash(
     &module_graph,
     compilation,
   );
+  let condition_chains = Self::get_chunk_import_condition_chains(
+    chunk,
+    &compilation.chunk_graph,
+    &module_graph,
+    &ordered_modules,
+  );
   let mut hasher = hashes
     .entry(SourceType::Css)
     .or_insert_with(|| RspackHash::from(&compilation.options.output));
@@ -208,12 +277,14 @@ async fn content_hash(
           .code_generation_results
           .get_hash(&m.identifier(), Some(&chunk.runtime)),
         compilation.chunk_graph.get_module_id(m.identifier()),
+        condition_chains.get(&m.identifier()),
       )
     })
-    .for_each(|(current, id)| {
+    .for_each(|(current, id, chains)| {
       if let Some(current) = current {
         current.hash(&mut hasher);
         id.hash(&mut hasher);
+        chains.hash(&mut hasher);
       }
     });
 
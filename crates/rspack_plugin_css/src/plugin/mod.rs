@@ -2,10 +2,17 @@
 mod impl_plugin_for_css_plugin;
 use std::cmp::{self, Reverse};
 
+use indexmap::IndexSet;
 use rspack_core::{Chunk, ChunkGraph, Compilation, Module, ModuleGraph, SourceType};
 use rspack_core::{ChunkUkey, ModuleIdentifier};
 use rspack_hook::plugin;
-use rspack_identifier::IdentifierSet;
+use rspack_identifier::{IdentifierMap, IdentifierSet};
+
+use crate::dependency::{CssImportConditions, CssImportDependency};
+
+/// The conditions of the `@import`s from the outermost importer to the module itself, a module
+/// imported with different conditions is rendered once for each distinct chain.
+pub(crate) type CssImportConditionChain = Vec<CssImportConditions>;
 
 #[plugin]
 #[derive(Debug, Default)]
@@ -19,6 +26,100 @@ pub struct CssOrderConflicts {
 }
 
 impl CssPlugin {
+  /// The import condition chains of the css modules of a chunk, only the `@import`s of importers
+  /// in the same chunk are followed and cyclic `@import`s are skipped.
+  pub(crate) fn get_chunk_import_condition_chains(
+    chunk: &Chunk,
+    chunk_graph: &ChunkGraph,
+    module_graph: &ModuleGraph,
+    modules: &[&dyn Module],
+  ) -> IdentifierMap<Vec<CssImportConditionChain>> {
+    let mut cache = IdentifierMap::default();
+    let mut visiting = IdentifierSet::default();
+    modules
+      .iter()
+      .map(|module| {
+        let module = module.identifier();
+        let (mut chains, _) = Self::get_import_condition_chains(
+          chunk,
+          chunk_graph,
+          module_graph,
+          &module,
+          &mut cache,
+          &mut visiting,
+        );
+        if chains.is_empty() {
+          chains.insert(vec![]);
+        }
+        (module, chains.into_iter().collect())
+      })
+      .collect()
+  }
+
+  /// Returns the chains of the module and whether a cyclic `@import` was skipped, the chains of
+  /// the modules in a cycle depend on where the cycle is entered so they are not cached.
+  fn get_import_condition_chains(
+    chunk: &Chunk,
+    chunk_graph: &ChunkGraph,
+    module_graph: &ModuleGraph,
+    module: &ModuleIdentifier,
+    cache: &mut IdentifierMap<IndexSet<CssImportConditionChain>>,
+    visiting: &mut IdentifierSet,
+  ) -> (IndexSet<CssImportConditionChain>, bool) {
+    if let Some(chains) = cache.get(module) {
+      return (chains.clone(), false);
+    }
+    visiting.insert(*module);
+    let mut incoming = module_graph
+      .get_incoming_connections(module)
+      .into_iter()
+      .collect::<Vec<_>>();
+    incoming.sort_by_key(|connection| connection.dependency_id);
+    let mut chains = IndexSet::new();
+    let mut has_cycle = false;
+    for connection in incoming {
+      let css_import = module_graph
+        .dependency_by_id(&connection.dependency_id)
+        .and_then(|dependency| dependency.downcast_ref::<CssImportDependency>());
+      match (css_import, connection.original_module_identifier) {
+        (Some(_), Some(origin)) if visiting.contains(&origin) => {
+          has_cycle = true;
+        }
+        (Some(_), Some(origin)) if !chunk_graph.is_module_in_chunk(&origin, chunk.ukey) => {}
+        (Some(css_import), Some(origin)) => {
+          let (origin_chains, origin_has_cycle) = Self::get_import_condition_chains(
+            chunk,
+            chunk_graph,
+            module_graph,
+            &origin,
+            cache,
+            visiting,
+          );
+          has_cycle |= origin_has_cycle;
+          for mut chain in origin_chains {
+            if !css_import.conditions().is_empty() {
+              chain.push(css_import.conditions().clone());
+            }
+            chains.insert(chain);
+          }
+        }
+        _ => {
+          chains.insert(vec![]);
+        }
+      }
+    }
+    // a module which is only imported through a cycle gets its chains from where the cycle is
+    // entered, other modules without importers in the chunk are rendered unconditionally
+    if chains.is_empty() && !has_cycle {
+      chains.insert(vec![]);
+    }
+    visiting.remove(module);
+    if !has_cycle {
+      cache.insert(*module, chains.clone());
+    }
+    (chains, has_cycle)
+  }
+
   pub(crate) fn get_ordered_chunk_css_modules<'chunk_graph>(
     chunk: &Chunk,
     chunk_graph: &'chunk_graph ChunkGraph,
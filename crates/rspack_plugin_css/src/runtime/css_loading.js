@@ -40,6 +40,7 @@ var loadStylesheet = function (chunkId, url, done, hmr, fetchPriority) {
 		link.href = url;
 
 		__CROSS_ORIGIN_LOADING_PLACEHOLDER__
+		__INTEGRITY_PLACEHOLDER__
 	}
 	var onLinkComplete = function (prev, event) {
 		link.onerror = link.onload = null;
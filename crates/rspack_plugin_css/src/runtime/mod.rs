@@ -94,6 +94,20 @@ impl RuntimeModule for CssLoadingRuntimeModule {
         "".to_string()
       };
 
+      // the content of a hot updated stylesheet differs from the one it was built with
+      let integrity_content = if runtime_requirements.contains(RuntimeGlobals::SRI_HASHES) {
+        format!(
+          r#"
+          if (!hmr && {sri_hashes}.css[chunkId]) {{
+            link.integrity = {sri_hashes}.css[chunkId];
+          }}
+          "#,
+          sri_hashes = RuntimeGlobals::SRI_HASHES
+        )
+      } else {
+        "".to_string()
+      };
+
       let chunk_load_timeout = compilation.options.output.chunk_load_timeout.to_string();
 
       source.add(RawSource::from(
@@ -102,6 +116,7 @@ impl RuntimeModule for CssLoadingRuntimeModule {
             "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
             &cross_origin_content,
           )
+          .replace("__INTEGRITY_PLACEHOLDER__", &integrity_content)
           .replace("__CHUNK_LOAD_TIMEOUT_PLACEHOLDER__", &chunk_load_timeout)
           .replace("__UNIQUE_NAME__", unique_name),
       ));
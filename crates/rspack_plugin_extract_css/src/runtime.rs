@@ -103,6 +103,25 @@ impl RuntimeModule for CssLoadingRuntimeModule {
       runtime.replace("__CROSS_ORIGIN_LOADING__", "")
     };
 
+    // the content of a hot updated stylesheet differs from the one it was built with
+    let runtime = if compilation
+      .chunk_graph
+      .get_chunk_runtime_requirements(&self.chunk)
+      .contains(RuntimeGlobals::SRI_HASHES)
+    {
+      runtime.replace(
+        "__SET_INTEGRITY__",
+        &format!(
+          "if (!oldTag && {sri_hashes}.css[chunkId]) {{
+  linkTag.integrity = {sri_hashes}.css[chunkId];
+}}",
+          sri_hashes = RuntimeGlobals::SRI_HASHES
+        ),
+      )
+    } else {
+      runtime.replace("__SET_INTEGRITY__", "")
+    };
+
     let runtime = match &self.insert {
       InsertType::Fn(f) => runtime.replace("__INSERT__", &format!("({f})(linkTag);")),
       InsertType::Selector(sel) => runtime.replace(
@@ -29,6 +29,7 @@ var createStylesheet = function (
 	linkTag.onerror = linkTag.onload = onLinkComplete;
 	linkTag.href = fullhref;
 	__CROSS_ORIGIN_LOADING__
+	__SET_INTEGRITY__
 	__INSERT__
 	return linkTag;
 }
@@ -62,6 +62,19 @@ impl FromStr for HtmlScriptLoading {
   }
 }
 
+#[cfg_attr(feature = "testing", derive(JsonSchema))]
+#[derive(Deserialize, Debug, Clone, Copy, Default)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct HtmlResourceHints {
+  /// preload the initial js and css files of the included entries
+  #[serde(default)]
+  pub initial: bool,
+  /// preload or prefetch the async chunks with `webpackPreload` or `webpackPrefetch`
+  /// which are directly imported by the included entries
+  #[serde(default)]
+  pub async_chunks: bool,
+}
+
 #[cfg_attr(feature = "testing", derive(JsonSchema))]
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -72,7 +85,9 @@ pub struct HtmlRspackPluginOptions {
   /// template html file
   pub template: Option<String>,
   pub template_content: Option<String>,
-  pub template_parameters: Option<HashMap<String, String>>,
+  /// merged into the context of the template, see [crate::template::HtmlTemplateContext], the
+  /// template is only rendered when it's set and is emitted as it is otherwise
+  pub template_parameters: Option<HashMap<String, serde_json::Value>>,
   /// `head`, `body`, `false`
   #[serde(default = "default_inject")]
   pub inject: HtmlInject,
@@ -94,6 +109,9 @@ pub struct HtmlRspackPluginOptions {
   pub title: Option<String>,
   pub favicon: Option<String>,
   pub meta: Option<HashMap<String, HashMap<String, String>>>,
+  /// `<link rel="preload">`, `<link rel="modulepreload">` or `<link rel="prefetch">` for chunks
+  #[serde(default)]
+  pub resource_hints: HtmlResourceHints,
 }
 
 fn default_filename() -> String {
@@ -125,6 +143,7 @@ impl Default for HtmlRspackPluginOptions {
       title: None,
       favicon: None,
       meta: None,
+      resource_hints: Default::default(),
     }
   }
 }
@@ -0,0 +1,79 @@
+use rspack_core::Compilation;
+use rspack_hook::define_hook;
+
+use crate::visitors::asset::HTMLPluginTag;
+
+define_hook!(HtmlPluginBeforeAssetTagGeneration: AsyncSeries(compilation: &Compilation, data: &mut BeforeAssetTagGenerationData));
+define_hook!(HtmlPluginAlterAssetTags: AsyncSeries(compilation: &Compilation, data: &mut AlterAssetTagsData));
+define_hook!(HtmlPluginAlterAssetTagGroups: AsyncSeries(compilation: &Compilation, data: &mut AlterAssetTagGroupsData));
+define_hook!(HtmlPluginAfterTemplateExecution: AsyncSeries(compilation: &Compilation, data: &mut AfterTemplateExecutionData));
+define_hook!(HtmlPluginBeforeEmit: AsyncSeries(compilation: &Compilation, data: &mut BeforeEmitData));
+
+#[derive(Debug, Default)]
+pub struct HtmlRspackPluginHooks {
+  pub before_asset_tag_generation: HtmlPluginBeforeAssetTagGenerationHook,
+  pub alter_asset_tags: HtmlPluginAlterAssetTagsHook,
+  pub alter_asset_tag_groups: HtmlPluginAlterAssetTagGroupsHook,
+  pub after_template_execution: HtmlPluginAfterTemplateExecutionHook,
+  pub before_emit: HtmlPluginBeforeEmitHook,
+}
+
+/// Urls of the assets which will be injected, public path included.
+#[derive(Debug, Clone, Default)]
+pub struct HtmlPluginAssets {
+  pub public_path: String,
+  pub js: Vec<String>,
+  pub css: Vec<String>,
+  pub favicon: Option<String>,
+  /// Urls to be hinted with `<link rel="preload">` or `<link rel="modulepreload">`.
+  pub preload: Vec<String>,
+  /// Urls to be hinted with `<link rel="prefetch">`.
+  pub prefetch: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct HtmlPluginAssetTags {
+  pub scripts: Vec<HTMLPluginTag>,
+  pub styles: Vec<HTMLPluginTag>,
+  pub meta: Vec<HTMLPluginTag>,
+  pub resource_hints: Vec<HTMLPluginTag>,
+}
+
+// `output_name` is the configured filename of the html, which tells the html plugin instances
+// of the same compilation apart. Placeholders in it are replaced after `before_emit`.
+
+#[derive(Debug)]
+pub struct BeforeAssetTagGenerationData {
+  pub assets: HtmlPluginAssets,
+  pub output_name: String,
+}
+
+#[derive(Debug)]
+pub struct AlterAssetTagsData {
+  pub asset_tags: HtmlPluginAssetTags,
+  pub public_path: String,
+  pub output_name: String,
+}
+
+#[derive(Debug)]
+pub struct AlterAssetTagGroupsData {
+  pub head_tags: Vec<HTMLPluginTag>,
+  pub body_tags: Vec<HTMLPluginTag>,
+  pub public_path: String,
+  pub output_name: String,
+}
+
+/// `html` is the result of the template, the tags are injected into it after this hook.
+#[derive(Debug)]
+pub struct AfterTemplateExecutionData {
+  pub html: String,
+  pub head_tags: Vec<HTMLPluginTag>,
+  pub body_tags: Vec<HTMLPluginTag>,
+  pub output_name: String,
+}
+
+#[derive(Debug)]
+pub struct BeforeEmitData {
+  pub html: String,
+  pub output_name: String,
+}
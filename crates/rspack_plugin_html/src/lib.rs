@@ -1,9 +1,12 @@
 #![feature(box_patterns)]
 
 pub mod config;
+mod drive;
 pub mod parser;
 pub mod sri;
+pub mod template;
 pub mod visitors;
 
 mod plugin;
+pub use drive::*;
 pub use plugin::*;
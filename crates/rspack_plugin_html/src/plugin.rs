@@ -1,30 +1,46 @@
 use std::{
-  collections::hash_map::DefaultHasher,
-  fs,
+  collections::{hash_map::DefaultHasher, HashMap},
+  env,
   hash::{Hash, Hasher},
   path::{Path, PathBuf},
+  sync::Arc,
 };
 
-use anyhow::Context;
-use dojang::dojang::Dojang;
+use dashmap::mapref::one::MappedRefMut;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
 use rayon::prelude::*;
+use regex::Regex;
 use rspack_core::{
   parse_to_url,
   rspack_sources::{RawSource, SourceExt},
-  Compilation, CompilationAsset, CompilationProcessAssets, FilenameTemplate, PathData, Plugin,
+  ChunkGroup, ChunkGroupOrderKey, Compilation, CompilationAsset, CompilationId,
+  CompilationProcessAssets, CrossOriginLoading, FilenameTemplate, PathData, Plugin,
 };
-use rspack_error::{AnyhowError, Result};
+use rspack_error::{error, Result};
 use rspack_hook::{plugin, plugin_hook};
-use rspack_util::infallible::ResultInfallibleExt as _;
+use rspack_util::{
+  fx_hash::{BuildFxHasher, FxDashMap},
+  infallible::ResultInfallibleExt as _,
+};
 use swc_html::visit::VisitMutWith;
 
 use crate::{
-  config::{HtmlInject, HtmlRspackPluginOptions},
+  config::{HtmlInject, HtmlRspackPluginOptions, HtmlScriptLoading},
+  drive::{
+    AfterTemplateExecutionData, AlterAssetTagGroupsData, AlterAssetTagsData,
+    BeforeAssetTagGenerationData, BeforeEmitData, HtmlPluginAssetTags, HtmlPluginAssets,
+    HtmlRspackPluginHooks,
+  },
   parser::HtmlCompiler,
   sri::{add_sri, create_digest_from_asset},
+  template::{render_template, HtmlTemplateContext},
   visitors::asset::{AssetWriter, HTMLPluginTag},
 };
 
+static COMPILATION_HOOKS_MAP: Lazy<FxDashMap<CompilationId, Arc<HtmlRspackPluginHooks>>> =
+  Lazy::new(Default::default);
+
 #[plugin]
 #[derive(Debug)]
 pub struct HtmlRspackPlugin {
@@ -35,6 +51,32 @@ impl HtmlRspackPlugin {
   pub fn new(config: HtmlRspackPluginOptions) -> Self {
     Self::new_inner(config)
   }
+
+  /// The hooks are shared rather than borrowed from the map, the hooks are async and holding a
+  /// lock of the map while awaiting them blocks other compilations and the taps in them.
+  pub fn get_compilation_hooks(compilation: &Compilation) -> Arc<HtmlRspackPluginHooks> {
+    COMPILATION_HOOKS_MAP
+      .entry(compilation.id())
+      .or_default()
+      .clone()
+  }
+
+  pub fn get_compilation_hooks_mut(
+    compilation: &Compilation,
+  ) -> MappedRefMut<
+    '_,
+    CompilationId,
+    Arc<HtmlRspackPluginHooks>,
+    HtmlRspackPluginHooks,
+    BuildFxHasher,
+  > {
+    COMPILATION_HOOKS_MAP
+      .entry(compilation.id())
+      .or_default()
+      .map(|hooks| {
+        Arc::get_mut(hooks).expect("should not tap html plugin hooks while they are called")
+      })
+  }
 }
 
 #[plugin_hook(CompilationProcessAssets for HtmlRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_INLINE)]
@@ -54,13 +96,19 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
       AsRef::<Path>::as_ref(&compilation.options.context).join(template.as_str()),
     );
 
-    let content = fs::read_to_string(&resolved_template)
-      .context(format!(
-        "failed to read `{}` from `{}`",
-        resolved_template.display(),
-        &compilation.options.context
-      ))
-      .map_err(AnyhowError::from)?;
+    let content = compilation
+      .input_filesystem
+      .read(&resolved_template)
+      .await
+      .map_err(|e| e.to_string())
+      .and_then(|content| String::from_utf8(content).map_err(|e| e.to_string()))
+      .map_err(|e| {
+        error!(
+          "failed to read `{}` from `{}`: {e}",
+          resolved_template.display(),
+          &compilation.options.context
+        )
+      })?;
 
     let url = resolved_template.to_string_lossy().to_string();
     compilation.file_dependencies.insert(resolved_template);
@@ -74,25 +122,18 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
     )
   };
 
-  // process with template parameters
-  let template_result = if let Some(template_parameters) = &self.config.template_parameters {
-    let mut dj = Dojang::new();
-    dj.add(url.clone(), content)
-      .expect("failed to add template");
-    dj.render(&url, serde_json::json!(template_parameters))
-      .expect("failed to render template")
-  } else {
-    content
+  let public_path = config.get_public_path(compilation, &self.config.filename);
+  // url of the included assets to their asset names, used to compute the integrity
+  let mut asset_names = HashMap::new();
+  let mut assets = HtmlPluginAssets {
+    public_path: public_path.clone(),
+    favicon: config
+      .favicon
+      .as_ref()
+      .map(|favicon| favicon_url(config, compilation, favicon)),
+    ..Default::default()
   };
-
-  let ast_with_diagnostic = parser.parse_file(&url, template_result)?;
-
-  let (mut current_ast, diagnostic) = ast_with_diagnostic.split_into_parts();
-
-  if !diagnostic.is_empty() {
-    compilation.extend_diagnostics(diagnostic);
-  }
-  let included_assets = compilation
+  let entrypoints = compilation
     .entrypoints
     .keys()
     .filter(|&entry_name| {
@@ -106,61 +147,208 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
       included
     })
     .map(|entry_name| compilation.entrypoint_by_name(entry_name))
+    .collect::<Vec<_>>();
+  let included_assets = entrypoints
+    .iter()
     .flat_map(|entry| entry.get_files(&compilation.chunk_by_ukey))
-    .filter_map(|asset_name| {
-      let asset = compilation.assets().get(&asset_name).expect("TODO:");
-      if asset.info.hot_module_replacement || asset.info.development {
-        None
+    .filter(|asset_name| is_included_asset(compilation, asset_name))
+    .collect::<Vec<_>>();
+  for asset_name in included_assets {
+    if let Some(extension) = Path::new(&asset_name).extension() {
+      let asset_uri = format!("{public_path}{asset_name}");
+      if extension.eq_ignore_ascii_case("css") {
+        assets.css.push(asset_uri.clone());
+      } else if extension.eq_ignore_ascii_case("js") || extension.eq_ignore_ascii_case("mjs") {
+        assets.js.push(asset_uri.clone());
       } else {
-        Some((asset_name.clone(), asset))
+        continue;
       }
-    })
-    .collect::<Vec<_>>();
-
-  let mut tags = vec![];
-  // if inject is 'false', don't do anything
-  if !matches!(config.inject, HtmlInject::False) {
-    for (asset_name, asset) in included_assets {
-      if let Some(extension) = Path::new(&asset_name).extension() {
-        let asset_uri = format!(
-          "{}{asset_name}",
-          config.get_public_path(compilation, &self.config.filename),
-        );
-        let mut tag: Option<HTMLPluginTag> = None;
-        if extension.eq_ignore_ascii_case("css") {
-          tag = Some(HTMLPluginTag::create_style(&asset_uri, HtmlInject::Head));
-        } else if extension.eq_ignore_ascii_case("js") || extension.eq_ignore_ascii_case("mjs") {
-          tag = Some(HTMLPluginTag::create_script(
-            &asset_uri,
-            config.inject,
-            &config.script_loading,
-          ))
-        }
+      asset_names.insert(asset_uri, asset_name);
+    }
+  }
 
-        if let Some(tag) = tag {
-          tags.push((tag, asset));
+  if config.resource_hints.initial {
+    assets.preload = assets.css.iter().chain(assets.js.iter()).cloned().collect();
+  }
+  if config.resource_hints.async_chunks {
+    for (order_key, hints) in [
+      (ChunkGroupOrderKey::Preload, &mut assets.preload),
+      (ChunkGroupOrderKey::Prefetch, &mut assets.prefetch),
+    ] {
+      for asset_name in get_ordered_async_chunk_files(compilation, &entrypoints, &order_key) {
+        let asset_uri = format!("{public_path}{asset_name}");
+        if !hints.contains(&asset_uri) {
+          hints.push(asset_uri.clone());
         }
+        asset_names.insert(asset_uri, asset_name);
       }
     }
   }
 
+  let hooks = HtmlRspackPlugin::get_compilation_hooks(compilation);
+
+  let mut before_asset_tag_generation = BeforeAssetTagGenerationData {
+    assets,
+    output_name: config.filename.clone(),
+  };
+  hooks
+    .before_asset_tag_generation
+    .call(compilation, &mut before_asset_tag_generation)
+    .await?;
+  let BeforeAssetTagGenerationData {
+    assets,
+    output_name,
+  } = before_asset_tag_generation;
+
+  let mut asset_tags = HtmlPluginAssetTags {
+    scripts: assets
+      .js
+      .iter()
+      .map(|src| HTMLPluginTag::create_script(src, &config.script_loading))
+      .collect(),
+    styles: assets
+      .css
+      .iter()
+      .map(|href| HTMLPluginTag::create_style(href))
+      .collect(),
+    meta: assets
+      .favicon
+      .iter()
+      .map(|href| HTMLPluginTag::create_favicon(href))
+      .chain(config.meta.iter().flat_map(|meta| {
+        meta
+          .keys()
+          .sorted()
+          .map(move |key| HTMLPluginTag::create_meta(&meta[key]))
+      }))
+      .collect(),
+    // nothing is injected with `inject: false`, the hints are left to the template as well
+    resource_hints: if matches!(config.inject, HtmlInject::False) {
+      vec![]
+    } else {
+      create_resource_hints(compilation, config, &assets)
+    },
+  };
+
   // if some plugin changes assets in the same stage after this plugin
   // both the name and the integrity may be inaccurate
   if let Some(hash_func) = &config.sri {
-    tags
+    asset_tags
+      .scripts
       .par_iter_mut()
-      .filter_map(|(tag, asset)| asset.get_source().map(|s| (tag, s)))
-      .for_each(|(tag, asset)| {
-        let sri_value = create_digest_from_asset(hash_func, asset);
+      .chain(asset_tags.styles.par_iter_mut())
+      .chain(asset_tags.resource_hints.par_iter_mut())
+      .filter_map(|tag| {
+        let asset_name = asset_names.get(tag.asset_url()?)?;
+        let source = compilation.assets().get(asset_name)?.get_source()?;
+        Some((tag, source))
+      })
+      .for_each(|(tag, source)| {
+        let sri_value = create_digest_from_asset(hash_func, source);
         add_sri(tag, &sri_value);
       });
   }
 
-  let tags = tags.into_iter().map(|(tag, _)| tag).collect::<Vec<_>>();
-  let mut visitor = AssetWriter::new(config, &tags, compilation);
+  // the files of the template context, `public_path` is moved into the hooks
+  let template_assets = assets.clone();
+  let mut alter_asset_tags = AlterAssetTagsData {
+    asset_tags,
+    public_path: assets.public_path,
+    output_name,
+  };
+  hooks
+    .alter_asset_tags
+    .call(compilation, &mut alter_asset_tags)
+    .await?;
+  let AlterAssetTagsData {
+    asset_tags,
+    public_path,
+    output_name,
+  } = alter_asset_tags;
+
+  let mut head_tags = asset_tags.meta;
+  head_tags.extend(asset_tags.resource_hints);
+  let mut body_tags = vec![];
+  // if inject is 'false', only the meta tags are added
+  match config.inject {
+    HtmlInject::Head => {
+      head_tags.extend(asset_tags.styles);
+      head_tags.extend(asset_tags.scripts);
+    }
+    HtmlInject::Body => {
+      head_tags.extend(asset_tags.styles);
+      body_tags.extend(asset_tags.scripts);
+    }
+    HtmlInject::False => {}
+  }
+  let mut alter_asset_tag_groups = AlterAssetTagGroupsData {
+    head_tags,
+    body_tags,
+    public_path,
+    output_name,
+  };
+  hooks
+    .alter_asset_tag_groups
+    .call(compilation, &mut alter_asset_tag_groups)
+    .await?;
+  let AlterAssetTagGroupsData {
+    head_tags,
+    body_tags,
+    output_name,
+    ..
+  } = alter_asset_tag_groups;
+
+  let template_result = if config.template_parameters.is_some() {
+    let context = HtmlTemplateContext {
+      config,
+      assets: &template_assets,
+      entrypoints: &entrypoints,
+      head_tags: &head_tags,
+      body_tags: &body_tags,
+    };
+    render_template(&url, content, context.to_json(compilation))?
+  } else {
+    content
+  };
+
+  let mut after_template_execution = AfterTemplateExecutionData {
+    html: template_result,
+    head_tags,
+    body_tags,
+    output_name,
+  };
+  hooks
+    .after_template_execution
+    .call(compilation, &mut after_template_execution)
+    .await?;
+  let AfterTemplateExecutionData {
+    html,
+    head_tags,
+    body_tags,
+    output_name,
+  } = after_template_execution;
+
+  let ast_with_diagnostic = parser.parse_file(&url, html)?;
+
+  let (mut current_ast, diagnostic) = ast_with_diagnostic.split_into_parts();
+
+  let mut visitor = AssetWriter::new(config, &head_tags, &body_tags);
   current_ast.visit_mut_with(&mut visitor);
 
-  let source = parser.codegen(&mut current_ast)?;
+  let mut before_emit = BeforeEmitData {
+    html: parser.codegen(&mut current_ast)?,
+    output_name,
+  };
+  hooks
+    .before_emit
+    .call(compilation, &mut before_emit)
+    .await?;
+
+  if !diagnostic.is_empty() {
+    compilation.extend_diagnostics(diagnostic);
+  }
+
+  let source = before_emit.html;
   let hash = hash_for_source(&source);
   let html_file_name = FilenameTemplate::from(config.filename.clone());
   // Use the same filename as template
@@ -191,13 +379,17 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
       .to_string();
 
     let resolved_favicon = AsRef::<Path>::as_ref(&compilation.options.context).join(url.path());
-    let content = fs::read(resolved_favicon)
-      .context(format!(
-        "failed to read `{}` from `{}`",
-        url.path(),
-        &compilation.options.context
-      ))
-      .map_err(AnyhowError::from)?;
+    let content = compilation
+      .input_filesystem
+      .read(&resolved_favicon)
+      .await
+      .map_err(|e| {
+        error!(
+          "failed to read `{}` from `{}`: {e}",
+          url.path(),
+          &compilation.options.context
+        )
+      })?;
     compilation.emit_asset(
       favicon_file_path,
       CompilationAsset::from(RawSource::from(content).boxed()),
@@ -243,3 +435,109 @@ fn hash_for_source(source: &str) -> String {
   source.hash(&mut hasher);
   format!("{:016x}", hasher.finish())
 }
+
+fn favicon_url(
+  config: &HtmlRspackPluginOptions,
+  compilation: &Compilation,
+  favicon: &str,
+) -> String {
+  let favicon = PathBuf::from(favicon)
+    .file_name()
+    .expect("favicon should have file name")
+    .to_string_lossy()
+    .to_string();
+
+  let favicon_relative_path = PathBuf::from(config.get_relative_path(compilation, &favicon));
+
+  let mut favicon_path = PathBuf::from(config.get_public_path(
+    compilation,
+    favicon_relative_path.to_string_lossy().to_string().as_str(),
+  ));
+
+  favicon_path.push(favicon_relative_path);
+
+  let mut favicon_link_path = favicon_path.to_string_lossy().to_string();
+
+  if env::consts::OS == "windows" {
+    let reg = Regex::new(r"[/\\]").expect("Invalid RegExp");
+    favicon_link_path = reg.replace_all(favicon_link_path.as_str(), "/").to_string();
+  }
+  favicon_link_path
+}
+
+/// Files of chunks which are not emitted as assets, e.g. removed by other plugins, are skipped.
+fn is_included_asset(compilation: &Compilation, asset_name: &str) -> bool {
+  compilation
+    .assets()
+    .get(asset_name)
+    .is_some_and(|asset| !asset.info.hot_module_replacement && !asset.info.development)
+}
+
+/// Files of the async chunk groups which are directly imported by the entries with a
+/// `webpackPreload` or `webpackPrefetch` order, the higher order comes first.
+fn get_ordered_async_chunk_files(
+  compilation: &Compilation,
+  entrypoints: &[&ChunkGroup],
+  order_key: &ChunkGroupOrderKey,
+) -> Vec<String> {
+  entrypoints
+    .iter()
+    .filter_map(|entry| entry.get_children_by_orders(compilation).remove(order_key))
+    .flatten()
+    .flat_map(|child_group_ukey| {
+      compilation
+        .chunk_group_by_ukey
+        .expect_get(&child_group_ukey)
+        .get_files(&compilation.chunk_by_ukey)
+        .into_iter()
+        .sorted()
+    })
+    .filter(|asset_name| is_included_asset(compilation, asset_name))
+    .unique()
+    .collect()
+}
+
+fn create_resource_hints(
+  compilation: &Compilation,
+  config: &HtmlRspackPluginOptions,
+  assets: &HtmlPluginAssets,
+) -> Vec<HTMLPluginTag> {
+  let cross_origin = match &compilation.options.output.cross_origin_loading {
+    CrossOriginLoading::Enable(cross_origin) => Some(cross_origin.as_str()),
+    CrossOriginLoading::Disable => None,
+  };
+  let is_module =
+    compilation.options.output.module || matches!(config.script_loading, HtmlScriptLoading::Module);
+  let preload = assets.preload.iter().filter_map(|href| {
+    let tag = match resource_hint_type(href)? {
+      "script" if is_module => {
+        HTMLPluginTag::create_resource_hint(href, "modulepreload", None, cross_origin)
+      }
+      as_type => HTMLPluginTag::create_resource_hint(href, "preload", Some(as_type), cross_origin),
+    };
+    Some(tag)
+  });
+  let prefetch = assets.prefetch.iter().filter_map(|href| {
+    let as_type = resource_hint_type(href)?;
+    Some(HTMLPluginTag::create_resource_hint(
+      href,
+      "prefetch",
+      Some(as_type),
+      cross_origin,
+    ))
+  });
+  preload.chain(prefetch).collect()
+}
+
+/// The `as` attribute of the resource hint, only scripts and styles are hinted.
+fn resource_hint_type(url: &str) -> Option<&'static str> {
+  let path = url.split(['?', '#']).next().unwrap_or_default();
+  let extension = Path::new(path).extension()?;
+  if extension.eq_ignore_ascii_case("css") {
+    Some("style")
+  } else if extension.eq_ignore_ascii_case("js") || extension.eq_ignore_ascii_case("mjs") {
+    Some("script")
+  } else {
+    None
+  }
+}
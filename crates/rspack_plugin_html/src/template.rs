@@ -0,0 +1,166 @@
+use std::path::Path;
+
+use dojang::dojang::Dojang;
+use itertools::Itertools;
+use rspack_core::{ChunkGroup, Compilation};
+use rspack_error::{error, Result};
+use serde_json::{json, Map, Value};
+
+use crate::{
+  config::{HtmlInject, HtmlRspackPluginOptions, HtmlScriptLoading},
+  drive::HtmlPluginAssets,
+  visitors::asset::HTMLPluginTag,
+};
+
+/// The data of the compilation which the template is rendered with, the template parameters are
+/// merged into the top level like `templateParameters` of html-webpack-plugin.
+///
+/// ```text
+/// htmlRspackPlugin.files      { publicPath, js, css, favicon, entries: { [name]: { js, css } } }
+/// htmlRspackPlugin.tags       { headTags, bodyTags } rendered as html, output them with `<%-`
+/// htmlRspackPlugin.options    the plugin options
+/// compilation                 { hash, publicPath, chunks: [{ id, name, files, hash, initial, entry }] }
+/// ```
+pub struct HtmlTemplateContext<'a> {
+  pub config: &'a HtmlRspackPluginOptions,
+  pub assets: &'a HtmlPluginAssets,
+  pub entrypoints: &'a [&'a ChunkGroup],
+  pub head_tags: &'a [HTMLPluginTag],
+  pub body_tags: &'a [HTMLPluginTag],
+}
+
+impl HtmlTemplateContext<'_> {
+  pub fn to_json(&self, compilation: &Compilation) -> Value {
+    let public_path = &self.assets.public_path;
+    let entries = self
+      .entrypoints
+      .iter()
+      .filter_map(|entry| {
+        let name = entry.name()?;
+        let files = entry
+          .get_files(&compilation.chunk_by_ukey)
+          .into_iter()
+          .sorted()
+          .collect::<Vec<_>>();
+        let urls = |extensions: &[&str]| {
+          files
+            .iter()
+            .filter(|file| has_extension(file, extensions))
+            .map(|file| format!("{public_path}{file}"))
+            .collect::<Vec<_>>()
+        };
+        Some((
+          name.to_string(),
+          json!({ "js": urls(&["js", "mjs"]), "css": urls(&["css"]) }),
+        ))
+      })
+      .collect::<Map<_, _>>();
+
+    let chunks = compilation
+      .chunk_by_ukey
+      .values()
+      .sorted_by(|a, b| a.id.cmp(&b.id))
+      .map(|chunk| {
+        json!({
+          "id": chunk.id,
+          "name": chunk.name,
+          "files": chunk.files.iter().sorted().collect::<Vec<_>>(),
+          "hash": chunk.rendered_hash.as_deref(),
+          "initial": chunk.can_be_initial(&compilation.chunk_group_by_ukey),
+          "entry": chunk.has_runtime(&compilation.chunk_group_by_ukey),
+        })
+      })
+      .collect::<Vec<_>>();
+
+    let tags_to_html = |tags: &[HTMLPluginTag]| tags.iter().map(|tag| tag.to_html()).collect_vec();
+
+    let mut context = match &self.config.template_parameters {
+      Some(template_parameters) => template_parameters
+        .iter()
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect::<Map<_, _>>(),
+      None => Map::new(),
+    };
+    context.insert(
+      "htmlRspackPlugin".to_string(),
+      json!({
+        "files": {
+          "publicPath": public_path,
+          "js": self.assets.js,
+          "css": self.assets.css,
+          "favicon": self.assets.favicon,
+          "entries": entries,
+        },
+        "tags": {
+          "headTags": tags_to_html(self.head_tags),
+          "bodyTags": tags_to_html(self.body_tags),
+        },
+        "options": {
+          "filename": self.config.filename,
+          "title": self.config.title,
+          "inject": match self.config.inject {
+            HtmlInject::Head => "head",
+            HtmlInject::Body => "body",
+            HtmlInject::False => "false",
+          },
+          "scriptLoading": match self.config.script_loading {
+            HtmlScriptLoading::Blocking => "blocking",
+            HtmlScriptLoading::Defer => "defer",
+            HtmlScriptLoading::Module => "module",
+          },
+          "publicPath": public_path,
+        },
+      }),
+    );
+    context.insert(
+      "compilation".to_string(),
+      json!({
+        "hash": compilation.get_hash(),
+        "publicPath": public_path,
+        "chunks": chunks,
+      }),
+    );
+    Value::Object(context)
+  }
+}
+
+fn has_extension(file: &str, extensions: &[&str]) -> bool {
+  Path::new(file)
+    .extension()
+    .is_some_and(|extension| extensions.iter().any(|e| extension.eq_ignore_ascii_case(e)))
+}
+
+pub fn render_template(url: &str, content: String, context: Value) -> Result<String> {
+  let mut dj = Dojang::new();
+  dj.add(url.to_string(), content)
+    .map_err(|e| error!("failed to add template `{url}`: {e}"))?;
+  dj.render(url, context)
+    .map_err(|e| error!("failed to render template `{url}`: {e}"))
+}
+
+#[cfg(test)]
+mod test {
+  use serde_json::json;
+
+  use super::render_template;
+
+  #[test]
+  fn render_files_of_context() {
+    let context = json!({
+      "title": "app",
+      "htmlRspackPlugin": {
+        "files": { "js": ["/main.js", "/vendor.js"], "css": ["/main.css"] }
+      }
+    });
+    let html = render_template(
+      "index.html",
+      "<title><%= title %></title><% for js in htmlRspackPlugin.files.js { %><script src=\"<%- js %>\"></script><% } %>".to_string(),
+      context,
+    )
+    .expect("should render");
+    assert_eq!(
+      html,
+      "<title>app</title><script src=\"/main.js\"></script><script src=\"/vendor.js\"></script>"
+    );
+  }
+}
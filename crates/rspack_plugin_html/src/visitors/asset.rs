@@ -1,31 +1,25 @@
-use std::env;
-use std::path::PathBuf;
+use std::collections::HashMap;
 
 use itertools::Itertools;
-use regex::Regex;
-use rspack_core::Compilation;
 use swc_core::{common::DUMMY_SP, ecma::atoms::Atom};
-use swc_html::ast::{Attribute, Child, Element, Namespace, Text};
+use swc_html::ast::{Child, Element, Namespace, Text};
 use swc_html::visit::{VisitMut, VisitMutWith};
 
 use super::utils::create_element;
-use crate::config::{HtmlInject, HtmlRspackPluginOptions, HtmlScriptLoading};
+use crate::config::{HtmlRspackPluginOptions, HtmlScriptLoading};
 
 // the tag
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct HTMLPluginTag {
   pub tag_name: String,
   pub attributes: Vec<HtmlPluginAttribute>,
   pub void_tag: bool,
-  // `head`, `body`, `false`
-  pub append_to: HtmlInject,
 }
 
 impl HTMLPluginTag {
-  pub fn create_style(href: &str, append_to: HtmlInject) -> HTMLPluginTag {
+  pub fn create_style(href: &str) -> HTMLPluginTag {
     HTMLPluginTag {
       tag_name: "link".to_string(),
-      append_to,
       attributes: vec![
         HtmlPluginAttribute {
           attr_name: "href".to_string(),
@@ -40,11 +34,7 @@ impl HTMLPluginTag {
     }
   }
 
-  pub fn create_script(
-    src: &str,
-    append_to: HtmlInject,
-    script_loading: &HtmlScriptLoading,
-  ) -> HTMLPluginTag {
+  pub fn create_script(src: &str, script_loading: &HtmlScriptLoading) -> HTMLPluginTag {
     let mut attributes = vec![HtmlPluginAttribute {
       attr_name: "src".to_string(),
       attr_value: Some(src.to_string()),
@@ -67,16 +57,120 @@ impl HTMLPluginTag {
 
     HTMLPluginTag {
       tag_name: "script".to_string(),
-      append_to,
       attributes,
       void_tag: false,
     }
   }
+
+  pub fn create_favicon(href: &str) -> HTMLPluginTag {
+    HTMLPluginTag {
+      tag_name: "link".to_string(),
+      attributes: vec![
+        HtmlPluginAttribute {
+          attr_name: "rel".to_string(),
+          attr_value: Some("icon".to_string()),
+        },
+        HtmlPluginAttribute {
+          attr_name: "href".to_string(),
+          attr_value: Some(href.to_string()),
+        },
+      ],
+      void_tag: true,
+    }
+  }
+
+  pub fn create_meta(attributes: &HashMap<String, String>) -> HTMLPluginTag {
+    HTMLPluginTag {
+      tag_name: "meta".to_string(),
+      attributes: attributes
+        .iter()
+        .sorted()
+        .map(|(name, value)| HtmlPluginAttribute {
+          attr_name: name.clone(),
+          attr_value: Some(value.clone()),
+        })
+        .collect(),
+      void_tag: true,
+    }
+  }
+
+  pub fn create_resource_hint(
+    href: &str,
+    rel: &str,
+    as_type: Option<&str>,
+    cross_origin: Option<&str>,
+  ) -> HTMLPluginTag {
+    let mut attributes = vec![
+      HtmlPluginAttribute {
+        attr_name: "rel".to_string(),
+        attr_value: Some(rel.to_string()),
+      },
+      HtmlPluginAttribute {
+        attr_name: "href".to_string(),
+        attr_value: Some(href.to_string()),
+      },
+    ];
+    if let Some(as_type) = as_type {
+      attributes.push(HtmlPluginAttribute {
+        attr_name: "as".to_string(),
+        attr_value: Some(as_type.to_string()),
+      });
+    }
+    if let Some(cross_origin) = cross_origin {
+      attributes.push(HtmlPluginAttribute {
+        attr_name: "crossorigin".to_string(),
+        attr_value: Some(cross_origin.to_string()),
+      });
+    }
+    HTMLPluginTag {
+      tag_name: "link".to_string(),
+      attributes,
+      void_tag: true,
+    }
+  }
+
+  pub fn get_attribute(&self, attr_name: &str) -> Option<&str> {
+    self
+      .attributes
+      .iter()
+      .find(|attribute| attribute.attr_name == attr_name)
+      .and_then(|attribute| attribute.attr_value.as_deref())
+  }
+
+  /// Serializes the tag as html, used to place the tags manually in the template.
+  pub fn to_html(&self) -> String {
+    let attributes = self
+      .attributes
+      .iter()
+      .map(|attribute| match &attribute.attr_value {
+        Some(value) => format!(
+          " {}=\"{}\"",
+          attribute.attr_name,
+          value.replace('&', "&amp;").replace('"', "&quot;")
+        ),
+        None => format!(" {}", attribute.attr_name),
+      })
+      .join("");
+    if self.void_tag {
+      format!("<{}{attributes}>", self.tag_name)
+    } else {
+      format!("<{0}{attributes}></{0}>", self.tag_name)
+    }
+  }
+
+  /// The url of the asset which the tag loads, `src` of scripts and `href` of links.
+  pub fn asset_url(&self) -> Option<&str> {
+    match self.tag_name.as_str() {
+      "script" => self.get_attribute("src"),
+      "link" => self.get_attribute("href"),
+      _ => None,
+    }
+  }
 }
 
 // attributes are presented as plain string.
 // namespace is not supported currently.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct HtmlPluginAttribute {
   pub attr_name: String,
   // None is ``
@@ -84,46 +178,28 @@ pub struct HtmlPluginAttribute {
 }
 
 #[derive(Debug)]
-pub struct AssetWriter<'a, 'c> {
+pub struct AssetWriter<'a> {
   config: &'a HtmlRspackPluginOptions,
-  head_tags: Vec<&'a HTMLPluginTag>,
-  body_tags: Vec<&'a HTMLPluginTag>,
-  compilation: &'c Compilation,
+  head_tags: &'a [HTMLPluginTag],
+  body_tags: &'a [HTMLPluginTag],
 }
 
-impl<'a, 'c> AssetWriter<'a, 'c> {
+impl<'a> AssetWriter<'a> {
   pub fn new(
     config: &'a HtmlRspackPluginOptions,
-    tags: &'a [HTMLPluginTag],
-    compilation: &'c Compilation,
-  ) -> AssetWriter<'a, 'c> {
-    let mut head_tags: Vec<&HTMLPluginTag> = vec![];
-    let mut body_tags: Vec<&HTMLPluginTag> = vec![];
-    for ele in tags.iter() {
-      match ele.append_to {
-        HtmlInject::Head => {
-          head_tags.push(ele);
-        }
-        HtmlInject::Body => {
-          body_tags.push(ele);
-        }
-        _ => (),
-      }
-    }
+    head_tags: &'a [HTMLPluginTag],
+    body_tags: &'a [HTMLPluginTag],
+  ) -> AssetWriter<'a> {
     AssetWriter {
       config,
       head_tags,
       body_tags,
-      compilation,
     }
   }
 }
 
-impl VisitMut for AssetWriter<'_, '_> {
+impl VisitMut for AssetWriter<'_> {
   fn visit_mut_element(&mut self, n: &mut Element) {
-    let head_tags = &self.head_tags;
-    let body_tags = &self.body_tags;
-
     match &*n.tag_name {
       "head" => {
         // add title
@@ -158,97 +234,13 @@ impl VisitMut for AssetWriter<'_, '_> {
           }
         }
 
-        // add favicon
-        if let Some(favicon) = &self.config.favicon {
-          let favicon = PathBuf::from(favicon)
-            .file_name()
-            .expect("favicon should have file name")
-            .to_string_lossy()
-            .to_string();
-
-          let favicon_relative_path =
-            PathBuf::from(self.config.get_relative_path(self.compilation, &favicon));
-
-          let mut favicon_path = PathBuf::from(self.config.get_public_path(
-            self.compilation,
-            favicon_relative_path.to_string_lossy().to_string().as_str(),
-          ));
-
-          favicon_path.push(favicon_relative_path);
-
-          let mut favicon_link_path = favicon_path.to_string_lossy().to_string();
-
-          if env::consts::OS == "windows" {
-            let reg = Regex::new(r"[/\\]").expect("Invalid RegExp");
-            favicon_link_path = reg.replace_all(favicon_link_path.as_str(), "/").to_string();
-          }
-
-          n.children.push(Child::Element(Element {
-            tag_name: Atom::from("link"),
-            children: vec![],
-            is_self_closing: true,
-            namespace: Namespace::HTML,
-            span: DUMMY_SP,
-            attributes: vec![
-              Attribute {
-                span: Default::default(),
-                namespace: None,
-                prefix: None,
-                name: "rel".into(),
-                raw_name: None,
-                value: Some("icon".into()),
-                raw_value: None,
-              },
-              Attribute {
-                span: Default::default(),
-                namespace: None,
-                prefix: None,
-                name: "href".into(),
-                raw_name: None,
-                value: Some(favicon_link_path.into()),
-                raw_value: None,
-              },
-            ],
-            content: None,
-          }));
-        }
-
-        // add meta tags
-        if let Some(meta) = &self.config.meta {
-          for key in meta.keys().sorted() {
-            let value = meta.get(key).expect("should have value");
-            let meta_ele = Element {
-              tag_name: Atom::from("meta"),
-              attributes: value
-                .iter()
-                .sorted()
-                .map(|(key, value)| Attribute {
-                  span: Default::default(),
-                  namespace: None,
-                  prefix: None,
-                  name: key.clone().into(),
-                  raw_name: None,
-                  value: Some(value.clone().into()),
-                  raw_value: None,
-                })
-                .collect(),
-              children: vec![],
-              content: None,
-              is_self_closing: true,
-              namespace: Namespace::HTML,
-              span: DUMMY_SP,
-            };
-            n.children.push(Child::Element(meta_ele));
-          }
-        }
-
-        for tag in head_tags.iter() {
+        for tag in self.head_tags.iter() {
           let new_element = create_element(tag);
           n.children.push(Child::Element(new_element));
         }
       }
       "body" => {
-        for tag in body_tags.iter() {
+        for tag in self.body_tags.iter() {
           let new_element = create_element(tag);
           n.children.push(Child::Element(new_element));
         }
@@ -13,7 +13,7 @@ use rspack_core::{
 use rspack_core::{ModuleGraph, RuntimeSpec};
 use rspack_error::miette::{MietteDiagnostic, Severity};
 use rspack_error::DiagnosticExt;
-use rspack_error::{Diagnostic, TraceableError};
+use rspack_error::{codes, Diagnostic, TraceableError};
 use rustc_hash::{FxHashMap, FxHashSet as HashSet};
 use swc_core::ecma::atoms::Atom;
 
@@ -311,7 +311,7 @@ pub fn harmony_import_dependency_get_linking_error<T: ModuleDependency>(
             additional_msg,
             module_dependency.user_request(),
           );
-          return Some(create_error(msg));
+          return Some(create_error(msg).with_diagnostic_code(codes::EXPORT_NOT_FOUND));
         }
         maybe_exports_info = export_info.id.get_nested_exports_info(module_graph);
       }
@@ -325,7 +325,7 @@ pub fn harmony_import_dependency_get_linking_error<T: ModuleDependency>(
         additional_msg,
         module_dependency.user_request()
       );
-      return Some(create_error(msg));
+      return Some(create_error(msg).with_diagnostic_code(codes::EXPORT_NOT_FOUND));
     }
   }
   match exports_type {
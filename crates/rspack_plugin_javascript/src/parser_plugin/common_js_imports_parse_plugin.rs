@@ -1,6 +1,6 @@
 use rspack_core::{ConstDependency, ContextMode, DependencyCategory, ErrorSpan, SpanExt};
 use rspack_core::{ContextNameSpaceObject, ContextOptions};
-use rspack_error::Severity;
+use rspack_error::{codes, Severity};
 use swc_core::common::{Span, Spanned};
 use swc_core::ecma::ast::{CallExpr, Expr, Ident, Lit, MemberExpr, UnaryExpr};
 
@@ -241,7 +241,8 @@ impl CommonJsImportsParserPlugin {
         parser.source_file,
         ident.span().into(),
       )
-      .with_severity(Severity::Warn),
+      .with_severity(Severity::Warn)
+      .with_diagnostic_code(codes::CRITICAL_DEPENDENCY),
     ));
     parser.dependencies.push(Box::new(dep));
     Some(true)
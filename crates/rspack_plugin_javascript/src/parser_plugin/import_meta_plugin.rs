@@ -1,6 +1,6 @@
 use itertools::Itertools;
 use rspack_core::{property_access, ConstDependency, SpanExt};
-use rspack_error::miette::Severity;
+use rspack_error::{codes, miette::Severity};
 use swc_core::common::{Span, Spanned};
 use swc_core::ecma::ast::MemberProp;
 use url::Url;
@@ -164,12 +164,15 @@ impl JavascriptParserPlugin for ImportMetaPlugin {
       } else {
         // import.meta
         // warn when access import.meta directly
-        parser.warning_diagnostics.push(Box::new(create_traceable_error(
-      "Critical dependency".into(),
-      "Accessing import.meta directly is unsupported (only property access or destructuring is supported)".into(),
-      parser.source_file,
-      span.into()
-    ).with_severity(Severity::Warning)));
+        let warning = create_traceable_error(
+          "Critical dependency".into(),
+          "Accessing import.meta directly is unsupported (only property access or destructuring is supported)".into(),
+          parser.source_file,
+          span.into(),
+        )
+        .with_severity(Severity::Warning)
+        .with_diagnostic_code(codes::CRITICAL_DEPENDENCY);
+        parser.warning_diagnostics.push(Box::new(warning));
 
         let content = if parser.is_asi_position(span.lo()) {
           ";({})"
@@ -945,9 +945,13 @@ impl ModuleConcatenationPlugin {
               resolver_factory: compilation.resolver_factory.clone(),
               module: CompilerModuleContext::from_module(&new_module),
               module_source_map_kind: rspack_util::source_map::SourceMapKind::empty(),
+              module_importer: None,
+              assets: Default::default(),
             },
             plugin_driver: compilation.plugin_driver.clone(),
             compiler_options: &compilation.options,
+            fs: compilation.input_filesystem.clone(),
+            old_cache: compilation.old_cache.clone(),
           },
           Some(compilation),
         )
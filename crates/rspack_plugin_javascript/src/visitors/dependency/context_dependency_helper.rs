@@ -4,7 +4,7 @@ use itertools::Itertools;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::parse_resource;
-use rspack_error::Severity;
+use rspack_error::{codes, Severity};
 use rspack_util::json_stringify;
 use swc_core::ecma::ast::Expr;
 
@@ -91,7 +91,8 @@ pub fn create_context_dependency(
           parser.source_file,
           rspack_core::ErrorSpan::new(range.0, range.1),
         )
-        .with_severity(Severity::Warn),
+        .with_severity(Severity::Warn)
+        .with_diagnostic_code(codes::CRITICAL_DEPENDENCY),
       ));
     }
 
@@ -165,7 +166,8 @@ pub fn create_context_dependency(
           parser.source_file,
           rspack_core::ErrorSpan::new(range.0, range.1),
         )
-        .with_severity(Severity::Warn),
+        .with_severity(Severity::Warn)
+        .with_diagnostic_code(codes::CRITICAL_DEPENDENCY),
       ));
     }
 
@@ -192,7 +194,8 @@ pub fn create_context_dependency(
           parser.source_file,
           rspack_core::ErrorSpan::new(range.0, range.1),
         )
-        .with_severity(Severity::Warn),
+        .with_severity(Severity::Warn)
+        .with_diagnostic_code(codes::CRITICAL_DEPENDENCY),
       ));
     }
 
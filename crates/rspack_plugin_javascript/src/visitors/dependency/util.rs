@@ -1,6 +1,6 @@
 use rspack_core::{ConstDependency, DependencyLocation, ErrorSpan, SpanExt};
 use rspack_error::miette::diagnostic;
-use rspack_error::{miette::Severity, DiagnosticKind, TraceableError};
+use rspack_error::{codes, miette::Severity, DiagnosticKind, TraceableError};
 use rspack_regex::RspackRegex;
 use rustc_hash::FxHashSet as HashSet;
 use swc_core::common::{SourceFile, Spanned};
@@ -367,7 +367,8 @@ pub fn clean_regexp_in_context_module(
           parser.source_file,
           error_span,
         )
-        .with_severity(rspack_error::RspackSeverity::Warn),
+        .with_severity(rspack_error::RspackSeverity::Warn)
+        .with_diagnostic_code(codes::CRITICAL_DEPENDENCY),
       ));
     } else {
       parser.warning_diagnostics.push(
@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 use std::sync::atomic::Ordering::Relaxed;
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
 use std::{cmp, sync::atomic::AtomicU32, time::Instant};
 
@@ -19,6 +19,76 @@ use rspack_core::{
 };
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
+use serde::Serialize;
+
+pub type ProgressHandlerFn = Arc<dyn Fn(&ProgressEvent) + Send + Sync>;
+
+/// Where structured [ProgressEvent]s are delivered instead of drawing the progress bar.
+#[derive(Clone)]
+pub enum ProgressPluginHandler {
+  Fn(ProgressHandlerFn),
+  // one JSON object per line on stderr
+  JsonLines,
+}
+
+impl std::fmt::Debug for ProgressPluginHandler {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Fn(_) => write!(f, "Fn(...)"),
+      Self::JsonLines => write!(f, "JsonLines"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProgressPhase {
+  Setup,
+  Building,
+  Sealing,
+  Optimizing,
+  Emitting,
+}
+
+impl ProgressPhase {
+  fn from_state(msg: &str, items: &[String]) -> Self {
+    match msg {
+      "setup" => Self::Setup,
+      "sealing"
+        if items
+          .first()
+          .is_some_and(|item| item.ends_with("optimization")) =>
+      {
+        Self::Optimizing
+      }
+      "sealing" => Self::Sealing,
+      "emitting" => Self::Emitting,
+      _ => Self::Building,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressPhaseDuration {
+  pub phase: ProgressPhase,
+  // in milliseconds
+  pub duration: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressEvent {
+  // between 0 and 1
+  pub percentage: f32,
+  pub phase: ProgressPhase,
+  pub message: String,
+  pub active_module: Option<String>,
+  pub modules_done: u32,
+  pub modules_total: u32,
+  // accumulated time spent in each phase so far, only collected when `profile` is enabled
+  pub phase_durations: Option<Vec<ProgressPhaseDuration>>,
+}
 
 #[derive(Debug, Clone, Default)]
 pub struct ProgressPluginOptions {
@@ -32,6 +102,8 @@ pub struct ProgressPluginOptions {
   pub tick_strings: Option<Vec<String>>,
   // the progress characters, see [`indicatif::ProgressStyle::progress_chars`]
   pub progress_chars: String,
+  // emits structured progress events to the handler instead of drawing the progress bar
+  pub handler: Option<ProgressPluginHandler>,
 }
 
 #[derive(Debug)]
@@ -52,10 +124,33 @@ pub struct ProgressPlugin {
   pub last_modules_count: RwLock<Option<u32>>,
   pub last_active_module: RwLock<Option<ModuleIdentifier>>,
   pub last_state_info: RwLock<Vec<ProgressPluginStateInfo>>,
+  pub current_phase: RwLock<Option<(ProgressPhase, Instant)>>,
+  pub phase_durations: RwLock<Vec<(ProgressPhase, Duration)>>,
 }
 
 impl ProgressPlugin {
   pub fn new(options: ProgressPluginOptions) -> Self {
+    let progress_bar = if options.handler.is_some() {
+      ProgressBar::hidden()
+    } else {
+      Self::create_progress_bar(&options)
+    };
+
+    Self::new_inner(
+      options,
+      progress_bar,
+      AtomicU32::new(0),
+      AtomicU32::new(0),
+      Default::default(),
+      Default::default(),
+      Default::default(),
+      Default::default(),
+      Default::default(),
+      Default::default(),
+    )
+  }
+
+  fn create_progress_bar(options: &ProgressPluginOptions) -> ProgressBar {
     // default interval is 20, means draw every 1000/20 = 50ms, use 100 to draw every 1000/100 = 10ms
     let progress_bar =
       ProgressBar::with_draw_target(Some(100), ProgressDrawTarget::stdout_with_hz(100));
@@ -72,17 +167,7 @@ impl ProgressPlugin {
       );
     }
     progress_bar.set_style(progress_bar_style);
-
-    Self::new_inner(
-      options,
-      progress_bar,
-      AtomicU32::new(0),
-      AtomicU32::new(0),
-      Default::default(),
-      Default::default(),
-      Default::default(),
-      Default::default(),
-    )
+    progress_bar
   }
 
   fn update(&self) {
@@ -120,13 +205,84 @@ impl ProgressPlugin {
     state_items: Vec<String>,
     time: Option<Duration>,
   ) {
-    if self.options.profile {
+    if let Some(handler) = &self.options.handler {
+      let event = self.create_event(percent, msg, state_items);
+      match handler {
+        ProgressPluginHandler::Fn(f) => f(&event),
+        ProgressPluginHandler::JsonLines => {
+          if let Ok(line) = serde_json::to_string(&event) {
+            eprintln!("{line}");
+          }
+        }
+      }
+    } else if self.options.profile {
       self.default_handler(percent, msg, state_items, time);
     } else {
       self.progress_bar_handler(percent, msg, state_items);
     }
   }
 
+  fn create_event(&self, percent: f32, msg: String, state_items: Vec<String>) -> ProgressEvent {
+    let phase = ProgressPhase::from_state(&msg, &state_items);
+    let now = Instant::now();
+    let phase_durations = if self.options.profile {
+      let mut current_phase = self.current_phase.write().expect("TODO:");
+      let mut phase_durations = self.phase_durations.write().expect("TODO:");
+      match *current_phase {
+        Some((last_phase, _)) if last_phase == phase => {}
+        Some((last_phase, start)) => {
+          add_phase_duration(&mut phase_durations, last_phase, now - start);
+          *current_phase = Some((phase, now));
+        }
+        None => *current_phase = Some((phase, now)),
+      }
+      // report the time spent in the current phase so far as well
+      let mut durations = phase_durations.clone();
+      if let Some((phase, start)) = *current_phase {
+        add_phase_duration(&mut durations, phase, now - start);
+      }
+      Some(
+        durations
+          .into_iter()
+          .map(|(phase, duration)| ProgressPhaseDuration {
+            phase,
+            duration: duration.as_millis() as u64,
+          })
+          .collect(),
+      )
+    } else {
+      None
+    };
+    let active_module = if phase == ProgressPhase::Building {
+      self
+        .last_active_module
+        .read()
+        .expect("TODO:")
+        .filter(|module| !module.is_empty())
+        .map(|module| module.to_string())
+    } else {
+      None
+    };
+    let message = [vec![msg], state_items]
+      .concat()
+      .into_iter()
+      .filter(|item| !item.is_empty())
+      .collect::<Vec<_>>()
+      .join(" ");
+    ProgressEvent {
+      percentage: percent,
+      phase,
+      message,
+      active_module,
+      modules_done: self.modules_done.load(Relaxed),
+      modules_total: cmp::max(
+        self.last_modules_count.read().expect("TODO:").unwrap_or(0),
+        self.modules_count.load(Relaxed),
+      ),
+      phase_durations,
+    }
+  }
+
   fn default_handler(&self, _: f32, msg: String, items: Vec<String>, duration: Option<Duration>) {
     let full_state = [vec![msg.clone()], items.clone()].concat();
     let now = Instant::now();
@@ -201,10 +357,15 @@ impl ProgressPlugin {
     self.progress_bar.set_position((percent * 100.0) as u64);
   }
 
+  fn reset_phases(&self) {
+    *self.current_phase.write().expect("TODO:") = None;
+    self.phase_durations.write().expect("TODO:").clear();
+  }
+
   fn sealing_hooks_report(&self, name: &str, index: i32) {
     let number_of_sealing_hooks = 38;
     self.handler(
-      0.7 + 0.25 * (index / number_of_sealing_hooks) as f32,
+      0.7 + 0.25 * (index as f32 / number_of_sealing_hooks as f32),
       "sealing".to_string(),
       vec![name.to_string()],
       None,
@@ -212,12 +373,25 @@ impl ProgressPlugin {
   }
 }
 
+fn add_phase_duration(
+  durations: &mut Vec<(ProgressPhase, Duration)>,
+  phase: ProgressPhase,
+  duration: Duration,
+) {
+  if let Some((_, total)) = durations.iter_mut().find(|(p, _)| *p == phase) {
+    *total += duration;
+  } else {
+    durations.push((phase, duration));
+  }
+}
+
 #[plugin_hook(CompilerThisCompilation for ProgressPlugin)]
 async fn this_compilation(
   &self,
   _compilation: &mut Compilation,
   _params: &mut CompilationParams,
 ) -> Result<()> {
+  self.reset_phases();
   self.handler(
     0.08,
     "setup".to_string(),
@@ -244,7 +418,7 @@ async fn compilation(
 
 #[plugin_hook(CompilerMake for ProgressPlugin)]
 async fn make(&self, _compilation: &mut Compilation) -> Result<()> {
-  if !self.options.profile {
+  if !self.options.profile && self.options.handler.is_none() {
     self.progress_bar.reset();
     self.progress_bar.set_prefix(self.options.prefix.clone());
   }
@@ -513,3 +687,146 @@ impl Plugin for ProgressPlugin {
     Ok(())
   }
 }
+
+#[cfg(test)]
+mod test {
+  use std::sync::{Arc, Mutex};
+  use std::time::Duration;
+
+  use super::{
+    ProgressEvent, ProgressPhase, ProgressPlugin, ProgressPluginHandler, ProgressPluginOptions,
+  };
+
+  const SEALING_HOOKS: [(&str, i32); 12] = [
+    ("finish modules", 0),
+    ("plugins", 1),
+    ("dependencies", 2),
+    ("module optimization", 7),
+    ("after module optimization", 8),
+    ("chunk optimization", 9),
+    ("module and chunk tree optimization", 11),
+    ("chunk modules optimization", 13),
+    ("module ids", 16),
+    ("chunk ids", 21),
+    ("asset processing", 35),
+    ("after asset optimization", 36),
+  ];
+
+  fn plugin(profile: bool) -> (ProgressPlugin, Arc<Mutex<Vec<ProgressEvent>>>) {
+    let events = Arc::new(Mutex::new(vec![]));
+    let handler_events = events.clone();
+    let plugin = ProgressPlugin::new(ProgressPluginOptions {
+      profile,
+      handler: Some(ProgressPluginHandler::Fn(Arc::new(move |event| {
+        handler_events.lock().unwrap().push(event.clone());
+      }))),
+      ..Default::default()
+    });
+    (plugin, events)
+  }
+
+  #[test]
+  fn phases_of_sealing_hooks() {
+    let (plugin, events) = plugin(false);
+    for (name, index) in SEALING_HOOKS {
+      plugin.sealing_hooks_report(name, index);
+    }
+    let events = events.lock().unwrap();
+    let phases = events
+      .iter()
+      .map(|event| (event.message.as_str(), event.phase))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      phases,
+      vec![
+        ("sealing finish modules", ProgressPhase::Sealing),
+        ("sealing plugins", ProgressPhase::Sealing),
+        ("sealing dependencies", ProgressPhase::Sealing),
+        ("sealing module optimization", ProgressPhase::Optimizing),
+        (
+          "sealing after module optimization",
+          ProgressPhase::Optimizing
+        ),
+        ("sealing chunk optimization", ProgressPhase::Optimizing),
+        (
+          "sealing module and chunk tree optimization",
+          ProgressPhase::Optimizing
+        ),
+        (
+          "sealing chunk modules optimization",
+          ProgressPhase::Optimizing
+        ),
+        ("sealing module ids", ProgressPhase::Sealing),
+        ("sealing chunk ids", ProgressPhase::Sealing),
+        ("sealing asset processing", ProgressPhase::Sealing),
+        (
+          "sealing after asset optimization",
+          ProgressPhase::Optimizing
+        ),
+      ]
+    );
+    assert_eq!(events[0].percentage, 0.7);
+    assert!(events
+      .windows(2)
+      .all(|pair| pair[0].percentage < pair[1].percentage));
+    assert!(events.iter().all(|event| event.percentage < 0.95));
+    assert!(events.iter().all(|event| event.phase_durations.is_none()));
+  }
+
+  #[test]
+  fn phase_durations() {
+    let (plugin, events) = plugin(true);
+    plugin.handler(
+      0.08,
+      "setup".to_string(),
+      vec!["compilation".to_string()],
+      None,
+    );
+    std::thread::sleep(Duration::from_millis(20));
+    plugin.handler(0.1, "building".to_string(), vec![], None);
+    for (name, index) in SEALING_HOOKS {
+      plugin.sealing_hooks_report(name, index);
+    }
+    plugin.handler(0.98, "emitting".to_string(), vec!["emit".to_string()], None);
+
+    let recorded = events.lock().unwrap().clone();
+    let durations = recorded
+      .last()
+      .and_then(|event| event.phase_durations.clone())
+      .expect("should have phase durations");
+    assert_eq!(
+      durations
+        .iter()
+        .map(|duration| duration.phase)
+        .collect::<Vec<_>>(),
+      vec![
+        ProgressPhase::Setup,
+        ProgressPhase::Building,
+        ProgressPhase::Sealing,
+        ProgressPhase::Optimizing,
+        ProgressPhase::Emitting,
+      ]
+    );
+    assert!(durations[0].duration >= 20);
+    // the duration of a finished phase doesn't change anymore
+    let building = recorded[1]
+      .phase_durations
+      .clone()
+      .expect("should have phase durations");
+    assert_eq!(building[0].phase, ProgressPhase::Setup);
+    assert_eq!(building[0].duration, durations[0].duration);
+
+    // a new compilation starts over
+    plugin.reset_phases();
+    plugin.handler(0.08, "setup".to_string(), vec![], None);
+    let durations = events
+      .lock()
+      .unwrap()
+      .last()
+      .and_then(|event| event.phase_durations.clone())
+      .expect("should have phase durations");
+    assert_eq!(durations.len(), 1);
+    assert_eq!(durations[0].phase, ProgressPhase::Setup);
+    assert!(durations[0].duration < 20);
+  }
+}
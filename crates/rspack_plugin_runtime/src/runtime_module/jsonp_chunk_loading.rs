@@ -182,12 +182,26 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
         "#
       };
 
+      let integrity = if runtime_requirements.contains(RuntimeGlobals::SRI_HASHES) {
+        format!(
+          r#"
+          if ({sri_hashes}.js[chunkId]) {{
+            link.integrity = {sri_hashes}.js[chunkId];
+          }}
+          "#,
+          sri_hashes = RuntimeGlobals::SRI_HASHES
+        )
+      } else {
+        "".to_string()
+      };
+
       source.add(RawSource::from(
         include_str!("runtime/jsonp_chunk_loading_with_preload.js")
           .replace("$JS_MATCHER$", &js_matcher)
           .replace("$CROSS_ORIGIN$", cross_origin.as_str())
           .replace("$SCRIPT_TYPE_LINK_PRE$", script_type_link_pre.as_str())
-          .replace("$SCRIPT_TYPE_LINK_POST$", script_type_link_post),
+          .replace("$SCRIPT_TYPE_LINK_POST$", script_type_link_post)
+          .replace("$INTEGRITY$", integrity.as_str()),
       ));
     }
 
@@ -76,6 +76,19 @@ impl RuntimeModule for LoadScriptRuntimeModule {
       ))
     };
 
+    let integrity = if runtime_requirements.contains(RuntimeGlobals::SRI_HASHES) {
+      format!(
+        r#"
+        if (key === "chunk-" + chunkId && {sri_hashes}.js[chunkId]) {{
+          script.integrity = {sri_hashes}.js[chunkId];
+        }}
+        "#,
+        sri_hashes = RuntimeGlobals::SRI_HASHES
+      )
+    } else {
+      "".to_string()
+    };
+
     let script_charset = if compilation.options.output.charset {
       "script.charset = 'utf-8';".to_string()
     } else {
@@ -88,6 +101,7 @@ impl RuntimeModule for LoadScriptRuntimeModule {
           "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
           &cross_origin_loading,
         )
+        .replace("$INTEGRITY$", &integrity)
         .replace("$URL$", &url)
         .replace("$SCRIPT_TYPE$", &script_type)
         .replace("$SCRIPT_CHARSET$", &script_charset)
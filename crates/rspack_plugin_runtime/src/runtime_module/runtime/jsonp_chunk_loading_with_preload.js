@@ -10,6 +10,7 @@ __webpack_require__.H.j = function (chunkId) {
     $SCRIPT_TYPE_LINK_POST$
     link.href = __webpack_require__.p + __webpack_require__.u(chunkId);
     $CROSS_ORIGIN$
+    $INTEGRITY$
     document.head.appendChild(link);
   }
 };
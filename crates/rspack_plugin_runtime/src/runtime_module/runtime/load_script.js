@@ -32,6 +32,7 @@ __webpack_require__.l = function (url, done, key, chunkId$FETCH_PRIORITY$) {
 		script.src = $URL$;
 
 		__CROSS_ORIGIN_LOADING_PLACEHOLDER__
+		$INTEGRITY$
 	}
 	inProgress[url] = [done];
 	var onScriptComplete = function (prev, event) {
@@ -0,0 +1,302 @@
+use std::{
+  hash::Hasher,
+  io::Read,
+  path::{Path, PathBuf},
+};
+
+use rspack_error::{error, Result};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use sha2::{Digest, Sha512};
+use tokio::{fs, sync::Mutex};
+use url::Url;
+
+use super::lockfile::{Lockfile, LockfileEntry};
+
+#[derive(Debug, Clone)]
+pub struct FetchResult {
+  pub resolved: String,
+  pub content_type: String,
+  pub content: Vec<u8>,
+}
+
+/// Fetches remote resources, keeps their integrity in the lockfile and their content in the
+/// cache directory so that later builds can work offline.
+#[derive(Debug)]
+pub struct HttpCache {
+  lockfile_location: PathBuf,
+  cache_location: Option<PathBuf>,
+  frozen: bool,
+  upgrade: bool,
+  lockfile: Mutex<Option<Lockfile>>,
+}
+
+impl HttpCache {
+  pub fn new(
+    lockfile_location: PathBuf,
+    cache_location: Option<PathBuf>,
+    frozen: bool,
+    upgrade: bool,
+  ) -> Self {
+    Self {
+      lockfile_location,
+      cache_location,
+      frozen,
+      upgrade,
+      lockfile: Default::default(),
+    }
+  }
+
+  async fn lockfile_entry(&self, url: &str) -> Result<Option<LockfileEntry>> {
+    let mut lockfile = self.lockfile.lock().await;
+    if lockfile.is_none() {
+      *lockfile = Some(Lockfile::read(&self.lockfile_location).await?);
+    }
+    Ok(
+      lockfile
+        .as_ref()
+        .and_then(|lockfile| lockfile.get(url))
+        .cloned(),
+    )
+  }
+
+  async fn update_lockfile(&self, url: &str, entry: LockfileEntry) -> Result<()> {
+    let mut lockfile = self.lockfile.lock().await;
+    let lockfile = match lockfile.as_mut() {
+      Some(lockfile) => lockfile,
+      None => lockfile.insert(Lockfile::read(&self.lockfile_location).await?),
+    };
+    lockfile.insert(url.to_string(), entry);
+    lockfile.write(&self.lockfile_location).await
+  }
+
+  pub async fn fetch(&self, url: &str) -> Result<FetchResult> {
+    let entry = self.lockfile_entry(url).await?;
+
+    if let Some(entry) = entry.as_ref()
+      && !self.upgrade
+    {
+      if let Some(content) = self.read_cache(url, &entry.integrity).await {
+        return Ok(FetchResult {
+          resolved: entry.resolved.clone(),
+          content_type: entry.content_type.clone(),
+          content,
+        });
+      }
+      if self.frozen {
+        return Err(error!(
+          "{url} has a lockfile entry but is not in the cache and the lockfile is frozen"
+        ));
+      }
+      let result = fetch(url).await?;
+      if result.resolved != entry.resolved {
+        return Err(error!(
+          "{url} has changed: it redirects to {} but the lockfile entry resolves to {}. Enable `upgrade` to update the lockfile",
+          result.resolved,
+          entry.resolved
+        ));
+      }
+      let integrity = compute_integrity(&result.content);
+      if integrity != entry.integrity {
+        return Err(error!(
+          "{url} has changed: the integrity {integrity} doesn't match the lockfile entry {}. Enable `upgrade` to update the lockfile",
+          entry.integrity
+        ));
+      }
+      self.write_cache(url, &integrity, &result.content).await?;
+      return Ok(result);
+    }
+
+    if self.frozen {
+      return Err(error!(
+        "{url} has no lockfile entry and the lockfile is frozen"
+      ));
+    }
+    let result = fetch(url).await?;
+    let integrity = compute_integrity(&result.content);
+    self.write_cache(url, &integrity, &result.content).await?;
+    let entry = LockfileEntry {
+      resolved: result.resolved.clone(),
+      integrity,
+      content_type: result.content_type.clone(),
+    };
+    self.update_lockfile(url, entry).await?;
+    Ok(result)
+  }
+
+  async fn read_cache(&self, url: &str, integrity: &str) -> Option<Vec<u8>> {
+    let path = cache_path(self.cache_location.as_deref()?, url, integrity)?;
+    let content = fs::read(path).await.ok()?;
+    (compute_integrity(&content) == integrity).then_some(content)
+  }
+
+  async fn write_cache(&self, url: &str, integrity: &str, content: &[u8]) -> Result<()> {
+    let Some(cache_location) = self.cache_location.as_deref() else {
+      return Ok(());
+    };
+    let Some(path) = cache_path(cache_location, url, integrity) else {
+      return Ok(());
+    };
+    if let Some(parent) = path.parent() {
+      fs::create_dir_all(parent)
+        .await
+        .map_err(|e| error!("Failed to write cache for {url}: {e}"))?;
+    }
+    fs::write(&path, content)
+      .await
+      .map_err(|e| error!("Failed to write cache for {url}: {e}"))
+  }
+}
+
+async fn fetch(url: &str) -> Result<FetchResult> {
+  let url = url.to_string();
+  tokio::task::spawn_blocking(move || {
+    let response = ureq::get(&url)
+      .call()
+      .map_err(|e| error!("Failed to fetch {url}: {e}"))?;
+    let resolved = response.get_url().to_string();
+    let content_type = response.content_type().to_string();
+    let mut content = vec![];
+    response
+      .into_reader()
+      .read_to_end(&mut content)
+      .map_err(|e| error!("Failed to fetch {url}: {e}"))?;
+    Ok(FetchResult {
+      resolved,
+      content_type,
+      content,
+    })
+  })
+  .await
+  .map_err(|e| error!("Failed to fetch: {e}"))?
+}
+
+fn compute_integrity(content: &[u8]) -> String {
+  format!(
+    "sha512-{}",
+    rspack_base64::encode_to_string(Sha512::digest(content))
+  )
+}
+
+/// `<cache_location>/<host>/<path>_<hash of integrity>`, different versions of the same url
+/// are stored side by side.
+fn cache_path(cache_location: &Path, url: &str, integrity: &str) -> Option<PathBuf> {
+  let url = Url::parse(url).ok()?;
+  let host = match url.port() {
+    Some(port) => format!("{}_{port}", url.host_str()?),
+    None => url.host_str()?.to_string(),
+  };
+  let mut name = url
+    .path()
+    .trim_start_matches('/')
+    .chars()
+    .map(|c| {
+      if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+        c
+      } else {
+        '_'
+      }
+    })
+    .collect::<String>();
+  name.truncate(64);
+  let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+  hasher.write(url.as_str().as_bytes());
+  hasher.write(integrity.as_bytes());
+  let hash = hasher.digest(&HashDigest::Hex);
+  Some(
+    cache_location
+      .join(host)
+      .join(format!("{name}_{}", hash.encoded())),
+  )
+}
+
+#[cfg(test)]
+mod test {
+  use std::{
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    sync::{
+      atomic::{AtomicUsize, Ordering},
+      Arc,
+    },
+  };
+
+  use super::*;
+
+  /// Serve `body` for every request and count the requests.
+  fn serve(body: &'static str) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    std::thread::spawn(move || {
+      for mut stream in listener.incoming().flatten() {
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut line = String::new();
+        while reader.read_line(&mut line).is_ok() && line != "\r\n" && !line.is_empty() {
+          line.clear();
+        }
+        counter.fetch_add(1, Ordering::SeqCst);
+        let _ = write!(
+          stream,
+          "HTTP/1.1 200 OK\r\nContent-Type: text/javascript\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+          body.len()
+        );
+      }
+    });
+    (format!("http://{address}/lib/index.js"), requests)
+  }
+
+  #[tokio::test]
+  async fn fetch_records_lockfile_and_builds_offline() {
+    let (url, requests) = serve("export default 1");
+    let dir = std::env::temp_dir().join(format!("rspack-http-cache-{}", std::process::id()));
+    let lockfile_location = dir.join("rspack.lock");
+    let cache_location = dir.join("rspack.lock.data");
+
+    let cache = HttpCache::new(
+      lockfile_location.clone(),
+      Some(cache_location.clone()),
+      false,
+      false,
+    );
+    let result = cache.fetch(&url).await.unwrap();
+    assert_eq!(result.content, b"export default 1");
+    assert_eq!(result.content_type, "text/javascript");
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    let lockfile = Lockfile::read(&lockfile_location).await.unwrap();
+    let entry = lockfile.get(&url).unwrap();
+    assert_eq!(entry.integrity, compute_integrity(b"export default 1"));
+
+    let frozen = HttpCache::new(lockfile_location, Some(cache_location), true, false);
+    let result = frozen.fetch(&url).await.unwrap();
+    assert_eq!(result.content, b"export default 1");
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+    assert!(frozen.fetch("http://127.0.0.1:1/missing.js").await.is_err());
+
+    let _ = std::fs::remove_dir_all(dir);
+  }
+
+  #[tokio::test]
+  async fn fetch_rejects_redirects_not_in_lockfile() {
+    let (url, _) = serve("export default 1");
+    let dir = std::env::temp_dir().join(format!("rspack-http-redirect-{}", std::process::id()));
+    let lockfile_location = dir.join("rspack.lock");
+    let mut lockfile = Lockfile::read(&lockfile_location).await.unwrap();
+    lockfile.insert(
+      url.clone(),
+      LockfileEntry {
+        resolved: url.replace("/lib/", "/lib/v2/"),
+        integrity: compute_integrity(b"export default 1"),
+        content_type: "text/javascript".to_string(),
+      },
+    );
+    lockfile.write(&lockfile_location).await.unwrap();
+
+    let cache = HttpCache::new(lockfile_location, None, false, false);
+    let error = cache.fetch(&url).await.unwrap_err().to_string();
+    assert!(error.contains("redirects to"), "{error}");
+
+    let _ = std::fs::remove_dir_all(dir);
+  }
+}
@@ -0,0 +1,73 @@
+use std::{collections::BTreeMap, path::Path};
+
+use rspack_error::{error, Result};
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+const LOCKFILE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockfileEntry {
+  /// The url after following redirects.
+  pub resolved: String,
+  /// Subresource integrity of the content, e.g. `sha512-...`.
+  pub integrity: String,
+  pub content_type: String,
+}
+
+/// Content of the lockfile, entries are keyed by the requested url and kept sorted so that
+/// the file is stable under version control.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Lockfile {
+  version: u32,
+  #[serde(flatten)]
+  entries: BTreeMap<String, LockfileEntry>,
+}
+
+impl Lockfile {
+  /// Read the lockfile at `path`, a missing file is an empty lockfile.
+  pub async fn read(path: &Path) -> Result<Self> {
+    let content = match fs::read_to_string(path).await {
+      Ok(content) => content,
+      Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+        return Ok(Self {
+          version: LOCKFILE_VERSION,
+          entries: Default::default(),
+        })
+      }
+      Err(e) => return Err(error!("Failed to read lockfile {}: {e}", path.display())),
+    };
+    let lockfile: Self = serde_json::from_str(&content)
+      .map_err(|e| error!("Failed to parse lockfile {}: {e}", path.display()))?;
+    if lockfile.version != LOCKFILE_VERSION {
+      return Err(error!(
+        "Unsupported lockfile version {} in {}",
+        lockfile.version,
+        path.display()
+      ));
+    }
+    Ok(lockfile)
+  }
+
+  pub async fn write(&self, path: &Path) -> Result<()> {
+    let content = serde_json::to_string_pretty(self)
+      .map_err(|e| error!("Failed to serialize lockfile: {e}"))?;
+    if let Some(parent) = path.parent() {
+      fs::create_dir_all(parent)
+        .await
+        .map_err(|e| error!("Failed to write lockfile {}: {e}", path.display()))?;
+    }
+    fs::write(path, content + "\n")
+      .await
+      .map_err(|e| error!("Failed to write lockfile {}: {e}", path.display()))
+  }
+
+  pub fn get(&self, url: &str) -> Option<&LockfileEntry> {
+    self.entries.get(url)
+  }
+
+  pub fn insert(&mut self, url: String, entry: LockfileEntry) {
+    self.entries.insert(url, entry);
+  }
+}
@@ -0,0 +1,241 @@
+mod http_cache;
+mod lockfile;
+
+use std::path::PathBuf;
+
+use dashmap::DashMap;
+use once_cell::sync::OnceCell;
+use rspack_core::{
+  ApplyContext, CompilerOptions, Content, ModuleFactoryCreateData,
+  NormalModuleFactoryResolveForScheme, NormalModuleFactoryResolveInScheme,
+  NormalModuleReadResource, Plugin, PluginContext, ResourceData, Scheme,
+};
+use rspack_error::{error, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_regex::RspackRegex;
+use url::Url;
+
+use self::http_cache::HttpCache;
+
+#[derive(Debug, Clone)]
+pub enum HttpUriPluginAllowedUri {
+  /// Matches urls starting with the string.
+  String(String),
+  Regexp(RspackRegex),
+}
+
+impl HttpUriPluginAllowedUri {
+  fn matches(&self, url: &str) -> bool {
+    match self {
+      Self::String(prefix) => url.starts_with(prefix),
+      Self::Regexp(regexp) => regexp.test(url),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct HttpUriPluginOptions {
+  /// Urls which are allowed to be fetched.
+  pub allowed_uris: Vec<HttpUriPluginAllowedUri>,
+  /// Location of the lockfile, defaults to `rspack.lock` in the context.
+  pub lockfile_location: Option<PathBuf>,
+  /// Location of the fetched contents, defaults to `<lockfile_location>.data`.
+  /// `Some(None)` disables the cache, which requires network access for every build.
+  pub cache_location: Option<Option<PathBuf>>,
+  /// Build only from the lockfile and the cache without fetching anything.
+  pub frozen: bool,
+  /// Fetch resources again and update their lockfile entries when the content changed.
+  pub upgrade: bool,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct HttpUriPlugin {
+  options: HttpUriPluginOptions,
+  cache: OnceCell<HttpCache>,
+  /// Fetched contents by resource, consumed when the module is built.
+  contents: DashMap<String, Vec<u8>>,
+  /// The urls that resources were redirected to, keyed by the resource without query as it is
+  /// passed as the issuer of their dependencies.
+  redirects: DashMap<String, String>,
+}
+
+impl HttpUriPlugin {
+  pub fn new(options: HttpUriPluginOptions) -> Self {
+    Self::new_inner(
+      options,
+      Default::default(),
+      Default::default(),
+      Default::default(),
+    )
+  }
+
+  fn check_allowed(&self, url: &str) -> Result<()> {
+    if self
+      .options
+      .allowed_uris
+      .iter()
+      .any(|allowed| allowed.matches(url))
+    {
+      return Ok(());
+    }
+    Err(error!(
+      "{url} doesn't match the allowedUris policy. These URIs are allowed:\n{}",
+      self
+        .options
+        .allowed_uris
+        .iter()
+        .map(|allowed| match allowed {
+          HttpUriPluginAllowedUri::String(s) => format!(" - {s}"),
+          HttpUriPluginAllowedUri::Regexp(r) => format!(" - {}", r.to_source_string()),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+    ))
+  }
+
+  async fn resolve(&self, url: Url, resource_data: &mut ResourceData) -> Result<()> {
+    let resource = url.to_string();
+    self.check_allowed(&resource)?;
+    let result = self.cache()?.fetch(&resource).await?;
+    if result.resolved != resource {
+      self.check_allowed(&result.resolved).map_err(|e| {
+        error!(
+          "{resource} is redirected to {}, which is not allowed: {e}",
+          result.resolved
+        )
+      })?;
+      let issuer = resource
+        .split_once('?')
+        .map_or(resource.as_str(), |(issuer, _)| issuer);
+      self
+        .redirects
+        .insert(issuer.to_string(), result.resolved.clone());
+    }
+
+    let mut path = url.clone();
+    path.set_query(None);
+    path.set_fragment(None);
+    resource_data.set_resource(resource.clone());
+    resource_data.set_path(PathBuf::from(path.as_str()));
+    resource_data.set_query_optional(url.query().map(|q| format!("?{q}")));
+    resource_data.set_fragment_optional(url.fragment().map(|f| format!("#{f}")));
+    if !result.content_type.is_empty() {
+      resource_data.set_mimetype(result.content_type);
+    }
+    self.contents.insert(resource, result.content);
+    Ok(())
+  }
+
+  fn cache(&self) -> Result<&HttpCache> {
+    self
+      .cache
+      .get()
+      .ok_or_else(|| error!("HttpUriPlugin should be applied before resolving"))
+  }
+}
+
+#[plugin_hook(NormalModuleFactoryResolveForScheme for HttpUriPlugin)]
+async fn resolve_for_scheme(
+  &self,
+  _data: &mut ModuleFactoryCreateData,
+  resource_data: &mut ResourceData,
+  scheme: &Scheme,
+) -> Result<Option<bool>> {
+  if !scheme.is_http() && !scheme.is_https() {
+    return Ok(None);
+  }
+  let url = Url::parse(&resource_data.resource).map_err(|e| error!(e.to_string()))?;
+  self.resolve(url, resource_data).await?;
+  Ok(Some(true))
+}
+
+#[plugin_hook(NormalModuleFactoryResolveInScheme for HttpUriPlugin)]
+async fn resolve_in_scheme(
+  &self,
+  data: &mut ModuleFactoryCreateData,
+  resource_data: &mut ResourceData,
+  scheme: &Scheme,
+) -> Result<Option<bool>> {
+  if !scheme.is_http() && !scheme.is_https() {
+    return Ok(None);
+  }
+  // bare specifiers are resolved from the local context
+  let request = resource_data.resource.as_str();
+  if !request.starts_with("./") && !request.starts_with("../") && !request.starts_with('/') {
+    return Ok(None);
+  }
+  // the context of a remote module is a mangled path, relative requests are resolved against
+  // the url of the issuer instead, after following its redirects like browsers do
+  let Some(issuer) = data.issuer.as_deref() else {
+    return Ok(None);
+  };
+  let base = match self.redirects.get(issuer) {
+    Some(resolved) => resolved.clone(),
+    None => issuer.to_string(),
+  };
+  let base = Url::parse(&base).map_err(|e| error!(e.to_string()))?;
+  let url = base.join(request).map_err(|e| error!(e.to_string()))?;
+  self.resolve(url, resource_data).await?;
+  Ok(Some(true))
+}
+
+#[plugin_hook(NormalModuleReadResource for HttpUriPlugin)]
+async fn read_resource(&self, resource_data: &ResourceData) -> Result<Option<Content>> {
+  let scheme = resource_data.get_scheme();
+  if !scheme.is_http() && !scheme.is_https() {
+    return Ok(None);
+  }
+  if let Some((_, content)) = self.contents.remove(&resource_data.resource) {
+    return Ok(Some(Content::Buffer(content)));
+  }
+  let result = self.cache()?.fetch(&resource_data.resource).await?;
+  Ok(Some(Content::Buffer(result.content)))
+}
+
+#[async_trait::async_trait]
+impl Plugin for HttpUriPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.HttpUriPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    options: &mut CompilerOptions,
+  ) -> Result<()> {
+    let lockfile_location = self
+      .options
+      .lockfile_location
+      .clone()
+      .unwrap_or_else(|| PathBuf::from(options.context.as_str()).join("rspack.lock"));
+    let cache_location = self.options.cache_location.clone().unwrap_or_else(|| {
+      let mut location = lockfile_location.clone().into_os_string();
+      location.push(".data");
+      Some(location.into())
+    });
+    let _ = self.cache.set(HttpCache::new(
+      lockfile_location,
+      cache_location,
+      self.options.frozen,
+      self.options.upgrade,
+    ));
+
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .resolve_for_scheme
+      .tap(resolve_for_scheme::new(self));
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .resolve_in_scheme
+      .tap(resolve_in_scheme::new(self));
+    ctx
+      .context
+      .normal_module_hooks
+      .read_resource
+      .tap(read_resource::new(self));
+    Ok(())
+  }
+}
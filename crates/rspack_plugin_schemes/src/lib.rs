@@ -2,6 +2,8 @@
 
 mod data_uri;
 mod file_uri;
+mod http_uri;
 
 pub use data_uri::DataUriPlugin;
 pub use file_uri::FileUriPlugin;
+pub use http_uri::{HttpUriPlugin, HttpUriPluginAllowedUri, HttpUriPluginOptions};
@@ -6,7 +6,7 @@ use rspack_core::{
   ApplyContext, ChunkGroup, Compilation, CompilationAsset, CompilerAfterEmit, CompilerOptions,
   Plugin, PluginContext,
 };
-use rspack_error::{Diagnostic, Result};
+use rspack_error::{codes, Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::size::format_size;
 
@@ -69,6 +69,7 @@ impl SizeLimitsPlugin {
 
   fn add_diagnostic(
     hints: &str,
+    code: &str,
     title: String,
     message: String,
     diagnostics: &mut Vec<Diagnostic>,
@@ -78,7 +79,7 @@ impl SizeLimitsPlugin {
       "warning" => Diagnostic::warn(title, message),
       _ => Diagnostic::error(title, format!("Invalid hints type: {hints}")),
     };
-    diagnostics.push(diagnostic);
+    diagnostics.push(diagnostic.with_diagnostic_code(code));
   }
 
   fn add_assets_over_size_limit_warning(
@@ -95,7 +96,7 @@ impl SizeLimitsPlugin {
     let title = String::from("assets over size limit warning");
     let message = format!("asset size limit: The following asset(s) exceed the recommended size limit ({}). This can impact web performance.\nAssets:{}", format_size(limit), asset_list);
 
-    Self::add_diagnostic(hints, title, message, diagnostics);
+    Self::add_diagnostic(hints, codes::ASSET_SIZE_LIMIT, title, message, diagnostics);
   }
 
   fn add_entrypoints_over_size_limit_warning(
@@ -127,7 +128,13 @@ impl SizeLimitsPlugin {
       entrypoint_list
     );
 
-    Self::add_diagnostic(hints, title, message, diagnostics);
+    Self::add_diagnostic(
+      hints,
+      codes::ENTRYPOINT_SIZE_LIMIT,
+      title,
+      message,
+      diagnostics,
+    );
   }
 }
 
@@ -209,7 +216,13 @@ async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
         let title = String::from("no async chunks warning");
         let message = String::from("Rspack performance recommendations:\nYou can limit the size of your bundles by using import() to lazy load some parts of your application.\nFor more info visit https://www.rspack.dev/guide/optimization/code-splitting");
 
-        Self::add_diagnostic(hints, title, message, &mut diagnostics);
+        Self::add_diagnostic(
+          hints,
+          codes::NO_ASYNC_CHUNKS,
+          title,
+          message,
+          &mut diagnostics,
+        );
       }
 
       compilation.extend_diagnostics(diagnostics);
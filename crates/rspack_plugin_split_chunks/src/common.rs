@@ -114,6 +114,30 @@ impl SplitChunkSizes {
     })
   }
 
+  /// Port of `checkMinSize` of Webpack's SplitChunksPlugin.
+  ///
+  /// `SourceType`s which this doesn't have or having zero size are not checked.
+  pub fn reaches(&self, threshold: &Self) -> bool {
+    threshold.iter().all(|(ty, threshold_size)| {
+      let size = self.get(ty).copied().unwrap_or_default();
+      size == 0.0 || size >= *threshold_size
+    })
+  }
+
+  /// Port of `checkMinSizeReduction` of Webpack's SplitChunksPlugin.
+  ///
+  /// Whether moving modules of these sizes out of `chunk_count` chunks reduces enough size.
+  pub fn reduces_enough(&self, min_size_reduction: &Self, chunk_count: usize) -> bool {
+    min_size_reduction.iter().all(|(ty, min_reduction)| {
+      let size = self.get(ty).copied().unwrap_or_default();
+      *min_reduction == 0.0 || size == 0.0 || size * chunk_count as f64 >= *min_reduction
+    })
+  }
+
+  pub fn has_non_zero(&self) -> bool {
+    self.values().any(|size| *size > 0.0)
+  }
+
   pub fn add_by(&mut self, other: &Self) {
     self.combine_with(other, &|a, b| a + b)
   }
@@ -139,6 +163,8 @@ pub struct FallbackCacheGroup {
   #[derivative(Debug = "ignore")]
   pub chunks_filter: ChunkFilter,
   pub min_size: SplitChunkSizes,
+  /// Used as the `min_size` of the parts left over by `maxSize` splitting if it's bigger.
+  pub min_remaining_size: SplitChunkSizes,
   pub max_async_size: SplitChunkSizes,
   pub max_initial_size: SplitChunkSizes,
   pub automatic_name_delimiter: String,
@@ -30,7 +30,18 @@ pub struct CacheGroup {
   pub name: ChunkNameGetter,
   pub priority: f64,
   pub min_size: SplitChunkSizes,
+  /// The minimum size reduction of the chunks the modules are moved out from, the split is skipped
+  /// if `size * number of chunks` is smaller
+  pub min_size_reduction: SplitChunkSizes,
+  /// The minimum size of the only chunk left after splitting, and of the parts left by `maxSize`
+  pub min_remaining_size: SplitChunkSizes,
+  /// `ModuleGroup`s reaching the threshold are split regardless of `maxInitialRequests`,
+  /// `maxAsyncRequests`, `minSizeReduction` and `minRemainingSize`
+  pub enforce_size_threshold: SplitChunkSizes,
   pub reuse_existing_chunk: bool,
+  /// Modules are only put into the same `ModuleGroup` for the chunks in whose runtimes their
+  /// exports are used equally
+  pub used_exports: bool,
   /// number of referenced chunks
   pub min_chunks: u32,
   pub id_hint: String,
@@ -197,7 +197,7 @@ fn deterministic_grouping_for_modules(
 struct ChunkWithSizeInfo<'a> {
   pub chunk: ChunkUkey,
   pub allow_max_size: Cow<'a, SplitChunkSizes>,
-  pub min_size: &'a SplitChunkSizes,
+  pub min_size: Cow<'a, SplitChunkSizes>,
   pub automatic_name_delimiter: &'a String,
 }
 
@@ -231,6 +231,17 @@ impl SplitChunksPlugin {
       let min_size = max_size_setting
         .map(|s| &s.min_size)
         .unwrap_or(&fallback_cache_group.min_size);
+      let min_remaining_size = max_size_setting
+        .map(|s| &s.min_remaining_size)
+        .unwrap_or(&fallback_cache_group.min_remaining_size);
+      // the parts left over by splitting should not be smaller than `min_remaining_size` either
+      let min_size = if min_remaining_size.is_empty() {
+        Cow::Borrowed(min_size)
+      } else {
+        let mut sizes = min_size.clone();
+        sizes.combine_with(min_remaining_size, &f64::max);
+        Cow::Owned(sizes)
+      };
       let max_async_size = max_size_setting
         .map(|s| &s.max_async_size)
         .unwrap_or(&fallback_cache_group.max_async_size);
@@ -273,7 +284,7 @@ impl SplitChunksPlugin {
         }
       });
       if is_invalid {
-        allow_max_size.to_mut().combine_with(min_size, &f64::max);
+        allow_max_size.to_mut().combine_with(&min_size, &f64::max);
       }
 
       Ok(Some(ChunkWithSizeInfo {
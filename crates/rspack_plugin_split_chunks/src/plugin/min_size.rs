@@ -1,9 +1,9 @@
 use std::ops::Deref;
 
-use rspack_core::{Compilation, SourceType};
+use rspack_core::{ChunkUkey, Compilation, ModuleIdentifier, SourceType};
 
 use super::ModuleGroupMap;
-use crate::{module_group::ModuleGroup, CacheGroup, SplitChunksPlugin};
+use crate::{module_group::ModuleGroup, CacheGroup, SplitChunkSizes, SplitChunksPlugin};
 
 impl SplitChunksPlugin {
   /// Return `true` if the `ModuleGroup` become empty.
@@ -81,9 +81,9 @@ impl SplitChunksPlugin {
       .filter_map(|(module_group_key, module_group)| {
         let cache_group = module_group.get_cache_group(&self.cache_groups);
         // Fast path
-        if cache_group.min_size.is_empty() {
+        if cache_group.min_size.is_empty() && cache_group.min_size_reduction.is_empty() {
           tracing::debug!(
-            "ModuleGroup({}) skips `minSize` checking. Reason: min_size of CacheGroup({}) is empty",
+            "ModuleGroup({}) skips `minSize` checking. Reason: min_size and min_size_reduction of CacheGroup({}) are empty",
             module_group_key,
             cache_group.key,
           );
@@ -95,7 +95,10 @@ impl SplitChunksPlugin {
           compilation,
           module_group,
           cache_group,
-        ) {
+        ) || !module_group
+          .sizes
+          .reduces_enough(&cache_group.min_size_reduction, module_group.chunks.len())
+        {
           Some(module_group_key.clone())
         } else {
           None
@@ -105,10 +108,80 @@ impl SplitChunksPlugin {
 
     invalidated_module_groups.into_iter().for_each(|key| {
       tracing::debug!(
-        "ModuleGroup({}) is removed. Reason: empty modules cause by `minSize` checking",
+        "ModuleGroup({}) is removed. Reason: empty modules or too small size reduction caused by `minSize` checking",
         key,
       );
       module_group_map.remove(&key);
     });
   }
+
+  /// `ModuleGroup`s reaching `splitChunks.enforceSizeThreshold`/`splitChunks.cacheGroups.{cacheGroup}.enforceSizeThreshold`
+  /// ignore `maxInitialRequests`, `maxAsyncRequests`, `minSizeReduction` and `minRemainingSize`.
+  pub(crate) fn is_enforced_by_size_threshold(
+    module_group: &ModuleGroup,
+    cache_group: &CacheGroup,
+  ) -> bool {
+    cache_group.enforce_size_threshold.has_non_zero()
+      && module_group
+        .sizes
+        .reaches(&cache_group.enforce_size_threshold)
+  }
+
+  /// Affected by `splitChunks.minRemainingSize`/`splitChunks.cacheGroups.{cacheGroup}.minRemainingSize`
+  ///
+  /// When modules are only moved out from one chunk, the modules having the `SourceType`s whose
+  /// remaining size of the chunk is smaller than `min_remaining_size` are violating. Return `None`
+  /// if the remaining size doesn't violate `min_remaining_size`.
+  pub(crate) fn get_min_remaining_size_violating_modules(
+    compilation: &Compilation,
+    module_group: &ModuleGroup,
+    cache_group: &CacheGroup,
+    chunk: &ChunkUkey,
+  ) -> Option<Vec<ModuleIdentifier>> {
+    if !cache_group.min_remaining_size.has_non_zero() {
+      return None;
+    }
+
+    let module_graph = compilation.get_module_graph();
+    let mut remaining_sizes = SplitChunkSizes::empty();
+    for module in compilation
+      .chunk_graph
+      .get_chunk_modules(chunk, &module_graph)
+    {
+      if module_group.modules.contains(&module.identifier()) {
+        continue;
+      }
+      for ty in module.source_types() {
+        *remaining_sizes.entry(*ty).or_default() += module.size(Some(ty), compilation);
+      }
+    }
+
+    let violating_source_types = cache_group
+      .min_remaining_size
+      .iter()
+      .filter_map(|(ty, min_remaining_size)| {
+        let remaining_size = remaining_sizes.get(ty).copied().unwrap_or_default();
+        (remaining_size != 0.0 && remaining_size < *min_remaining_size).then_some(*ty)
+      })
+      .collect::<Box<[_]>>();
+    if violating_source_types.is_empty() {
+      return None;
+    }
+
+    Some(
+      module_group
+        .modules
+        .iter()
+        .filter(|module_id| {
+          let module = module_graph
+            .module_by_identifier(module_id)
+            .expect("Should have a module");
+          violating_source_types
+            .iter()
+            .any(|ty| module.source_types().contains(ty))
+        })
+        .copied()
+        .collect(),
+    )
+  }
 }
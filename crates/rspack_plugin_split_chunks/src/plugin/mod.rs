@@ -97,9 +97,13 @@ impl SplitChunksPlugin {
         module_group.chunks.remove(&new_chunk);
       }
 
+      let enforced = Self::is_enforced_by_size_threshold(&module_group, cache_group);
+
       let mut used_chunks = Cow::Borrowed(&module_group.chunks);
 
-      self.ensure_max_request_fit(compilation, cache_group, &mut used_chunks);
+      if !enforced {
+        self.ensure_max_request_fit(compilation, cache_group, &mut used_chunks);
+      }
 
       if used_chunks.len() != module_group.chunks.len() {
         // There are some chunks removed by `ensure_max_request_fit`
@@ -114,6 +118,40 @@ impl SplitChunksPlugin {
           tracing::trace!("ModuleGroup({module_group_key}) is skipped. Reason: used_chunks_len({used_chunks_len:?}) < cache_group.min_chunks({:?})", cache_group.min_chunks);
           return;
         }
+
+        if !enforced
+          && !module_group
+            .sizes
+            .reduces_enough(&cache_group.min_size_reduction, used_chunks.len())
+        {
+          tracing::trace!("ModuleGroup({module_group_key}) is skipped. Reason: size reduction is smaller than cache_group.min_size_reduction({:?})", cache_group.min_size_reduction);
+          return;
+        }
+      }
+
+      if !enforced
+        && used_chunks.len() == 1
+        && let Some(violating_modules) = Self::get_min_remaining_size_violating_modules(
+          compilation,
+          &module_group,
+          cache_group,
+          used_chunks.iter().next().expect("should have one chunk"),
+        )
+      {
+        tracing::trace!("ModuleGroup({module_group_key}) is skipped. Reason: the remaining size of the chunk is smaller than cache_group.min_remaining_size({:?})", cache_group.min_remaining_size);
+        let module_graph = compilation.get_module_graph();
+        let modules_len = module_group.modules.len();
+        for module in violating_modules {
+          let module = module_graph
+            .module_by_identifier(&module)
+            .expect("Should have a module");
+          module_group.remove_module(module.as_ref(), compilation);
+        }
+        if !module_group.modules.is_empty() && module_group.modules.len() != modules_len {
+          // process it again without the violating modules
+          module_group_map.insert(module_group_key, module_group);
+        }
+        return;
       }
 
       if !cache_group.max_initial_size.is_empty() || !cache_group.max_async_size.is_empty() {
@@ -121,6 +159,7 @@ impl SplitChunksPlugin {
           new_chunk,
           MaxSizeSetting {
             min_size: cache_group.min_size.clone(),
+            min_remaining_size: cache_group.min_remaining_size.clone(),
             max_async_size: cache_group.max_async_size.clone(),
             max_initial_size: cache_group.max_initial_size.clone(),
             automatic_name_delimiter: cache_group.automatic_name_delimiter.clone(),
@@ -189,6 +228,7 @@ impl Plugin for SplitChunksPlugin {
 #[derive(Debug)]
 struct MaxSizeSetting {
   pub min_size: SplitChunkSizes,
+  pub min_remaining_size: SplitChunkSizes,
   pub max_async_size: SplitChunkSizes,
   pub max_initial_size: SplitChunkSizes,
   pub automatic_name_delimiter: String,
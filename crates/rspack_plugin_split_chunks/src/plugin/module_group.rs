@@ -3,7 +3,9 @@ use std::hash::{BuildHasherDefault, Hash, Hasher};
 
 use dashmap::DashMap;
 use rayon::prelude::*;
-use rspack_core::{Chunk, ChunkGraph, ChunkUkey, Compilation, Module, ModuleGraph};
+use rspack_core::{
+  Chunk, ChunkUkey, Compilation, Module, ModuleGraph, ModuleIdentifier, UsageState,
+};
 use rspack_error::Result;
 use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 
@@ -82,36 +84,49 @@ impl SplitChunksPlugin {
 
     let module_group_map: DashMap<String, ModuleGroup> = DashMap::default();
 
-    // chunk_sets_in_graph: key: module, value: multiple chunks contains the module
-    // single_chunk_sets: chunkset of module that belongs to only one chunk
-    // chunk_sets_by_count: use chunkset len as key
-    let (chunk_sets_in_graph, chunk_sets_by_count) =
-      { Self::prepare_combination_maps(&module_graph, &compilation.chunk_graph) };
-
-    let combinations_cache =
-      DashMap::<ChunksKey, Vec<FxHashSet<ChunkUkey>>, ChunksKeyHashBuilder>::default();
-
-    let get_combination = |chunks_key: ChunksKey| match combinations_cache.entry(chunks_key) {
-      dashmap::mapref::entry::Entry::Occupied(entry) => entry.get().clone(),
-      dashmap::mapref::entry::Entry::Vacant(entry) => {
-        let chunks_set = chunk_sets_in_graph
-          .get(&chunks_key)
-          .expect("This should never happen, please file an issue");
-        let mut result = vec![chunks_set.clone()];
-
-        for (count, array_of_set) in &chunk_sets_by_count {
-          if *count < chunks_set.len() {
-            for set in array_of_set {
-              if set.is_subset(chunks_set) {
-                result.push(set.clone());
-              }
-            }
-          }
+    let combinator = Combinator::new(
+      module_graph
+        .modules()
+        .keys()
+        .map(|module| compilation.chunk_graph.get_module_chunks(*module).clone()),
+    );
+
+    // Chunks of each module grouped by how the exports of the module are used in their runtimes,
+    // only needed by `splitChunks.cacheGroups.{cacheGroup}.usedExports`
+    let chunks_grouped_by_exports: FxHashMap<ModuleIdentifier, Vec<FxHashSet<ChunkUkey>>> = if self
+      .cache_groups
+      .iter()
+      .any(|cache_group| cache_group.used_exports)
+    {
+      module_graph
+        .modules()
+        .keys()
+        .map(|module| {
+          (
+            *module,
+            Self::group_chunks_by_exports(module, &module_graph, compilation),
+          )
+        })
+        .collect()
+    } else {
+      Default::default()
+    };
+    let exports_combinator = Combinator::new(chunks_grouped_by_exports.values().flatten().cloned());
+
+    let get_combination_by_used_exports = |module: &ModuleIdentifier| {
+      let mut combinations =
+        HashMap::<ChunksKey, FxHashSet<ChunkUkey>, ChunksKeyHashBuilder>::default();
+      for chunks in chunks_grouped_by_exports
+        .get(module)
+        .expect("should have chunks grouped by exports")
+      {
+        for combination in exports_combinator.get_combination(Self::get_key(chunks.iter())) {
+          combinations
+            .entry(Self::get_key(combination.iter()))
+            .or_insert(combination);
         }
-
-        entry.insert(result.clone());
-        result
       }
+      combinations.into_values().collect::<Vec<_>>()
     };
 
     module_graph.modules().values().par_bridge().map(|module| {
@@ -168,7 +183,11 @@ impl SplitChunksPlugin {
         .filter(|(index, _)| temp[*index]);
 
       for (cache_group_index, (idx, cache_group)) in filtered.enumerate() {
-        let combs = get_combination(chunks_key);
+        let combs = if cache_group.used_exports {
+          get_combination_by_used_exports(&module.identifier())
+        } else {
+          combinator.get_combination(chunks_key)
+        };
 
         for chunk_combination in combs {
           if chunk_combination.is_empty() {
@@ -401,21 +420,43 @@ impl SplitChunksPlugin {
     hasher.finish()
   }
 
-  #[allow(clippy::type_complexity)]
-  fn prepare_combination_maps(
+  /// Port of `groupChunksByExports` of Webpack's SplitChunksPlugin, the module is used equally in the
+  /// chunks of a group.
+  fn group_chunks_by_exports(
+    module: &ModuleIdentifier,
     module_graph: &ModuleGraph,
-    chunk_graph: &ChunkGraph,
-  ) -> (
-    HashMap<ChunksKey, FxHashSet<ChunkUkey>, ChunksKeyHashBuilder>,
-    FxHashMap<usize, Vec<FxHashSet<ChunkUkey>>>,
-  ) {
+    compilation: &Compilation,
+  ) -> Vec<FxHashSet<ChunkUkey>> {
+    let exports_info = module_graph.get_exports_info(module);
+    let mut grouped_by_used_exports: FxHashMap<Vec<UsageState>, FxHashSet<ChunkUkey>> =
+      Default::default();
+    for chunk in compilation.chunk_graph.get_module_chunks(*module) {
+      let runtime = &compilation.chunk_by_ukey.expect_get(chunk).runtime;
+      grouped_by_used_exports
+        .entry(exports_info.get_usage_key(Some(runtime), module_graph))
+        .or_default()
+        .insert(*chunk);
+    }
+    grouped_by_used_exports.into_values().collect()
+  }
+}
+
+/// Finds the combinations of chunk sets, a combination of a chunk set is the set itself and the
+/// other chunk sets which are subsets of it.
+struct Combinator {
+  chunk_sets_in_graph: HashMap<ChunksKey, FxHashSet<ChunkUkey>, ChunksKeyHashBuilder>,
+  chunk_sets_by_count: FxHashMap<usize, Vec<FxHashSet<ChunkUkey>>>,
+  combinations_cache: DashMap<ChunksKey, Vec<FxHashSet<ChunkUkey>>, ChunksKeyHashBuilder>,
+}
+
+impl Combinator {
+  fn new(chunk_sets: impl Iterator<Item = FxHashSet<ChunkUkey>>) -> Self {
     let mut chunk_sets_in_graph =
       HashMap::<ChunksKey, FxHashSet<ChunkUkey>, ChunksKeyHashBuilder>::default();
 
-    for module in module_graph.modules().keys() {
-      let chunks = chunk_graph.get_module_chunks(*module);
-      let chunk_key = Self::get_key(chunks.iter());
-      chunk_sets_in_graph.insert(chunk_key, chunks.clone());
+    for chunks in chunk_sets {
+      let chunk_key = SplitChunksPlugin::get_key(chunks.iter());
+      chunk_sets_in_graph.insert(chunk_key, chunks);
     }
 
     let mut chunk_sets_by_count = FxHashMap::<usize, Vec<FxHashSet<ChunkUkey>>>::default();
@@ -428,6 +469,36 @@ impl SplitChunksPlugin {
         .or_insert(vec![chunks.clone()]);
     }
 
-    (chunk_sets_in_graph, chunk_sets_by_count)
+    Self {
+      chunk_sets_in_graph,
+      chunk_sets_by_count,
+      combinations_cache: Default::default(),
+    }
+  }
+
+  fn get_combination(&self, chunks_key: ChunksKey) -> Vec<FxHashSet<ChunkUkey>> {
+    match self.combinations_cache.entry(chunks_key) {
+      dashmap::mapref::entry::Entry::Occupied(entry) => entry.get().clone(),
+      dashmap::mapref::entry::Entry::Vacant(entry) => {
+        let chunks_set = self
+          .chunk_sets_in_graph
+          .get(&chunks_key)
+          .expect("This should never happen, please file an issue");
+        let mut result = vec![chunks_set.clone()];
+
+        for (count, array_of_set) in &self.chunk_sets_by_count {
+          if *count < chunks_set.len() {
+            for set in array_of_set {
+              if set.is_subset(chunks_set) {
+                result.push(set.clone());
+              }
+            }
+          }
+        }
+
+        entry.insert(result.clone());
+        result
+      }
+    }
   }
 }
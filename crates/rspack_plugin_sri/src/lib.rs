@@ -0,0 +1,281 @@
+mod runtime;
+
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use rayon::prelude::*;
+use regex::Regex;
+use rspack_core::{
+  rspack_sources::{RawSource, ReplaceSource, SourceExt},
+  ApplyContext, ChunkUkey, Compilation, CompilationAdditionalTreeRuntimeRequirements,
+  CompilationProcessAssets, CompilerOptions, CrossOriginLoading, Plugin, PluginContext,
+  RuntimeGlobals,
+};
+use rspack_error::{error, Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rustc_hash::FxHashMap as HashMap;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+use self::runtime::{integrity_placeholder, SriHashesRuntimeModule};
+
+static INTEGRITY_PLACEHOLDER_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"__rspack_sri_(?:js|css)_[0-9a-f]{16}_*").expect("Invalid regex"));
+
+#[derive(Debug, Clone, Copy)]
+pub enum SubresourceIntegrityHashFunction {
+  Sha256,
+  Sha384,
+  Sha512,
+}
+
+impl FromStr for SubresourceIntegrityHashFunction {
+  type Err = rspack_error::Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    match s {
+      "sha256" => Ok(Self::Sha256),
+      "sha384" => Ok(Self::Sha384),
+      "sha512" => Ok(Self::Sha512),
+      _ => Err(error!(
+        "Subresource integrity hash function only supports 'sha256', 'sha384' or 'sha512', but got '{s}'"
+      )),
+    }
+  }
+}
+
+impl SubresourceIntegrityHashFunction {
+  const ALL: [Self; 3] = [Self::Sha256, Self::Sha384, Self::Sha512];
+
+  fn digest(&self, content: &[u8]) -> String {
+    let (name, digest) = match self {
+      Self::Sha256 => ("sha256", Sha256::digest(content).to_vec()),
+      Self::Sha384 => ("sha384", Sha384::digest(content).to_vec()),
+      Self::Sha512 => ("sha512", Sha512::digest(content).to_vec()),
+    };
+    format!("{name}-{}", rspack_base64::encode_to_string(digest))
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct SubresourceIntegrityPluginOptions {
+  pub hash_func_names: Vec<SubresourceIntegrityHashFunction>,
+}
+
+impl Default for SubresourceIntegrityPluginOptions {
+  fn default() -> Self {
+    Self {
+      hash_func_names: vec![SubresourceIntegrityHashFunction::Sha384],
+    }
+  }
+}
+
+/// Sets the `integrity` of the scripts and stylesheets of chunks loaded on demand.
+///
+/// The integrity of every async chunk is embedded into the runtime as a placeholder of the same
+/// length, which is replaced once the content of the chunk files is final, after the real content
+/// hashes are computed at [`Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_HASH`]. The integrities of
+/// the runtime chunks in the html, which is generated before, are refreshed along with them.
+#[plugin]
+#[derive(Debug)]
+pub struct SubresourceIntegrityPlugin {
+  options: SubresourceIntegrityPluginOptions,
+}
+
+impl SubresourceIntegrityPlugin {
+  pub fn new(options: SubresourceIntegrityPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+
+  /// The length of the integrity of any content with the configured hash functions.
+  fn integrity_len(&self) -> usize {
+    self.compute_integrity(&[]).len()
+  }
+
+  fn compute_integrity(&self, content: &[u8]) -> String {
+    self
+      .options
+      .hash_func_names
+      .iter()
+      .map(|hash_func| hash_func.digest(content))
+      .collect::<Vec<_>>()
+      .join(" ")
+  }
+}
+
+#[plugin_hook(CompilationAdditionalTreeRuntimeRequirements for SubresourceIntegrityPlugin)]
+async fn additional_tree_runtime_requirements(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  runtime_requirements: &mut RuntimeGlobals,
+) -> Result<()> {
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  if chunk.has_async_chunks(&compilation.chunk_group_by_ukey) {
+    runtime_requirements.insert(RuntimeGlobals::SRI_HASHES);
+    compilation.add_runtime_module(
+      chunk_ukey,
+      Box::new(SriHashesRuntimeModule::new(
+        *chunk_ukey,
+        self.integrity_len(),
+      )),
+    )?;
+  }
+  Ok(())
+}
+
+#[plugin_hook(CompilationProcessAssets for SubresourceIntegrityPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_HASH + 1)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let runtime_files = compilation
+    .chunk_by_ukey
+    .values()
+    .filter(|chunk| {
+      compilation
+        .chunk_graph
+        .get_chunk_runtime_requirements(&chunk.ukey)
+        .contains(RuntimeGlobals::SRI_HASHES)
+    })
+    .flat_map(|chunk| chunk.files.iter().cloned())
+    .collect::<Vec<_>>();
+  if runtime_files.is_empty() {
+    return Ok(());
+  }
+
+  let integrity_len = self.integrity_len();
+  let chunk_files = compilation
+    .chunk_by_ukey
+    .values()
+    .filter_map(|chunk| Some((chunk.id.as_deref()?, chunk)))
+    .flat_map(|(chunk_id, chunk)| {
+      chunk.files.iter().filter_map(move |file| {
+        let kind = if file.ends_with(".css") {
+          "css"
+        } else if file.ends_with(".js") || file.ends_with(".mjs") || file.ends_with(".cjs") {
+          "js"
+        } else {
+          return None;
+        };
+        Some((
+          integrity_placeholder(kind, chunk_id, chunk, integrity_len),
+          file,
+        ))
+      })
+    })
+    .collect::<Vec<_>>();
+  let integrities = chunk_files
+    .into_par_iter()
+    .filter_map(|(placeholder, file)| {
+      let source = compilation.assets().get(file)?.get_source()?;
+      Some((placeholder, self.compute_integrity(&source.buffer())))
+    })
+    .collect::<HashMap<_, _>>();
+
+  // the integrities of the runtime chunks before and after the placeholders are replaced
+  let mut refreshed_integrities = HashMap::default();
+  for file in runtime_files {
+    let Some(old_content) = compilation
+      .assets()
+      .get(&file)
+      .and_then(|asset| asset.get_source())
+      .map(|source| source.buffer().into_owned())
+    else {
+      continue;
+    };
+    compilation.update_asset(&file, |source, info| {
+      let content = source.source().into_owned();
+      if !INTEGRITY_PLACEHOLDER_REGEX.is_match(&content) {
+        return Ok((source, info));
+      }
+      // replace in place to keep the source map of the runtime chunk
+      let mut replaced = ReplaceSource::new(source);
+      for placeholder in INTEGRITY_PLACEHOLDER_REGEX.find_iter(&content) {
+        // a chunk without a file of this kind loads nothing to verify
+        let integrity = integrities
+          .get(placeholder.as_str())
+          .map(|integrity| integrity.as_str())
+          .unwrap_or_default();
+        replaced.replace(
+          placeholder.start() as u32,
+          placeholder.end() as u32,
+          integrity,
+          None,
+        );
+      }
+      Ok((replaced.boxed(), info))
+    })?;
+    if let Some(new_content) = compilation
+      .assets()
+      .get(&file)
+      .and_then(|asset| asset.get_source())
+      .map(|source| source.buffer().into_owned())
+      .filter(|new_content| new_content != &old_content)
+    {
+      for hash_func in SubresourceIntegrityHashFunction::ALL {
+        refreshed_integrities.insert(
+          hash_func.digest(&old_content),
+          hash_func.digest(&new_content),
+        );
+      }
+    }
+  }
+
+  if !refreshed_integrities.is_empty() {
+    let html_files = compilation
+      .assets()
+      .keys()
+      .filter(|name| name.ends_with(".html"))
+      .cloned()
+      .collect::<Vec<_>>();
+    for file in html_files {
+      compilation.update_asset(&file, |source, info| {
+        let content = source.source();
+        if !refreshed_integrities
+          .keys()
+          .any(|integrity| content.contains(integrity.as_str()))
+        {
+          return Ok((source, info));
+        }
+        let content = refreshed_integrities
+          .iter()
+          .fold(content.into_owned(), |content, (old, new)| {
+            content.replace(old.as_str(), new)
+          });
+        Ok((RawSource::from(content).boxed(), info))
+      })?;
+    }
+  }
+
+  if matches!(
+    compilation.options.output.cross_origin_loading,
+    CrossOriginLoading::Disable
+  ) {
+    compilation.push_diagnostic(Diagnostic::warn(
+      "SubresourceIntegrityPlugin".to_string(),
+      "Chunks loaded from another origin fail the integrity check without a CORS request, set `output.crossOriginLoading` to \"anonymous\" or \"use-credentials\"".to_string(),
+    ));
+  }
+  Ok(())
+}
+
+impl Plugin for SubresourceIntegrityPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.SubresourceIntegrityPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .additional_tree_runtime_requirements
+      .tap(additional_tree_runtime_requirements::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
@@ -0,0 +1,113 @@
+use std::hash::Hasher;
+
+use itertools::Itertools;
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Chunk, ChunkUkey, Compilation, RuntimeGlobals, RuntimeModule, SourceType,
+};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_identifier::Identifier;
+use rspack_plugin_runtime::chunk_has_js;
+use serde_json::{Map, Value};
+
+/// Placeholder of the integrity of a chunk file, replaced once the content of the chunk file is
+/// final.
+///
+/// It is as long as the integrity, `len`, so that replacing it keeps the offsets of the source
+/// maps. It is derived from the content hashes of the chunk so that the hash of the runtime chunk
+/// changes whenever the integrity does.
+pub(crate) fn integrity_placeholder(
+  kind: &str,
+  chunk_id: &str,
+  chunk: &Chunk,
+  len: usize,
+) -> String {
+  let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+  hasher.write(chunk_id.as_bytes());
+  for content_hash in chunk
+    .content_hash
+    .values()
+    .map(|content_hash| content_hash.encoded())
+    .sorted_unstable()
+  {
+    hasher.write(content_hash.as_bytes());
+  }
+  let placeholder = format!(
+    "__rspack_sri_{kind}_{}",
+    hasher.digest(&HashDigest::Hex).encoded()
+  );
+  format!("{placeholder:_<len$}")
+}
+
+fn chunk_has_css(chunk_ukey: &ChunkUkey, compilation: &Compilation) -> bool {
+  compilation
+    .chunk_graph
+    .get_chunk_modules(chunk_ukey, &compilation.get_module_graph())
+    .iter()
+    .any(|module| {
+      module
+        .source_types()
+        .iter()
+        .any(|source_type| match source_type {
+          SourceType::Css => true,
+          // e.g. `css/mini-extract` of CssExtractRspackPlugin
+          SourceType::Custom(name) => name.starts_with("css"),
+          _ => false,
+        })
+    })
+}
+
+/// Generated once the async chunks are hashed, see [integrity_placeholder].
+#[impl_runtime_module]
+#[derive(Debug)]
+pub struct SriHashesRuntimeModule {
+  id: Identifier,
+  chunk: ChunkUkey,
+  integrity_len: usize,
+}
+
+impl SriHashesRuntimeModule {
+  pub fn new(chunk: ChunkUkey, integrity_len: usize) -> Self {
+    Self::with_default(
+      Identifier::from("webpack/runtime/sri_hashes"),
+      chunk,
+      integrity_len,
+    )
+  }
+}
+
+impl RuntimeModule for SriHashesRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+    let chunk = compilation.chunk_by_ukey.expect_get(&self.chunk);
+    let mut js = Map::new();
+    let mut css = Map::new();
+    for (chunk_ukey, chunk_id, async_chunk) in chunk
+      .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+      .iter()
+      .filter_map(|chunk_ukey| {
+        let async_chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+        Some((chunk_ukey, async_chunk.id.clone()?, async_chunk))
+      })
+      .sorted_by(|a, b| a.1.cmp(&b.1))
+    {
+      if chunk_has_js(chunk_ukey, compilation) {
+        let placeholder = integrity_placeholder("js", &chunk_id, async_chunk, self.integrity_len);
+        js.insert(chunk_id.clone(), Value::String(placeholder));
+      }
+      if chunk_has_css(chunk_ukey, compilation) {
+        let placeholder = integrity_placeholder("css", &chunk_id, async_chunk, self.integrity_len);
+        css.insert(chunk_id.clone(), Value::String(placeholder));
+      }
+    }
+    let hashes = Value::Object(Map::from_iter([
+      ("js".to_string(), Value::Object(js)),
+      ("css".to_string(), Value::Object(css)),
+    ]));
+    Ok(RawSource::from(format!("{} = {};\n", RuntimeGlobals::SRI_HASHES, hashes)).boxed())
+  }
+}
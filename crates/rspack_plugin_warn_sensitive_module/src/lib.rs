@@ -6,7 +6,7 @@ use rspack_core::{
   ApplyContext, Compilation, CompilationSeal, CompilerOptions, Logger, Module, ModuleGraph, Plugin,
   PluginContext,
 };
-use rspack_error::{Diagnostic, Result};
+use rspack_error::{codes, Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 
 #[plugin]
@@ -77,10 +77,13 @@ fn seal(&self, compilation: &mut Compilation) -> Result<()> {
     if lower_map.values().len() > 1 {
       let mut case_modules = lower_map.values().map(|m| m.as_ref()).collect::<Vec<_>>();
       case_modules.sort_by_key(|m| m.identifier());
-      diagnostics.push(Diagnostic::warn(
-        "Sensitive Modules Warn".to_string(),
-        self.create_sensitive_modules_warning(&case_modules, &compilation.get_module_graph()),
-      ));
+      diagnostics.push(
+        Diagnostic::warn(
+          "Sensitive Modules Warn".to_string(),
+          self.create_sensitive_modules_warning(&case_modules, &compilation.get_module_graph()),
+        )
+        .with_diagnostic_code(codes::CASE_SENSITIVE_MODULES),
+      );
     }
   }
 
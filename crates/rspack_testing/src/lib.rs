@@ -0,0 +1,212 @@
+//! Helpers to build small compilations from a [MemoryFileSystem] in the tests of rspack crates.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use rspack_core::{
+  rspack_sources::Source, BoxPlugin, CacheOptions, ChunkLoading, ChunkLoadingType, Compilation,
+  Compiler, CompilerOptions, Context, CrossOriginLoading, DynamicImportMode, EntryOptions,
+  Environment, JavascriptParserOptions, JavascriptParserOrder, JavascriptParserUrl, Mode,
+  ModuleOptions, ModuleType, Optimization, OutputOptions, ParserOptions, ParserOptionsByModuleType,
+  PathInfo, PluginExt, PublicPath, Resolve, StatsOptions, Target, WasmLoading,
+};
+use rspack_error::Result;
+use rspack_fs::{MemoryFileSystem, ReadableFileSystem, WritableFileSystem};
+use rspack_hash::{HashDigest, HashFunction, HashSalt};
+
+/// The context of the compilations, the files are written relative to it.
+pub const CONTEXT: &str = "/project";
+/// The output path of the compilations.
+pub const OUTPUT_PATH: &str = "/project/dist";
+
+/// Compiler options of a development build targeting node, the entry chunk is emitted as
+/// `main.js` to [OUTPUT_PATH].
+pub fn compiler_options() -> CompilerOptions {
+  CompilerOptions {
+    context: Context::from(CONTEXT),
+    dev_server: Default::default(),
+    output: OutputOptions {
+      path: OUTPUT_PATH.into(),
+      pathinfo: PathInfo::Bool(false),
+      clean: false,
+      public_path: PublicPath::Filename(String::new().into()),
+      asset_module_filename: "[hash][ext][query]".to_string().into(),
+      wasm_loading: WasmLoading::Disable,
+      webassembly_module_filename: "[hash].module.wasm".to_string().into(),
+      unique_name: "rspack-testing".to_string(),
+      chunk_loading: ChunkLoading::Enable(ChunkLoadingType::Require),
+      chunk_loading_global: "webpackChunkrspack_testing".to_string(),
+      chunk_load_timeout: 120000,
+      charset: false,
+      filename: "[name].js".to_string().into(),
+      chunk_filename: "[name].js".to_string().into(),
+      cross_origin_loading: CrossOriginLoading::Disable,
+      css_filename: "[name].css".to_string().into(),
+      css_chunk_filename: "[name].css".to_string().into(),
+      hot_update_main_filename: "[runtime].[fullhash].hot-update.json".to_string().into(),
+      hot_update_chunk_filename: "[id].[fullhash].hot-update.js".to_string().into(),
+      hot_update_global: "webpackHotUpdaterspack_testing".to_string(),
+      library: None,
+      enabled_library_types: None,
+      strict_module_error_handling: false,
+      global_object: "global".to_string(),
+      import_function_name: "import".to_string(),
+      iife: true,
+      module: false,
+      trusted_types: None,
+      source_map_filename: "[file].map".to_string().into(),
+      hash_function: HashFunction::Xxhash64,
+      hash_digest: HashDigest::Hex,
+      hash_digest_length: 16,
+      hash_salt: HashSalt::None,
+      async_chunks: true,
+      worker_chunk_loading: ChunkLoading::Disable,
+      worker_wasm_loading: WasmLoading::Disable,
+      worker_public_path: String::new(),
+      script_type: "false".to_string(),
+      environment: Environment {
+        r#const: Some(true),
+        arrow_function: Some(true),
+      },
+    },
+    target: Target::new(&vec![]).expect("should create target"),
+    mode: Mode::Development,
+    resolve: Resolve {
+      extensions: Some(vec![".js".to_string(), ".json".to_string()]),
+      ..Default::default()
+    },
+    resolve_loader: Resolve {
+      extensions: Some(vec![".js".to_string()]),
+      ..Default::default()
+    },
+    module: ModuleOptions {
+      parser: Some(ParserOptionsByModuleType::from_iter(
+        [ModuleType::JsAuto, ModuleType::JsDynamic, ModuleType::JsEsm].map(|module_type| {
+          (
+            module_type,
+            ParserOptions::Javascript(JavascriptParserOptions {
+              dynamic_import_mode: DynamicImportMode::Lazy,
+              dynamic_import_preload: JavascriptParserOrder::Disable,
+              dynamic_import_prefetch: JavascriptParserOrder::Disable,
+              dynamic_import_fetch_priority: None,
+              url: JavascriptParserUrl::Enable,
+              expr_context_critical: true,
+              wrapped_context_critical: false,
+              exports_presence: None,
+              import_exports_presence: None,
+              reexport_exports_presence: None,
+              strict_export_presence: false,
+              worker: vec!["...".to_string()],
+              override_strict: None,
+            }),
+          )
+        }),
+      )),
+      ..Default::default()
+    },
+    stats: StatsOptions::default(),
+    snapshot: Default::default(),
+    cache: CacheOptions::Disabled,
+    experiments: Default::default(),
+    node: None,
+    optimization: Optimization {
+      remove_available_modules: false,
+      side_effects: "false".into(),
+      provided_exports: false,
+      used_exports: "false".into(),
+      inner_graph: false,
+      mangle_exports: "false".into(),
+      concatenate_modules: false,
+    },
+    profile: false,
+    bail: false,
+    ignore_warnings: vec![],
+    __references: Default::default(),
+  }
+}
+
+/// The plugins needed to bundle javascript, with `entry` as the `main` entry.
+pub fn javascript_plugins(entry: &str) -> Vec<BoxPlugin> {
+  let mut plugins = vec![
+    rspack_plugin_javascript::JsPlugin::default().boxed(),
+    rspack_plugin_entry::EntryPlugin::new(
+      Context::from(CONTEXT),
+      entry.to_string(),
+      EntryOptions {
+        name: Some("main".to_string()),
+        ..Default::default()
+      },
+    )
+    .boxed(),
+    rspack_plugin_runtime::RuntimePlugin::default().boxed(),
+    rspack_plugin_runtime::CommonJsChunkFormatPlugin::default().boxed(),
+    rspack_ids::NamedModuleIdsPlugin::default().boxed(),
+    rspack_ids::NamedChunkIdsPlugin::new(None, None).boxed(),
+  ];
+  rspack_plugin_runtime::enable_chunk_loading_plugin(ChunkLoadingType::Require, &mut plugins);
+  plugins
+}
+
+/// Writes `files`, whose paths are relative to [CONTEXT], to a new [MemoryFileSystem].
+pub fn memory_fs(files: &[(&str, &str)]) -> Arc<MemoryFileSystem> {
+  let fs = MemoryFileSystem::new();
+  for (path, content) in files {
+    let path = Path::new(CONTEXT).join(path);
+    if let Some(dir) = path.parent() {
+      fs.create_dir_all(dir).expect("should create directory");
+    }
+    fs.write(&path, content.as_bytes())
+      .expect("should write file");
+  }
+  Arc::new(fs)
+}
+
+/// Builds `files` with `options` and `plugins`, both the input and the output file systems of
+/// the compiler are in memory.
+pub async fn build_with_options(
+  files: &[(&str, &str)],
+  options: CompilerOptions,
+  plugins: Vec<BoxPlugin>,
+) -> Result<Compiler<MemoryFileSystem>> {
+  let mut compiler = Compiler::new(
+    options,
+    plugins,
+    MemoryFileSystem::new(),
+    memory_fs(files),
+    None,
+    None,
+  );
+  compiler.build().await?;
+  Ok(compiler)
+}
+
+/// Builds `files` with [compiler_options], the [javascript_plugins] of `entry` and `plugins`.
+pub async fn build(
+  files: &[(&str, &str)],
+  entry: &str,
+  plugins: Vec<BoxPlugin>,
+) -> Result<Compiler<MemoryFileSystem>> {
+  let mut all_plugins = javascript_plugins(entry);
+  all_plugins.extend(plugins);
+  build_with_options(files, compiler_options(), all_plugins).await
+}
+
+/// The source of the asset `filename` of `compilation`.
+pub fn asset_source(compilation: &Compilation, filename: &str) -> String {
+  compilation
+    .assets()
+    .get(filename)
+    .and_then(|asset| asset.get_source())
+    .unwrap_or_else(|| panic!("should have asset {filename}"))
+    .source()
+    .to_string()
+}
+
+/// Reads an emitted file, `filename` is relative to [OUTPUT_PATH].
+pub fn read_output(compiler: &Compiler<MemoryFileSystem>, filename: &str) -> String {
+  let content = compiler
+    .output_filesystem
+    .read(&Path::new(OUTPUT_PATH).join(filename))
+    .unwrap_or_else(|_| panic!("should emit {filename}"));
+  String::from_utf8(content).expect("should be utf8")
+}
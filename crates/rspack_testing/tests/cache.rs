@@ -0,0 +1,190 @@
+use std::path::Path;
+use std::sync::{
+  atomic::{AtomicUsize, Ordering},
+  Arc,
+};
+
+use rspack_core::{
+  ApplyContext, BoxLoader, CacheOptions, Compilation, Compiler, CompilerOptions, Context,
+  FileSystemCacheOptions, Loader, LoaderContext, LogType, ModuleRule, ModuleRuleUse,
+  ModuleRuleUseLoader, NormalModuleFactoryResolveLoader, Plugin, PluginContext, PluginExt,
+  Resolver, RuleSetCondition, RunnerContext, SnapshotStrategy,
+};
+use rspack_error::Result;
+use rspack_fs::{MemoryFileSystem, WritableFileSystem};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_identifier::{Identifiable, Identifier};
+use rspack_testing::{asset_source, compiler_options, javascript_plugins, memory_fs, CONTEXT};
+
+const LOADER: &str = "counting-loader";
+
+/// Turns the text into the default export and counts how often it runs.
+#[derive(Debug)]
+struct CountingLoader {
+  runs: Arc<AtomicUsize>,
+}
+
+#[async_trait::async_trait]
+impl Loader<RunnerContext> for CountingLoader {
+  async fn run(&self, loader_context: &mut LoaderContext<RunnerContext>) -> Result<()> {
+    self.runs.fetch_add(1, Ordering::SeqCst);
+    let content = loader_context
+      .content
+      .take()
+      .expect("should have content")
+      .into_string_lossy();
+    loader_context.content =
+      Some(format!("export default {};", serde_json::json!(content.trim())).into());
+    Ok(())
+  }
+}
+
+impl Identifiable for CountingLoader {
+  fn identifier(&self) -> Identifier {
+    LOADER.into()
+  }
+}
+
+#[plugin]
+#[derive(Debug)]
+struct CountingLoaderPlugin {
+  runs: Arc<AtomicUsize>,
+}
+
+impl CountingLoaderPlugin {
+  fn new(runs: Arc<AtomicUsize>) -> Self {
+    Self::new_inner(runs)
+  }
+}
+
+#[plugin_hook(NormalModuleFactoryResolveLoader for CountingLoaderPlugin)]
+async fn resolve_loader(
+  &self,
+  _context: &Context,
+  _resolver: &Resolver,
+  l: &ModuleRuleUseLoader,
+) -> Result<Option<BoxLoader>> {
+  Ok((l.loader == LOADER).then(|| {
+    Arc::new(CountingLoader {
+      runs: self.runs.clone(),
+    }) as BoxLoader
+  }))
+}
+
+impl Plugin for CountingLoaderPlugin {
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .resolve_loader
+      .tap(resolve_loader::new(self));
+    Ok(())
+  }
+}
+
+/// Builds with a new compiler, whose filesystem cache is stored in `cache_location`.
+async fn build(
+  input_filesystem: Arc<MemoryFileSystem>,
+  cache_location: &Path,
+  runs: Arc<AtomicUsize>,
+) -> Compiler<MemoryFileSystem> {
+  let mut options = compiler_options();
+  options.cache = CacheOptions::FileSystem(FileSystemCacheOptions {
+    cache_location: cache_location.to_string_lossy().to_string(),
+    ..Default::default()
+  });
+  options.snapshot.module = SnapshotStrategy {
+    hash: true,
+    timestamp: false,
+  };
+  options.module.rules.push(ModuleRule {
+    test: Some(RuleSetCondition::String(
+      "/project/src/data.txt".to_string(),
+    )),
+    r#use: ModuleRuleUse::Array(vec![ModuleRuleUseLoader {
+      loader: LOADER.to_string(),
+      options: None,
+    }]),
+    ..Default::default()
+  });
+  let mut plugins = javascript_plugins("./src/index.js");
+  plugins.push(CountingLoaderPlugin::new(runs).boxed());
+  let mut compiler = Compiler::new(
+    options,
+    plugins,
+    MemoryFileSystem::new(),
+    input_filesystem,
+    None,
+    None,
+  );
+  compiler.build().await.expect("should build");
+  compiler
+}
+
+/// The hits and the total lookups of the chunk assets cache.
+fn chunk_assets_cache(compilation: &Compilation) -> (u32, u32) {
+  compilation
+    .get_logging()
+    .iter()
+    .flat_map(|entry| {
+      entry
+        .value()
+        .iter()
+        .filter_map(|log| match log {
+          LogType::Cache {
+            label: "chunk assets cache",
+            hit,
+            total,
+          } => Some((*hit, *total)),
+          _ => None,
+        })
+        .collect::<Vec<_>>()
+    })
+    .next()
+    .expect("should log the chunk assets cache")
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn filesystem_cache_is_used_by_a_new_compiler() {
+  let cache_location =
+    std::env::temp_dir().join(format!("rspack-testing-cache-{}", std::process::id()));
+  let _ = std::fs::remove_dir_all(&cache_location);
+  let input_filesystem = memory_fs(&[
+    (
+      "src/index.js",
+      "import data from './data.txt';\nconsole.log(data);",
+    ),
+    ("src/data.txt", "data"),
+  ]);
+  let runs = Arc::new(AtomicUsize::new(0));
+
+  let compiler = build(input_filesystem.clone(), &cache_location, runs.clone()).await;
+  let main = asset_source(&compiler.compilation, "main.js");
+  assert_eq!(runs.load(Ordering::SeqCst), 1);
+  let (hit, total) = chunk_assets_cache(&compiler.compilation);
+  assert_eq!(hit, 0);
+  assert!(total > 0);
+
+  // the loader result and the chunk assets are restored from the persisted cache
+  let compiler = build(input_filesystem.clone(), &cache_location, runs.clone()).await;
+  assert_eq!(asset_source(&compiler.compilation, "main.js"), main);
+  assert_eq!(runs.load(Ordering::SeqCst), 1);
+  let (hit, total) = chunk_assets_cache(&compiler.compilation);
+  assert_eq!(hit, total);
+
+  // changed files are built and rendered again
+  input_filesystem
+    .write(&Path::new(CONTEXT).join("src/data.txt"), b"changed")
+    .expect("should write file");
+  let compiler = build(input_filesystem, &cache_location, runs.clone()).await;
+  assert!(asset_source(&compiler.compilation, "main.js").contains(r#""changed""#));
+  assert_eq!(runs.load(Ordering::SeqCst), 2);
+  let (hit, _) = chunk_assets_cache(&compiler.compilation);
+  assert_eq!(hit, 0);
+
+  let _ = std::fs::remove_dir_all(cache_location);
+}
@@ -0,0 +1,257 @@
+use rspack_core::{
+  ApplyContext, Compilation, CompilationParams, CompilerCompilation, CompilerOptions, Plugin,
+  PluginContext, PluginExt,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rspack_plugin_html::{
+  config::{HtmlInject, HtmlResourceHints, HtmlRspackPluginOptions, HtmlScriptLoading},
+  visitors::asset::HtmlPluginAttribute,
+  AlterAssetTagGroupsData, AlterAssetTagsData, BeforeEmitData, HtmlPluginAlterAssetTagGroups,
+  HtmlPluginAlterAssetTags, HtmlPluginBeforeEmit, HtmlRspackPlugin,
+};
+use rspack_testing::{build, read_output};
+
+const FILES: &[(&str, &str)] = &[("src/index.js", "console.log('index');")];
+
+const ASYNC_CHUNKS_FILES: &[(&str, &str)] = &[
+  (
+    "src/index.js",
+    r#"import(/* webpackChunkName: "preloaded", webpackPreload: true */ "./preloaded.js");
+import(/* webpackChunkName: "prefetched", webpackPrefetch: true */ "./prefetched.js");"#,
+  ),
+  ("src/preloaded.js", "console.log('preloaded');"),
+  ("src/prefetched.js", "console.log('prefetched');"),
+];
+
+async fn build_html(files: &[(&str, &str)], options: HtmlRspackPluginOptions) -> String {
+  let compiler = build(
+    files,
+    "./src/index.js",
+    vec![HtmlRspackPlugin::new(options).boxed()],
+  )
+  .await
+  .expect("should build");
+  read_output(&compiler, "index.html")
+}
+
+#[plugin]
+#[derive(Debug, Default)]
+struct HtmlHooksTestPlugin;
+
+#[plugin_hook(CompilerCompilation for HtmlHooksTestPlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  let mut hooks = HtmlRspackPlugin::get_compilation_hooks_mut(compilation);
+  hooks.alter_asset_tags.tap(alter_asset_tags::new(self));
+  hooks
+    .alter_asset_tag_groups
+    .tap(alter_asset_tag_groups::new(self));
+  hooks.before_emit.tap(before_emit::new(self));
+  Ok(())
+}
+
+#[plugin_hook(HtmlPluginAlterAssetTags for HtmlHooksTestPlugin)]
+async fn alter_asset_tags(
+  &self,
+  compilation: &Compilation,
+  data: &mut AlterAssetTagsData,
+) -> Result<()> {
+  // the hooks can be accessed while they are called
+  let _ = HtmlRspackPlugin::get_compilation_hooks(compilation);
+  for tag in &mut data.asset_tags.scripts {
+    tag.attributes.push(HtmlPluginAttribute {
+      attr_name: "data-altered".to_string(),
+      attr_value: Some(data.output_name.clone()),
+    });
+  }
+  Ok(())
+}
+
+#[plugin_hook(HtmlPluginAlterAssetTagGroups for HtmlHooksTestPlugin)]
+async fn alter_asset_tag_groups(
+  &self,
+  _compilation: &Compilation,
+  data: &mut AlterAssetTagGroupsData,
+) -> Result<()> {
+  let (scripts, others) = data
+    .head_tags
+    .drain(..)
+    .partition(|tag| tag.tag_name == "script");
+  data.head_tags = others;
+  data.body_tags.extend::<Vec<_>>(scripts);
+  Ok(())
+}
+
+#[plugin_hook(HtmlPluginBeforeEmit for HtmlHooksTestPlugin)]
+async fn before_emit(&self, _compilation: &Compilation, data: &mut BeforeEmitData) -> Result<()> {
+  data.html = data.html.replace("</body>", "<!-- before emit --></body>");
+  Ok(())
+}
+
+impl Plugin for HtmlHooksTestPlugin {
+  fn name(&self) -> &'static str {
+    "HtmlHooksTestPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .compilation
+      .tap(compilation::new(self));
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn html_plugin_hooks_alter_the_html() {
+  let compiler = build(
+    FILES,
+    "./src/index.js",
+    vec![
+      HtmlRspackPlugin::new(HtmlRspackPluginOptions::default()).boxed(),
+      HtmlHooksTestPlugin::default().boxed(),
+    ],
+  )
+  .await
+  .expect("should build");
+
+  let html = read_output(&compiler, "index.html");
+  let body = html.find("<body>").expect("should have body");
+  let script = html
+    .find(r#"<script src="main.js" defer data-altered="index.html">"#)
+    .unwrap_or_else(|| panic!("should have altered script in {html}"));
+  assert!(script > body, "{html}");
+  assert!(html.contains("<!-- before emit --></body>"), "{html}");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn resource_hints_preload_and_prefetch_chunks() {
+  let html = build_html(
+    ASYNC_CHUNKS_FILES,
+    HtmlRspackPluginOptions {
+      resource_hints: HtmlResourceHints {
+        initial: true,
+        async_chunks: true,
+      },
+      ..Default::default()
+    },
+  )
+  .await;
+
+  // preloads come first, then prefetches
+  let hints = [
+    r#"<link rel="preload" href="main.js" as="script" />"#,
+    r#"<link rel="preload" href="preloaded.js" as="script" />"#,
+    r#"<link rel="prefetch" href="prefetched.js" as="script" />"#,
+  ];
+  assert!(html.contains(&hints.concat()), "{html}");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn resource_hints_modulepreload_module_scripts() {
+  let html = build_html(
+    FILES,
+    HtmlRspackPluginOptions {
+      script_loading: HtmlScriptLoading::Module,
+      resource_hints: HtmlResourceHints {
+        initial: true,
+        async_chunks: false,
+      },
+      ..Default::default()
+    },
+  )
+  .await;
+
+  assert!(
+    html.contains(r#"<link rel="modulepreload" href="main.js" />"#),
+    "{html}"
+  );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn resource_hints_are_not_injected_without_inject() {
+  let html = build_html(
+    ASYNC_CHUNKS_FILES,
+    HtmlRspackPluginOptions {
+      inject: HtmlInject::False,
+      resource_hints: HtmlResourceHints {
+        initial: true,
+        async_chunks: true,
+      },
+      ..Default::default()
+    },
+  )
+  .await;
+
+  assert!(!html.contains("<link"), "{html}");
+  assert!(!html.contains("<script"), "{html}");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn template_context_of_the_compilation() {
+  let template = r#"<title><%= title %></title>
+<% for js in htmlRspackPlugin.files.js { %><script src="<%- js %>"></script><% } %>
+<% for js in htmlRspackPlugin.files.entries.main.js { %><entry src="<%- js %>"></entry><% } %>
+<% for chunk in compilation.chunks { %><chunk name="<%- chunk.name %>"></chunk><% } %>
+<hash><%= compilation.hash %></hash>
+<inject><%= htmlRspackPlugin.options.inject %></inject>"#;
+  let html = build_html(
+    ASYNC_CHUNKS_FILES,
+    HtmlRspackPluginOptions {
+      template_content: Some(template.to_string()),
+      template_parameters: Some(
+        [("title".to_string(), serde_json::json!("app"))]
+          .into_iter()
+          .collect(),
+      ),
+      inject: HtmlInject::False,
+      public_path: Some("/static/".to_string()),
+      ..Default::default()
+    },
+  )
+  .await;
+
+  assert!(html.contains("<title>app</title>"), "{html}");
+  assert!(
+    html.contains(r#"<script src="/static/main.js"></script>"#),
+    "{html}"
+  );
+  assert!(
+    html.contains(r#"<entry src="/static/main.js"></entry>"#),
+    "{html}"
+  );
+  for name in ["main", "preloaded", "prefetched"] {
+    assert!(
+      html.contains(&format!(r#"<chunk name="{name}">"#)),
+      "{html}"
+    );
+  }
+  assert!(!html.contains("<hash></hash>"), "{html}");
+  assert!(html.contains("<inject>false</inject>"), "{html}");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn template_without_parameters_is_not_rendered() {
+  let template = "<title><%= title %></title>";
+  let html = build_html(
+    FILES,
+    HtmlRspackPluginOptions {
+      template_content: Some(template.to_string()),
+      inject: HtmlInject::False,
+      ..Default::default()
+    },
+  )
+  .await;
+
+  // left as it is, the text is escaped by the html serializer
+  assert!(html.contains("<title>&lt;%= title %&gt;</title>"), "{html}");
+}
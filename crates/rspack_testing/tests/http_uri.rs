@@ -0,0 +1,86 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+
+use rspack_core::PluginExt;
+use rspack_plugin_schemes::{HttpUriPlugin, HttpUriPluginAllowedUri, HttpUriPluginOptions};
+use rspack_testing::{build, read_output};
+
+/// Serve the `routes`, a route whose body starts with `->` redirects to the path after it.
+fn serve(routes: &'static [(&'static str, &'static str)]) -> String {
+  let listener = TcpListener::bind("127.0.0.1:0").expect("should bind");
+  let address = listener.local_addr().expect("should have address");
+  std::thread::spawn(move || {
+    for mut stream in listener.incoming().flatten() {
+      let mut reader = BufReader::new(stream.try_clone().expect("should clone stream"));
+      let mut request = String::new();
+      reader.read_line(&mut request).expect("should read request");
+      let mut line = String::new();
+      while reader.read_line(&mut line).is_ok() && line != "\r\n" && !line.is_empty() {
+        line.clear();
+      }
+      let path = request.split(' ').nth(1).unwrap_or_default();
+      let _ = match routes.iter().find(|(route, _)| *route == path) {
+        Some((_, body)) if body.starts_with("->") => write!(
+          stream,
+          "HTTP/1.1 302 Found\r\nLocation: {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+          &body[2..]
+        ),
+        Some((_, body)) => write!(
+          stream,
+          "HTTP/1.1 200 OK\r\nContent-Type: text/javascript\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+          body.len()
+        ),
+        None => write!(
+          stream,
+          "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+        ),
+      };
+    }
+  });
+  format!("http://{address}")
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn resolve_relative_imports_inside_remote_modules() {
+  let origin = serve(&[
+    ("/lib/index.js", "->/lib/v2/index.js"),
+    (
+      "/lib/v2/index.js",
+      "import { a } from './a.js';\nexport default a;",
+    ),
+    ("/lib/v2/a.js", "export { b as a } from '../b.js';"),
+    ("/lib/b.js", "export const b = 'from remote';"),
+  ]);
+  let dir = std::env::temp_dir().join(format!("rspack-testing-http-uri-{}", std::process::id()));
+  let plugin = HttpUriPlugin::new(HttpUriPluginOptions {
+    allowed_uris: vec![HttpUriPluginAllowedUri::String(format!("{origin}/"))],
+    lockfile_location: Some(dir.join("rspack.lock")),
+    cache_location: Some(None),
+    ..Default::default()
+  });
+
+  let compiler = build(
+    &[(
+      "src/index.js",
+      &format!("import value from '{origin}/lib/index.js';\nconsole.log(value);"),
+    )],
+    "./src/index.js",
+    vec![plugin.boxed()],
+  )
+  .await
+  .expect("should build");
+
+  let errors = compiler
+    .compilation
+    .get_errors()
+    .map(|e| e.to_string())
+    .collect::<Vec<_>>();
+  assert!(errors.is_empty(), "{errors:?}");
+  let main = read_output(&compiler, "main.js");
+  assert!(main.contains(&format!("{origin}/lib/v2/a.js")));
+  assert!(main.contains("'from remote'"));
+
+  let lockfile = std::fs::read_to_string(dir.join("rspack.lock")).unwrap();
+  assert!(lockfile.contains(&format!("\"resolved\": \"{origin}/lib/v2/index.js\"")));
+  let _ = std::fs::remove_dir_all(dir);
+}
@@ -0,0 +1,24 @@
+use rspack_testing::{build, read_output};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn build_entry_from_memory_file_system() {
+  let compiler = build(
+    &[
+      (
+        "src/index.js",
+        "import { a } from './lib/a.js';\nconsole.log(a);",
+      ),
+      ("src/lib/a.js", "export { b as a } from '../b.js';"),
+      ("src/b.js", "export const b = 'from memory';"),
+    ],
+    "./src/index.js",
+    vec![],
+  )
+  .await
+  .expect("should build");
+
+  assert!(compiler.compilation.get_errors().next().is_none());
+  let main = read_output(&compiler, "main.js");
+  assert!(main.contains("./src/lib/a.js"));
+  assert!(main.contains("'from memory'"));
+}
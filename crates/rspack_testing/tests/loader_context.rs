@@ -0,0 +1,200 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  ApplyContext, BoxLoader, Compiler, CompilerOptions, Context, Loader, LoaderContext,
+  LoaderContextExt, ModuleRule, ModuleRuleUse, ModuleRuleUseLoader,
+  NormalModuleFactoryResolveLoader, Plugin, PluginContext, PluginExt, Resolve, Resolver,
+  RuleSetCondition, RunnerContext,
+};
+use rspack_error::Result;
+use rspack_fs::MemoryFileSystem;
+use rspack_hook::{plugin, plugin_hook};
+use rspack_identifier::{Identifiable, Identifier};
+use rspack_plugin_javascript::{
+  FlagDependencyExportsPlugin, FlagDependencyUsagePlugin, ModuleConcatenationPlugin,
+};
+use rspack_testing::{asset_source, build_with_options, compiler_options, javascript_plugins};
+
+const LOADER: &str = "test-loader";
+
+const FILES: &[(&str, &str)] = &[
+  (
+    "src/index.js",
+    "import data from './data.txt';\nconsole.log(data);",
+  ),
+  ("src/data.txt", "data"),
+  ("src/value.js", "export default 'value';"),
+  ("lib/dep.mjs", "export default 'dep';"),
+];
+
+/// Emits a file, resolves `@lib/dep` and imports `./value.js`, the results are the exports of
+/// the module.
+#[derive(Debug)]
+struct TestLoader;
+
+#[async_trait::async_trait]
+impl Loader<RunnerContext> for TestLoader {
+  async fn run(&self, loader_context: &mut LoaderContext<RunnerContext>) -> Result<()> {
+    let context = loader_context.context.module.context.as_deref().cloned();
+    let context = context.expect("should have context");
+    loader_context.emit_file(
+      "emitted.txt".to_string(),
+      RawSource::from("emitted").boxed(),
+      None,
+    );
+    let resolved = loader_context
+      .resolve(Path::new(context.as_str()), "@lib/dep")
+      .await?;
+    let rspack_core::ResolveResult::Resource(resolved) = resolved else {
+      panic!("should resolve to a resource");
+    };
+    let imported = loader_context
+      .import_module("./value.js".to_string(), None, None)
+      .await?;
+    let mut imported_files = imported
+      .file_dependencies
+      .iter()
+      .map(|file| file.to_string_lossy().to_string())
+      .collect::<Vec<_>>();
+    imported_files.sort();
+    loader_context.content = Some(
+      format!(
+        "export default {};\nexport const imported = {};",
+        serde_json::json!(resolved.path),
+        serde_json::json!(imported_files)
+      )
+      .into(),
+    );
+    Ok(())
+  }
+}
+
+impl Identifiable for TestLoader {
+  fn identifier(&self) -> Identifier {
+    LOADER.into()
+  }
+}
+
+#[plugin]
+#[derive(Debug, Default)]
+struct TestLoaderPlugin;
+
+#[plugin_hook(NormalModuleFactoryResolveLoader for TestLoaderPlugin)]
+async fn resolve_loader(
+  &self,
+  _context: &Context,
+  _resolver: &Resolver,
+  l: &ModuleRuleUseLoader,
+) -> Result<Option<BoxLoader>> {
+  Ok((l.loader == LOADER).then(|| Arc::new(TestLoader) as BoxLoader))
+}
+
+impl Plugin for TestLoaderPlugin {
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .resolve_loader
+      .tap(resolve_loader::new(self));
+    Ok(())
+  }
+}
+
+/// Options where `@lib` is only resolved with the resolve options of the loader rule and `.mjs`
+/// only for esm dependencies.
+fn options() -> CompilerOptions {
+  let mut options = compiler_options();
+  options.resolve.by_dependency = Some(
+    [(
+      "esm".into(),
+      Resolve {
+        extensions: Some(vec![".mjs".to_string(), ".js".to_string()]),
+        ..Default::default()
+      },
+    )]
+    .into_iter()
+    .collect(),
+  );
+  options.module.rules.push(ModuleRule {
+    test: Some(RuleSetCondition::String(
+      "/project/src/data.txt".to_string(),
+    )),
+    r#use: ModuleRuleUse::Array(vec![ModuleRuleUseLoader {
+      loader: LOADER.to_string(),
+      options: None,
+    }]),
+    resolve: Some(Resolve {
+      alias: Some(vec![(
+        "@lib".to_string(),
+        vec![rspack_core::AliasMap::Path("/project/lib".to_string())],
+      )]),
+      ..Default::default()
+    }),
+    ..Default::default()
+  });
+  options
+}
+
+async fn build(options: CompilerOptions) -> Compiler<MemoryFileSystem> {
+  let mut plugins = javascript_plugins("./src/index.js");
+  plugins.push(TestLoaderPlugin::default().boxed());
+  if options.optimization.concatenate_modules {
+    plugins.push(FlagDependencyExportsPlugin::default().boxed());
+    plugins.push(FlagDependencyUsagePlugin::new(false).boxed());
+    plugins.push(ModuleConcatenationPlugin::default().boxed());
+  }
+  build_with_options(FILES, options, plugins)
+    .await
+    .expect("should build")
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn loader_context_apis() {
+  let compiler = build(options()).await;
+  let compilation = &compiler.compilation;
+  assert!(compilation.get_errors().next().is_none());
+
+  assert_eq!(asset_source(compilation, "emitted.txt"), "emitted");
+  assert!(compilation
+    .chunk_by_ukey
+    .values()
+    .any(|chunk| chunk.auxiliary_files.contains("emitted.txt")));
+
+  // resolved with the alias of the rule and the extensions of esm dependencies
+  let main = asset_source(compilation, "main.js");
+  assert!(main.contains(r#""/project/lib/dep.mjs""#));
+  assert!(main.contains(r#"["/project/src/value.js"]"#));
+
+  let file_dependencies = compilation.file_dependencies().collect::<Vec<_>>();
+  assert!(file_dependencies.contains(&&Path::new("/project/lib/dep.mjs").to_path_buf()));
+  assert!(file_dependencies.contains(&&Path::new("/project/src/value.js").to_path_buf()));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn loader_context_emit_file_concatenated() {
+  let mut options = options();
+  options.optimization.provided_exports = true;
+  options.optimization.used_exports = "global".into();
+  options.optimization.concatenate_modules = true;
+  let compiler = build(options).await;
+  let compilation = &compiler.compilation;
+  assert!(compilation.get_errors().next().is_none());
+  assert!(compilation.get_warnings().next().is_none());
+
+  let module_graph = compilation.get_module_graph();
+  assert!(module_graph
+    .modules()
+    .values()
+    .any(|module| module.as_concatenated_module().is_some()));
+  assert_eq!(asset_source(compilation, "emitted.txt"), "emitted");
+  assert!(compilation
+    .chunk_by_ukey
+    .values()
+    .any(|chunk| chunk.auxiliary_files.contains("emitted.txt")));
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use rspack_core::{
+  rspack_sources::SourceMap, ApplyContext, BoxLoader, CompilerOptions, Context, Loader,
+  LoaderContext, ModuleRule, ModuleRuleUse, ModuleRuleUseLoader, NormalModuleFactoryResolveLoader,
+  Plugin, PluginContext, PluginExt, Resolver, RuleSetCondition, RunnerContext,
+};
+use rspack_error::{miette::Severity, DiagnosticExt, Result, TraceableError};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_identifier::{Identifiable, Identifier};
+use rspack_testing::{build_with_options, compiler_options, javascript_plugins};
+
+const LOADER: &str = "strip-types-loader";
+
+const ORIGINAL: &str = "const a: number = 1;\nfoo(a);\n";
+
+const GENERATED: &str = "var a = 1;\nfoo(a);\n";
+
+/// Strips the type annotation of [ORIGINAL] and warns about the call of `foo` in its output.
+#[derive(Debug)]
+struct StripTypesLoader;
+
+#[async_trait::async_trait]
+impl Loader<RunnerContext> for StripTypesLoader {
+  async fn run(&self, loader_context: &mut LoaderContext<RunnerContext>) -> Result<()> {
+    let source_map = SourceMap::from_json(
+      &serde_json::json!({
+        "version": 3,
+        "sources": ["data.js"],
+        "sourcesContent": [ORIGINAL],
+        "names": [],
+        "mappings": "AAAA;AACA",
+      })
+      .to_string(),
+    )
+    .expect("should be a valid source map");
+    let start = GENERATED.find("foo").expect("should call foo");
+    loader_context.emit_diagnostic(
+      TraceableError::from_file(
+        GENERATED.to_string(),
+        start,
+        start + "foo".len(),
+        "Loader warning".to_string(),
+        "foo is deprecated".to_string(),
+      )
+      .with_severity(Severity::Warning)
+      .boxed()
+      .into(),
+    );
+    loader_context.content = Some(GENERATED.to_string().into());
+    loader_context.source_map = Some(source_map);
+    Ok(())
+  }
+}
+
+impl Identifiable for StripTypesLoader {
+  fn identifier(&self) -> Identifier {
+    LOADER.into()
+  }
+}
+
+#[plugin]
+#[derive(Debug, Default)]
+struct StripTypesLoaderPlugin;
+
+#[plugin_hook(NormalModuleFactoryResolveLoader for StripTypesLoaderPlugin)]
+async fn resolve_loader(
+  &self,
+  _context: &Context,
+  _resolver: &Resolver,
+  l: &ModuleRuleUseLoader,
+) -> Result<Option<BoxLoader>> {
+  Ok((l.loader == LOADER).then(|| Arc::new(StripTypesLoader) as BoxLoader))
+}
+
+impl Plugin for StripTypesLoaderPlugin {
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .resolve_loader
+      .tap(resolve_loader::new(self));
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn loader_diagnostics_are_mapped_to_original_source() {
+  let mut options = compiler_options();
+  options.module.rules.push(ModuleRule {
+    test: Some(RuleSetCondition::String("/project/src/data.js".to_string())),
+    r#use: ModuleRuleUse::Array(vec![ModuleRuleUseLoader {
+      loader: LOADER.to_string(),
+      options: None,
+    }]),
+    ..Default::default()
+  });
+  let mut plugins = javascript_plugins("./src/index.js");
+  plugins.push(StripTypesLoaderPlugin::default().boxed());
+  let compiler = build_with_options(
+    &[
+      ("src/index.js", "import './data.js';"),
+      ("src/data.js", ORIGINAL),
+    ],
+    options,
+    plugins,
+  )
+  .await
+  .expect("should build");
+
+  let warnings = compiler.compilation.get_warnings().collect::<Vec<_>>();
+  assert_eq!(warnings.len(), 1);
+  let warning = warnings[0]
+    .traceable_error()
+    .expect("should have a traceable error");
+  // the code frame shows the source with the type annotation
+  assert_eq!(warning.src(), ORIGINAL);
+  let start = ORIGINAL.find("foo").expect("should call foo");
+  assert_eq!(warning.span(), (start, start + "foo".len()));
+}
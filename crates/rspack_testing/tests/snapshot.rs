@@ -0,0 +1,41 @@
+use std::path::{Path, PathBuf};
+
+use rspack_core::{retain_changed_files, Compiler, SnapshotStrategy};
+use rspack_fs::{MemoryFileSystem, WritableFileSystem};
+use rspack_testing::{compiler_options, javascript_plugins, memory_fs, CONTEXT};
+use rustc_hash::FxHashSet as HashSet;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn retain_files_whose_content_changed() {
+  let input_filesystem = memory_fs(&[
+    (
+      "src/index.js",
+      "import { a } from './a.js';\nconsole.log(a);",
+    ),
+    ("src/a.js", "export const a = 1;"),
+  ]);
+  let mut options = compiler_options();
+  options.snapshot.module = SnapshotStrategy {
+    hash: true,
+    timestamp: false,
+  };
+  let mut compiler = Compiler::new(
+    options,
+    javascript_plugins("./src/index.js"),
+    MemoryFileSystem::new(),
+    input_filesystem.clone(),
+    None,
+    None,
+  );
+  compiler.build().await.unwrap();
+
+  let index = Path::new(CONTEXT).join("src/index.js");
+  let a = Path::new(CONTEXT).join("src/a.js");
+  let unknown = Path::new(CONTEXT).join("src/unknown.js");
+  input_filesystem.write(&a, b"export const a = 2;").unwrap();
+  let mut files: HashSet<PathBuf> = [index, a.clone(), unknown.clone()].into_iter().collect();
+  retain_changed_files(&compiler.compilation, &mut files).await;
+
+  let expected: HashSet<PathBuf> = [a, unknown].into_iter().collect();
+  assert_eq!(files, expected);
+}
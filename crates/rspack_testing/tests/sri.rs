@@ -0,0 +1,115 @@
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  CrossOriginLoading, PluginExt,
+};
+use rspack_plugin_devtool::{
+  SourceMapDevToolModuleOptionsPlugin, SourceMapDevToolModuleOptionsPluginOptions,
+  SourceMapDevToolPlugin, SourceMapDevToolPluginOptions,
+};
+use rspack_plugin_html::{
+  config::HtmlRspackPluginOptions,
+  sri::{create_digest_from_asset, HtmlSriHashFunction},
+  HtmlRspackPlugin,
+};
+use rspack_plugin_real_content_hash::RealContentHashPlugin;
+use rspack_plugin_sri::{SubresourceIntegrityPlugin, SubresourceIntegrityPluginOptions};
+use rspack_testing::{build_with_options, compiler_options, javascript_plugins, read_output};
+
+fn integrity(content: &str) -> String {
+  create_digest_from_asset(
+    &HtmlSriHashFunction::Sha384,
+    &RawSource::from(content.to_string()).boxed(),
+  )
+}
+
+const FILES: &[(&str, &str)] = &[
+  (
+    "src/index.js",
+    r#"import(/* webpackChunkName: "async" */ "./async.js").then(m => console.log(m.default));"#,
+  ),
+  ("src/async.js", "export default 'async';"),
+];
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn html_integrity_matches_the_final_runtime_chunk() {
+  let mut options = compiler_options();
+  options.output.cross_origin_loading = CrossOriginLoading::Enable("anonymous".to_string());
+  let mut plugins = javascript_plugins("./src/index.js");
+  plugins.push(
+    HtmlRspackPlugin::new(HtmlRspackPluginOptions {
+      sri: Some(HtmlSriHashFunction::Sha384),
+      ..Default::default()
+    })
+    .boxed(),
+  );
+  plugins
+    .push(SubresourceIntegrityPlugin::new(SubresourceIntegrityPluginOptions::default()).boxed());
+  let compiler = build_with_options(FILES, options, plugins)
+    .await
+    .expect("should build");
+
+  let main = read_output(&compiler, "main.js");
+  let async_chunk = read_output(&compiler, "async.js");
+  assert!(!main.contains("__rspack_sri_"), "{main}");
+  assert!(main.contains(&integrity(&async_chunk)), "{main}");
+
+  let html = read_output(&compiler, "index.html");
+  assert!(
+    html.contains(&format!(r#"integrity="{}""#, integrity(&main))),
+    "{html}"
+  );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn integrity_matches_the_chunk_after_real_content_hash() {
+  let mut options = compiler_options();
+  options.output.cross_origin_loading = CrossOriginLoading::Enable("anonymous".to_string());
+  options.output.chunk_filename = "[name].[contenthash].js".to_string().into();
+  let mut plugins = javascript_plugins("./src/index.js");
+  // the `sourceMappingURL` of the async chunk contains its own content hash, which is replaced
+  // by the real content hash
+  plugins.push(
+    SourceMapDevToolModuleOptionsPlugin::new(SourceMapDevToolModuleOptionsPluginOptions {
+      module: true,
+      cheap: false,
+    })
+    .boxed(),
+  );
+  plugins.push(
+    SourceMapDevToolPlugin::new(SourceMapDevToolPluginOptions {
+      append: None,
+      columns: true,
+      fallback_module_filename_template: None,
+      file_context: None,
+      filename: Some("[file].map".to_string()),
+      module: true,
+      module_filename_template: None,
+      namespace: None,
+      no_sources: false,
+      public_path: None,
+      source_root: None,
+      test: None,
+    })
+    .boxed(),
+  );
+  plugins.push(RealContentHashPlugin::default().boxed());
+  plugins
+    .push(SubresourceIntegrityPlugin::new(SubresourceIntegrityPluginOptions::default()).boxed());
+  let compiler = build_with_options(FILES, options, plugins)
+    .await
+    .expect("should build");
+
+  let compilation = &compiler.compilation;
+  let async_file = compilation
+    .assets()
+    .keys()
+    .find(|name| name.starts_with("async.") && name.ends_with(".js"))
+    .expect("should have the async chunk");
+  let async_chunk = read_output(&compiler, async_file);
+  assert!(
+    async_chunk.contains(&format!("sourceMappingURL={async_file}.map")),
+    "{async_chunk}"
+  );
+  let main = read_output(&compiler, "main.js");
+  assert!(main.contains(&integrity(&async_chunk)), "{main}");
+}
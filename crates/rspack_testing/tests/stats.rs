@@ -0,0 +1,141 @@
+use std::time::Duration;
+
+use rspack_core::{StatsOptions, StatsPreset};
+use rspack_testing::build;
+use serde_json::{json, Value};
+
+const FILES: &[(&str, &str)] = &[
+  (
+    "src/index.js",
+    r#"import value from "./value.js";
+import(/* webpackChunkName: "lazy" */ "./lazy.js");
+console.log(value);"#,
+  ),
+  ("src/value.js", "export default 'value';"),
+  ("src/lazy.js", "console.log('lazy');"),
+];
+
+async fn stats_json(options: &StatsOptions) -> Value {
+  let compiler = build(FILES, "./src/index.js", vec![])
+    .await
+    .expect("should build");
+  compiler
+    .compilation
+    .get_stats()
+    .to_json(options)
+    .expect("should serialize stats")
+}
+
+fn find<'a>(values: &'a Value, key: &str, expected: &Value) -> &'a Value {
+  values
+    .as_array()
+    .expect("should be an array")
+    .iter()
+    .find(|value| &value[key] == expected)
+    .unwrap_or_else(|| panic!("should have an item with {key} {expected}"))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn stats_json_webpack_fields() {
+  let stats = stats_json(&StatsOptions::default()).await;
+
+  assert!(stats["hash"].is_string());
+  assert_eq!(stats["publicPath"], json!(""));
+  assert_eq!(stats["outputPath"], json!("/project/dist"));
+  assert_eq!(stats["errorsCount"], json!(0));
+  assert_eq!(stats["warningsCount"], json!(0));
+  assert_eq!(stats["errors"], json!([]));
+
+  assert_eq!(stats["assetsByChunkName"]["main"], json!(["main.js"]));
+  assert_eq!(stats["assetsByChunkName"]["lazy"], json!(["lazy.js"]));
+  let main_asset = find(&stats["assets"], "name", &json!("main.js"));
+  assert_eq!(main_asset["type"], json!("asset"));
+  assert_eq!(main_asset["chunkNames"], json!(["main"]));
+  assert!(main_asset["size"].as_f64().is_some_and(|size| size > 0.0));
+  assert!(main_asset["info"]["development"].is_boolean());
+
+  let main_chunk = find(&stats["chunks"], "names", &json!(["main"]));
+  assert_eq!(main_chunk["entry"], json!(true));
+  assert_eq!(main_chunk["initial"], json!(true));
+  assert_eq!(main_chunk["files"], json!(["main.js"]));
+  let lazy_chunk = find(&stats["chunks"], "names", &json!(["lazy"]));
+  assert_eq!(lazy_chunk["initial"], json!(false));
+  assert_eq!(lazy_chunk["parents"], json!([main_chunk["id"]]));
+  let lazy_origin = &lazy_chunk["origins"][0];
+  assert_eq!(lazy_origin["moduleName"], json!("./src/index.js"));
+  assert_eq!(lazy_origin["request"], json!("./lazy.js"));
+
+  let value_module = find(&stats["modules"], "name", &json!("./src/value.js"));
+  assert_eq!(value_module["moduleType"], json!("javascript/auto"));
+  assert_eq!(value_module["issuerName"], json!("./src/index.js"));
+  assert_eq!(value_module["chunks"], json!([main_chunk["id"]]));
+  assert!(value_module["sizes"]["javascript"].is_number());
+  let reason = find(
+    &value_module["reasons"],
+    "userRequest",
+    &json!("./value.js"),
+  );
+  assert_eq!(reason["moduleName"], json!("./src/index.js"));
+  assert_eq!(reason["type"], json!("esm import"));
+
+  assert_eq!(stats["entrypoints"]["main"]["name"], json!("main"));
+  assert_eq!(
+    stats["entrypoints"]["main"]["assets"],
+    json!([{ "name": "main.js", "size": main_asset["size"] }])
+  );
+  assert_eq!(
+    stats["namedChunkGroups"]["lazy"]["chunks"],
+    json!([lazy_chunk["id"]])
+  );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn stats_json_presets() {
+  let stats = stats_json(&StatsOptions::from_preset(StatsPreset::ErrorsOnly)).await;
+  assert_eq!(stats, json!({ "errors": [], "errorsCount": 0 }));
+
+  let stats = stats_json(&StatsOptions::from_preset(StatsPreset::Verbose)).await;
+  assert!(stats.get("modules").is_none());
+  let main_chunk = find(&stats["chunks"], "names", &json!(["main"]));
+  find(&main_chunk["modules"], "name", &json!("./src/value.js"));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn stats_text() {
+  let compiler = build(FILES, "./src/index.js", vec![])
+    .await
+    .expect("should build");
+  let stats = compiler.compilation.get_stats();
+
+  let text = stats
+    .to_text(&StatsOptions::default(), Some(Duration::from_millis(42)))
+    .expect("should print stats");
+  let lines = text.lines().collect::<Vec<_>>();
+  assert!(lines
+    .iter()
+    .any(|line| line.starts_with("asset main.js ") && line.ends_with(" [emitted] (name: main)")));
+  assert!(lines
+    .iter()
+    .any(|line| line.starts_with("Entrypoint main ") && line.ends_with(" = main.js")));
+  assert!(lines.contains(&"chunk (runtime: main) main.js (main) 126 bytes [entry] [rendered]"));
+  assert!(lines.contains(&"  ./src/value.js 23 bytes [dependent] [built] [code generated]"));
+  assert!(lines.contains(&"./src/lazy.js 20 bytes [built] [code generated]"));
+  assert!(!text.contains("[]"));
+  assert!(text.ends_with("\nRspack compiled successfully in 42 ms\n"));
+
+  let colored = stats
+    .to_text(
+      &StatsOptions {
+        colors: true,
+        ..Default::default()
+      },
+      None,
+    )
+    .expect("should print stats");
+  assert!(colored.contains("\u{1b}["));
+
+  let errors_only = stats
+    .to_text(&StatsOptions::from_preset(StatsPreset::ErrorsOnly), None)
+    .expect("should print stats");
+  assert_eq!(errors_only, "\nRspack compiled successfully\n");
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use rspack_core::{Compiler, WatchOptions, Watching};
+use rspack_fs::{AsyncNativeFileSystem, MemoryFileSystem};
+use rspack_testing::{asset_source, compiler_options, javascript_plugins};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn rebuild_when_a_file_dependency_changes() {
+  let root = std::env::temp_dir().join(format!("rspack-testing-watching-{}", std::process::id()));
+  let src = root.join("src");
+  std::fs::create_dir_all(&src).unwrap();
+  let index = src.join("index.js");
+  let a = src.join("a.js");
+  std::fs::write(&index, "import { a } from './a.js';\nconsole.log(a);").unwrap();
+  std::fs::write(&a, "export const a = 'first build';").unwrap();
+
+  let mut options = compiler_options();
+  options.context = root.to_string_lossy().to_string().into();
+  options.output.path = root.join("dist");
+  let compiler = Compiler::new(
+    options,
+    javascript_plugins(&index.to_string_lossy()),
+    MemoryFileSystem::new(),
+    Arc::new(AsyncNativeFileSystem),
+    None,
+    None,
+  );
+  let mut watching = Watching::new(
+    compiler,
+    WatchOptions {
+      aggregate_timeout: 10,
+      ..Default::default()
+    },
+  )
+  .unwrap();
+  let handle = watching.handle();
+
+  let mut outputs = vec![];
+  let watch = watching.watch(|result, compilation| {
+    result.unwrap();
+    outputs.push(asset_source(compilation, "main.js"));
+    if outputs.len() == 1 {
+      // the dependencies are watched once the handler returns
+      let a = a.clone();
+      std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(200));
+        std::fs::write(a, "export const a = 'second build';").unwrap();
+      });
+    } else {
+      handle.close();
+    }
+  });
+  tokio::time::timeout(Duration::from_secs(30), watch)
+    .await
+    .expect("should rebuild after the change")
+    .unwrap();
+
+  assert_eq!(outputs.len(), 2);
+  assert!(outputs[0].contains("'first build'"));
+  assert!(outputs[1].contains("'second build'"));
+  let _ = std::fs::remove_dir_all(root);
+}
@@ -1,3 +1,5 @@
+/// Formats a size in bytes the same way as webpack's `formatSize`, the value is rounded to 3
+/// significant digits like `+value.toPrecision(3)`.
 pub fn format_size(size: f64) -> String {
   if !size.is_finite() {
     return String::from("unknown size");
@@ -15,8 +17,37 @@ pub fn format_size(size: f64) -> String {
   }
 
   format!(
-    "{:.3} {}",
-    size / 1024.0_f64.powf(index as f64),
+    "{} {}",
+    to_precision_3(size / 1024.0_f64.powf(index as f64)),
     abbreviations[index]
   )
 }
+
+fn to_precision_3(value: f64) -> f64 {
+  let exponent = value.log10().floor() as i32;
+  if exponent <= 2 {
+    let factor = 10.0_f64.powi(2 - exponent);
+    (value * factor).round() / factor
+  } else {
+    let factor = 10.0_f64.powi(exponent - 2);
+    (value / factor).round() * factor
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_format_size() {
+    assert_eq!(format_size(0.0), "0 bytes");
+    assert_eq!(format_size(300.0), "300 bytes");
+    assert_eq!(format_size(1000.0), "1000 bytes");
+    assert_eq!(format_size(1229.0), "1.2 KiB");
+    assert_eq!(format_size(1500.0), "1.46 KiB");
+    assert_eq!(format_size(250000.0), "244 KiB");
+    assert_eq!(format_size(1023.0 * 1024.0), "1020 KiB");
+    assert_eq!(format_size(3.5 * 1024.0 * 1024.0), "3.5 MiB");
+    assert_eq!(format_size(f64::NAN), "unknown size");
+  }
+}
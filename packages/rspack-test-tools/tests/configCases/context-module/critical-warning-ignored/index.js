@@ -0,0 +1,12 @@
+import { load } from "./other";
+
+it("should only ignore the critical warnings of the index module", async () => {
+  const n = "a"
+  const { default: a } = await import(`./sub/${n}`);
+  expect(a).toBe("a")
+  try {
+    await load();
+  } catch (e) {
+    expect(e.code).toBe("MODULE_NOT_FOUND");
+  }
+})
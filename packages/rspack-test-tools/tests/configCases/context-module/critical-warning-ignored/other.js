@@ -0,0 +1,2 @@
+const n = "./sub/a.js";
+export const load = () => import(n);
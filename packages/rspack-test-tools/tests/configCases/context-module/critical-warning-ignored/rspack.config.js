@@ -0,0 +1,15 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+  module: {
+    parser: {
+      javascript: {
+        exprContextCritical: true,
+        wrappedContextCritical: true,
+      }
+    }
+  },
+  ignoreWarnings: [
+    { code: "export-not-found" },
+    { code: "critical-dependency", module: /index\.js$/ },
+  ],
+}
@@ -0,0 +1,3 @@
+module.exports = [
+  /Critical dependency: the request of a dependency is an expression/,
+]
@@ -0,0 +1,28 @@
+import * as styles from "./index.module.css";
+const fs = require("fs");
+const path = require("path");
+
+it("should export the values of @value and :export", () => {
+	expect(styles).toEqual(
+		nsObj({
+			primary: "#BF4040",
+			mobile: "(max-width: 599px)",
+			border: "1px solid #BF4040",
+			accent: "#BF4040",
+			button: "./index.module-button"
+		})
+	);
+});
+
+it("should replace the values in the css", async () => {
+	const css = await fs.promises.readFile(
+		path.resolve(__dirname, "bundle0.css"),
+		"utf-8"
+	);
+	const content = css.replace(/\s+/g, "");
+
+	expect(content).toContain("{color:#BF4040;border:1pxsolid#BF4040;}");
+	expect(content).toContain("@media(max-width:599px){");
+	expect(content).toContain("{background:#BF4040;}");
+	expect(content).not.toContain("@value");
+});
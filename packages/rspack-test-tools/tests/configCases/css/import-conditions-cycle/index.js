@@ -0,0 +1,15 @@
+require("./index.css");
+const fs = require("fs");
+const path = require("path");
+
+it("should skip the conditions of cyclic @import", async () => {
+	const css = await fs.promises.readFile(
+		path.resolve(__dirname, "bundle0.css"),
+		"utf-8"
+	);
+	const content = css.replace(/\s+/g, "");
+
+	expect(content).not.toContain("@mediaprint");
+	expect(content.match(/\.index\{color:red;\}/g)).toHaveLength(1);
+	expect(content.match(/\.a\{color:green;\}/g)).toHaveLength(1);
+});
@@ -0,0 +1,17 @@
+require("./index.css");
+const fs = require("fs");
+const path = require("path");
+
+it("should keep the conditions of @import", async () => {
+	const css = await fs.promises.readFile(
+		path.resolve(__dirname, "bundle0.css"),
+		"utf-8"
+	);
+	const content = css.replace(/\s+/g, "");
+
+	expect(content).toContain(
+		"@mediascreenand(min-width:600px){@supports(display:grid){@layerbase{.a{color:green;}}}}"
+	);
+	expect(content).toContain("@mediaprint{.b{color:blue;}}");
+	expect(content.match(/\.b\{color:blue;\}/g)).toHaveLength(2);
+});
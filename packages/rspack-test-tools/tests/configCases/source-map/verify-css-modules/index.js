@@ -0,0 +1,15 @@
+const fs = require("fs");
+const path = require("path");
+
+require("./style.module.css");
+
+it("verify css modules source map", () => {
+	const source = fs.readFileSync(
+		path.resolve(__dirname, "bundle0.css.map"),
+		"utf-8"
+	);
+	const map = JSON.parse(source);
+	expect(map.sources).toEqual(["webpack:///./style.module.css"]);
+	expect(map.file).toEqual("bundle0.css");
+	expect(map.names).toEqual(expect.arrayContaining(["foo"]));
+});
@@ -0,0 +1,11 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		generator: {
+			"css/auto": {
+				exportsOnly: false
+			}
+		}
+	},
+	devtool: "source-map"
+};
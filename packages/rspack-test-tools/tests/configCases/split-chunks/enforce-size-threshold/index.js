@@ -0,0 +1,11 @@
+import fs from "fs";
+import path from "path";
+import enforced from "./enforced";
+import limited from "./limited";
+
+it("should ignore maxInitialRequests when the size reaches enforceSizeThreshold", () => {
+	expect(enforced).toBe("enforced.js");
+	expect(limited).toBe("limited.js");
+	expect(fs.existsSync(path.resolve(__dirname, "./enforced.js"))).toBe(true);
+	expect(fs.existsSync(path.resolve(__dirname, "./limited.js"))).toBe(false);
+});
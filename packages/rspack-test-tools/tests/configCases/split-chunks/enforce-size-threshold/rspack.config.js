@@ -0,0 +1,27 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	entry: "./index.js",
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		splitChunks: {
+			chunks: "all",
+			maxInitialRequests: 1,
+			cacheGroups: {
+				enforced: {
+					test: /enforced\.js/,
+					name: "enforced",
+					minSize: 0,
+					enforceSizeThreshold: 10
+				},
+				limited: {
+					test: /limited\.js/,
+					name: "limited",
+					minSize: 0
+				}
+			}
+		}
+	}
+};
@@ -0,0 +1,4 @@
+import shared from "./shared";
+import large from "./large";
+import other from "./other";
+export default [shared, large.length, other];
@@ -0,0 +1,10 @@
+import fs from "fs";
+import path from "path";
+
+it("should not split modules when the remaining size of the chunk is smaller than minRemainingSize", async () => {
+	const { default: a } = await import("./a");
+	expect(a).toEqual(["shared.js", 1200, "other.js"]);
+	expect(fs.existsSync(path.resolve(__dirname, "./shared.js"))).toBe(false);
+	expect(fs.existsSync(path.resolve(__dirname, "./large.js"))).toBe(false);
+	expect(fs.existsSync(path.resolve(__dirname, "./other.js"))).toBe(true);
+});
@@ -0,0 +1 @@
+export default "large.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.jslarge.js";
@@ -0,0 +1,31 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	entry: "./index.js",
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				shared: {
+					test: /shared\.js/,
+					name: "shared",
+					minSize: 0,
+					minRemainingSize: 100000
+				},
+				// minRemainingSize defaults to minSize
+				large: {
+					test: /large\.js/,
+					name: "large",
+					minSize: 1000
+				},
+				other: {
+					test: /other\.js/,
+					name: "other",
+					minSize: 0
+				}
+			}
+		}
+	}
+};
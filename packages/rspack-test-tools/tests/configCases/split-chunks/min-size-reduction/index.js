@@ -0,0 +1,9 @@
+import fs from "fs";
+import path from "path";
+
+it("should not split the shared module when the size reduction is too small", async () => {
+	const [a, b] = await Promise.all([import("./a"), import("./b")]);
+	expect(a.default).toBe("a.jsshared.js");
+	expect(b.default).toBe("b.jsshared.js");
+	expect(fs.existsSync(path.resolve(__dirname, "./shared.js"))).toBe(false);
+});
@@ -0,0 +1,20 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	entry: "./index.js",
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				shared: {
+					test: /shared\.js/,
+					name: "shared",
+					minSize: 0,
+					minSizeReduction: 100000
+				}
+			}
+		}
+	}
+};
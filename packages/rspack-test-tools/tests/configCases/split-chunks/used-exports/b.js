@@ -0,0 +1,8 @@
+import fs from "fs";
+import { b } from "./shared";
+
+it("should not split the shared module used differently in the entries", () => {
+	expect(b).toBe("b");
+	const files = fs.readdirSync(__dirname).filter(file => file.endsWith(".js"));
+	expect(files.sort()).toEqual(["a.js", "b.js"]);
+});
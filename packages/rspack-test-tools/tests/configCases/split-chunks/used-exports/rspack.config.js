@@ -0,0 +1,27 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	entry: {
+		a: "./a.js",
+		b: "./b.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		usedExports: true,
+		splitChunks: {
+			chunks: "all",
+			minSize: 0,
+			usedExports: true,
+			cacheGroups: {
+				default: false,
+				defaultVendors: false,
+				shared: {
+					test: /shared\.js/,
+					minChunks: 2
+				}
+			}
+		}
+	}
+};
@@ -11,7 +11,7 @@ const htmlRspackPluginOptions = z.strictObject({
 	filename: z.string().optional(),
 	template: z.string().optional(),
 	templateContent: z.string().optional(),
-	templateParameters: z.record(z.string()).optional(),
+	templateParameters: z.record(z.any()).optional(),
 	inject: z.enum(["head", "body"]).or(z.boolean()).optional(),
 	publicPath: z.string().optional(),
 	scriptLoading: z.enum(["blocking", "defer", "module"]).optional(),
@@ -21,7 +21,13 @@ const htmlRspackPluginOptions = z.strictObject({
 	minify: z.boolean().optional(),
 	title: z.string().optional(),
 	favicon: z.string().optional(),
-	meta: z.record(z.string().or(z.record(z.string()))).optional()
+	meta: z.record(z.string().or(z.record(z.string()))).optional(),
+	resourceHints: z
+		.strictObject({
+			initial: z.boolean().optional(),
+			asyncChunks: z.boolean().optional()
+		})
+		.optional()
 });
 export type HtmlRspackPluginOptions = z.infer<typeof htmlRspackPluginOptions>;
 export const HtmlRspackPlugin = create(
@@ -17,6 +17,7 @@ export const ProgressPlugin = create(
 			progress.template ??
 			"● {prefix:.bold} {bar:25.green/white.dim} ({percent}%) {wide_msg:.dim}",
 		tick: progress.tick,
-		progressChars: progress.progressChars ?? "━━"
+		progressChars: progress.progressChars ?? "━━",
+		handler: progress.handler
 	})
 );
@@ -103,6 +103,9 @@ function toRawSplitChunksOptions(
 		cacheGroups = {},
 		fallbackCacheGroup,
 		minSize,
+		minSizeReduction,
+		enforceSizeThreshold,
+		minRemainingSize,
 		maxSize,
 		maxAsyncSize,
 		maxInitialSize,
@@ -123,6 +126,9 @@ function toRawSplitChunksOptions(
 					name,
 					chunks,
 					minSize,
+					minSizeReduction,
+					enforceSizeThreshold,
+					minRemainingSize,
 					maxSize,
 					maxAsyncSize,
 					maxInitialSize,
@@ -134,6 +140,10 @@ function toRawSplitChunksOptions(
 					name: getName(name),
 					chunks: getChunks(chunks),
 					minSize: JsSplitChunkSizes.__to_binding(minSize),
+					minSizeReduction: JsSplitChunkSizes.__to_binding(minSizeReduction),
+					enforceSizeThreshold:
+						JsSplitChunkSizes.__to_binding(enforceSizeThreshold),
+					minRemainingSize: JsSplitChunkSizes.__to_binding(minRemainingSize),
 					maxSize: JsSplitChunkSizes.__to_binding(maxSize),
 					maxAsyncSize: JsSplitChunkSizes.__to_binding(maxAsyncSize),
 					maxInitialSize: JsSplitChunkSizes.__to_binding(maxInitialSize),
@@ -146,6 +156,9 @@ function toRawSplitChunksOptions(
 			...fallbackCacheGroup
 		},
 		minSize: JsSplitChunkSizes.__to_binding(minSize),
+		minSizeReduction: JsSplitChunkSizes.__to_binding(minSizeReduction),
+		enforceSizeThreshold: JsSplitChunkSizes.__to_binding(enforceSizeThreshold),
+		minRemainingSize: JsSplitChunkSizes.__to_binding(minRemainingSize),
 		maxSize: JsSplitChunkSizes.__to_binding(maxSize),
 		maxAsyncSize: JsSplitChunkSizes.__to_binding(maxAsyncSize),
 		maxInitialSize: JsSplitChunkSizes.__to_binding(maxInitialSize),
@@ -0,0 +1,19 @@
+import {
+	BuiltinPluginName,
+	type RawSubresourceIntegrityPluginOptions
+} from "@rspack/binding";
+
+import { create } from "./base";
+
+export type SubresourceIntegrityPluginOptions = {
+	hashFuncNames?: ("sha256" | "sha384" | "sha512")[];
+};
+
+export const SubresourceIntegrityPlugin = create(
+	BuiltinPluginName.SubresourceIntegrityPlugin,
+	(
+		options: SubresourceIntegrityPluginOptions = {}
+	): RawSubresourceIntegrityPluginOptions => {
+		return { hashFuncNames: options.hashFuncNames ?? ["sha384"] };
+	}
+);
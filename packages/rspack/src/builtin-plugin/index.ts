@@ -55,6 +55,7 @@ export * from "./RuntimeChunkPlugin";
 export * from "./RuntimePlugin";
 export * from "./SideEffectsFlagPlugin";
 export * from "./SizeLimitsPlugin";
+export * from "./SubresourceIntegrityPlugin";
 export * from "./SourceMapDevToolPlugin";
 export * from "./SplitChunksPlugin";
 export * from "./SwcCssMinimizerPlugin";
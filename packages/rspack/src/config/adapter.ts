@@ -39,6 +39,7 @@ import {
 } from "./adapterRuleUse";
 import type {
 	ExperimentsNormalized,
+	IgnoreWarningsNormalized,
 	ModuleOptionsNormalized,
 	OutputNormalized,
 	RspackOptionsNormalized
@@ -56,6 +57,7 @@ import type {
 	CssGeneratorOptions,
 	CssParserOptions,
 	GeneratorOptionsByModuleType,
+	IgnoreWarningsRule,
 	JavascriptParserOptions,
 	LibraryName,
 	LibraryOptions,
@@ -121,6 +123,7 @@ export const getRawOptions = (
 		profile: options.profile!,
 		// SAFETY: applied default value in `applyRspackOptionsDefaults`.
 		bail: options.bail!,
+		ignoreWarnings: getRawIgnoreWarnings(options.ignoreWarnings),
 		__references: {}
 	};
 };
@@ -856,9 +859,24 @@ function getRawOptimization(
 }
 
 function getRawSnapshotOptions(
-	_snapshot: SnapshotOptions
+	snapshot: SnapshotOptions
 ): RawOptions["snapshot"] {
-	return {};
+	const { managedPaths, immutablePaths, module } = snapshot;
+	return {
+		managedPaths,
+		immutablePaths,
+		module
+	};
+}
+
+function getRawIgnoreWarnings(
+	ignoreWarnings: IgnoreWarningsNormalized | undefined
+): RawOptions["ignoreWarnings"] {
+	return (ignoreWarnings ?? [])
+		.filter(
+			(ignore): ignore is IgnoreWarningsRule => typeof ignore !== "function"
+		)
+		.map(({ code, module, message }) => ({ code, module, message }));
 }
 
 function getRawExperiments(
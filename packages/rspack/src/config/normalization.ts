@@ -46,6 +46,7 @@ import type {
 	HotUpdateChunkFilename,
 	HotUpdateGlobal,
 	HotUpdateMainFilename,
+	IgnoreWarningsRule,
 	Iife,
 	ImportFunctionName,
 	InfrastructureLogging,
@@ -91,12 +92,13 @@ export const getNormalizedRspackOptions = (
 		ignoreWarnings:
 			config.ignoreWarnings !== undefined
 				? config.ignoreWarnings.map(ignore => {
-						if (typeof ignore === "function") {
-							return ignore;
-						} else {
+						if (ignore instanceof RegExp) {
 							return (warning: Error) => {
 								return ignore.test(warning.message);
 							};
+						} else {
+							// rules are applied to the coded diagnostics in rust
+							return ignore;
 						}
 					})
 				: undefined,
@@ -256,7 +258,11 @@ export const getNormalizedRspackOptions = (
 				}
 		),
 		loader: cloneObject(config.loader),
-		snapshot: nestedConfig(config.snapshot, _snapshot => ({})),
+		snapshot: nestedConfig(config.snapshot, snapshot => ({
+			managedPaths: optionalNestedArray(snapshot.managedPaths, p => [...p]),
+			immutablePaths: optionalNestedArray(snapshot.immutablePaths, p => [...p]),
+			module: optionalNestedConfig(snapshot.module, module => ({ ...module }))
+		})),
 		cache: optionalNestedConfig(config.cache, cache => cache),
 		stats: nestedConfig(config.stats, stats => {
 			if (stats === false) {
@@ -514,10 +520,10 @@ export interface ExperimentsNormalized {
 	rspackFuture?: RspackFutureOptions;
 }
 
-export type IgnoreWarningsNormalized = ((
-	warning: Error,
-	compilation: Compilation
-) => boolean)[];
+export type IgnoreWarningsNormalized = (
+	| ((warning: Error, compilation: Compilation) => boolean)
+	| IgnoreWarningsRule
+)[];
 
 export type OptimizationRuntimeChunkNormalized =
 	| false
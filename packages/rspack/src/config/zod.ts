@@ -287,7 +287,15 @@ export type HashDigest = z.infer<typeof hashDigest>;
 const hashDigestLength = z.number();
 export type HashDigestLength = z.infer<typeof hashDigestLength>;
 
-const hashFunction = z.enum(["md4", "xxhash64"]);
+const hashFunction = z.enum([
+	"md4",
+	"xxhash64",
+	"sha256",
+	"sha512",
+	"xxhash3",
+	"xxhash128",
+	"blake3"
+]);
 export type HashFunction = z.infer<typeof hashFunction>;
 
 const hashSalt = z.string();
@@ -1008,7 +1016,20 @@ export type Loader = z.infer<typeof loader>;
 //#endregion
 
 //#region Snapshot
-const snapshotOptions = z.strictObject({});
+const snapshotPaths = z.array(z.string().or(z.instanceof(RegExp)));
+export type SnapshotPaths = z.infer<typeof snapshotPaths>;
+
+const snapshotStrategy = z.strictObject({
+	hash: z.boolean().optional(),
+	timestamp: z.boolean().optional()
+});
+export type SnapshotStrategy = z.infer<typeof snapshotStrategy>;
+
+const snapshotOptions = z.strictObject({
+	managedPaths: snapshotPaths.optional(),
+	immutablePaths: snapshotPaths.optional(),
+	module: snapshotStrategy.optional()
+});
 export type SnapshotOptions = z.infer<typeof snapshotOptions>;
 //#endregion
 
@@ -1197,10 +1218,14 @@ const sharedOptimizationSplitChunksCacheGroup = {
 	minChunks: z.number().min(1).optional(),
 	name: optimizationSplitChunksName.optional(),
 	minSize: optimizationSplitChunksSizes.optional(),
+	minSizeReduction: optimizationSplitChunksSizes.optional(),
+	enforceSizeThreshold: optimizationSplitChunksSizes.optional(),
+	minRemainingSize: optimizationSplitChunksSizes.optional(),
 	maxSize: optimizationSplitChunksSizes.optional(),
 	maxAsyncSize: optimizationSplitChunksSizes.optional(),
 	maxInitialSize: optimizationSplitChunksSizes.optional(),
-	automaticNameDelimiter: z.string().optional()
+	automaticNameDelimiter: z.string().optional(),
+	usedExports: z.boolean().optional()
 };
 const optimizationSplitChunksCacheGroup = z.strictObject({
 	test: z
@@ -1334,8 +1359,16 @@ const devServer = z.custom<DevServer>();
 //#endregion
 
 //#region IgnoreWarnings
+const ignoreWarningsRule = z.strictObject({
+	code: z.string().optional(),
+	module: z.instanceof(RegExp).optional(),
+	message: z.instanceof(RegExp).optional()
+});
+export type IgnoreWarningsRule = z.infer<typeof ignoreWarningsRule>;
+
 const ignoreWarnings = z
 	.instanceof(RegExp)
+	.or(ignoreWarningsRule)
 	.or(
 		z
 			.function()
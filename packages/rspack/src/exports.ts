@@ -234,6 +234,7 @@ export type { LightningCssMinimizerRspackPluginOptions } from "./builtin-plugin"
 export type { CopyRspackPluginOptions } from "./builtin-plugin";
 export type { SourceMapDevToolPluginOptions } from "./builtin-plugin";
 export type { EvalDevToolModulePluginOptions } from "./builtin-plugin";
+export type { SubresourceIntegrityPluginOptions } from "./builtin-plugin";
 export type {
 	CssExtractRspackLoaderOptions,
 	CssExtractRspackPluginOptions
@@ -247,6 +248,7 @@ export { SourceMapDevToolPlugin } from "./builtin-plugin";
 export { EvalSourceMapDevToolPlugin } from "./builtin-plugin";
 export { EvalDevToolModulePlugin } from "./builtin-plugin";
 export { CssExtractRspackPlugin } from "./builtin-plugin";
+export { SubresourceIntegrityPlugin } from "./builtin-plugin";
 
 ///// Rspack Postfixed Internal Loaders /////
 export type {
@@ -16,8 +16,9 @@ export default class IgnoreWarningsPlugin implements RspackPluginInstance {
 		compiler.hooks.compilation.tap(this.name, compilation => {
 			compilation.hooks.processWarnings.tap(this.name, warnings => {
 				return warnings.filter(warning => {
-					return !this._ignorePattern.some(ignore =>
-						ignore(warning, compilation)
+					return !this._ignorePattern.some(
+						ignore =>
+							typeof ignore === "function" && ignore(warning, compilation)
 					);
 				});
 			});
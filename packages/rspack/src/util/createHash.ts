@@ -163,6 +163,11 @@ export const createHash = (
 			return new DebugHash();
 		case "xxhash64":
 			return new BatchedHash(createXXHash64());
+		// only implemented natively, hashes of js hooks are updated into the native hash
+		case "xxhash3":
+		case "xxhash128":
+		case "blake3":
+			return new BatchedHash(createXXHash64());
 		case "md4":
 			return new BatchedHash(createMd4());
 		case "native-md4":